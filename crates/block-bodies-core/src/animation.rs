@@ -0,0 +1,486 @@
+use crate::pose::{BlockPose, PartPose};
+use crate::BlockBodyError;
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Easing curve applied to the segment leading into a keyframe
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// Hold the previous pose, then snap
+    Step,
+}
+
+impl Easing {
+    /// Map linear progress `t` in [0, 1] onto the curve
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::Step => {
+                if t < 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
+/// A pose at a point in time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe {
+    pub time: f32,
+    pub pose: BlockPose,
+    pub easing: Easing,
+    /// Events fired when playback passes this keyframe (e.g. "hit")
+    pub events: Vec<String>,
+}
+
+/// Ordered keyframes sampled over time
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnimationClip {
+    pub keyframes: Vec<Keyframe>,
+    pub looping: bool,
+}
+
+/// Keyframe entry in a clip file; `pose` is a path relative to the clip file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipFileKeyframe {
+    pub time: f32,
+    pub pose: String,
+    #[serde(default)]
+    pub easing: Easing,
+    #[serde(default)]
+    pub events: Vec<String>,
+}
+
+/// Serializable clip: `AnimationClip(looping, keyframes: [(time, pose, easing, events)])`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "AnimationClip")]
+pub struct AnimationClipFile {
+    #[serde(default)]
+    pub looping: bool,
+    pub keyframes: Vec<ClipFileKeyframe>,
+}
+
+impl AnimationClipFile {
+    pub fn from_ron_str(content: &str) -> Result<Self, BlockBodyError> {
+        Ok(ron::from_str(content)?)
+    }
+
+    /// Build the clip from the poses of its keyframes, loaded in file order
+    pub fn into_clip(self, poses: Vec<BlockPose>) -> AnimationClip {
+        debug_assert_eq!(poses.len(), self.keyframes.len());
+
+        let keyframes = self
+            .keyframes
+            .into_iter()
+            .zip(poses)
+            .map(|(key, pose)| Keyframe {
+                time: key.time,
+                pose,
+                easing: key.easing,
+                events: key.events,
+            })
+            .collect();
+
+        AnimationClip::new(keyframes, self.looping)
+    }
+}
+
+impl Keyframe {
+    pub fn new(time: f32, pose: BlockPose) -> Self {
+        Self {
+            time,
+            pose,
+            easing: Easing::Linear,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_event(mut self, event: impl Into<String>) -> Self {
+        self.events.push(event.into());
+        self
+    }
+}
+
+impl AnimationClip {
+    /// Build a clip, sorting keyframes by time
+    pub fn new(mut keyframes: Vec<Keyframe>, looping: bool) -> Self {
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Self { keyframes, looping }
+    }
+
+    /// Time of the last keyframe
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |key| key.time)
+    }
+
+    /// Looping clips wrap around, others hold the last pose
+    fn local_time(&self, time: f32) -> f32 {
+        let duration = self.duration();
+        if self.looping && duration > 0.0 {
+            time.rem_euclid(duration)
+        } else {
+            time.clamp(0.0, duration)
+        }
+    }
+
+    /// Interpolated pose at `time` (per-part slerp between neighbouring keyframes).
+    /// A looping clip should repeat its first pose at the end for a seamless loop.
+    pub fn sample(&self, time: f32) -> BlockPose {
+        let Some(first) = self.keyframes.first() else {
+            return BlockPose::default();
+        };
+
+        let time = self.local_time(time);
+        if time <= first.time {
+            return first.pose.clone();
+        }
+
+        for pair in self.keyframes.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+            if time <= to.time {
+                let span = to.time - from.time;
+                let t = if span > 0.0 {
+                    (time - from.time) / span
+                } else {
+                    1.0
+                };
+                return blend_poses(&from.pose, &to.pose, to.easing.apply(t));
+            }
+        }
+
+        self.keyframes[self.keyframes.len() - 1].pose.clone()
+    }
+
+    /// Events of keyframes passed in (from, to]. Looping clips repeat events every cycle;
+    /// pass a negative `from` to include keyframes at time 0.
+    pub fn events_between(&self, from: f32, to: f32) -> Vec<&str> {
+        let duration = self.duration();
+        if to <= from {
+            return Vec::new();
+        }
+
+        let cycles = if self.looping && duration > 0.0 {
+            (from.max(0.0) / duration).floor() as i64..=(to / duration).floor() as i64
+        } else {
+            0..=0
+        };
+
+        cycles
+            .flat_map(|cycle| {
+                let offset = cycle as f32 * duration;
+                self.keyframes.iter().filter(move |key| {
+                    let time = offset + key.time;
+                    time > from && time <= to
+                })
+            })
+            .flat_map(|key| key.events.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Load a clip and all of its poses. Pose paths are relative to the clip file.
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, BlockBodyError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let file = AnimationClipFile::from_ron_str(&content)?;
+        let base = path.parent().unwrap_or(Path::new(""));
+
+        let poses = file
+            .keyframes
+            .iter()
+            .map(|key| BlockPose::load_from_file(base.join(&key.pose)))
+            .collect::<Result<Vec<_>, BlockBodyError>>()?;
+
+        Ok(file.into_clip(poses))
+    }
+}
+
+/// Per-part blend between two poses. Parts present in only one pose keep its values.
+pub fn blend_poses(a: &BlockPose, b: &BlockPose, t: f32) -> BlockPose {
+    let mut parts: Vec<PartPose> = a
+        .parts
+        .iter()
+        .map(|from| match b.get(&from.name) {
+            Some(to) => PartPose {
+                name: from.name.clone(),
+                offset: blend_offset(from.offset, to.offset, t),
+                rotation: from.rotation.normalize().slerp(to.rotation.normalize(), t),
+            },
+            None => from.clone(),
+        })
+        .collect();
+
+    parts.extend(
+        b.parts
+            .iter()
+            .filter(|to| a.get(&to.name).is_none())
+            .cloned(),
+    );
+
+    BlockPose { parts }
+}
+
+fn blend_offset(a: Option<Vec3>, b: Option<Vec3>, t: f32) -> Option<Vec3> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.lerp(b, t)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Playback state of one clip
+#[derive(Debug, Clone)]
+struct ClipState {
+    clip: AnimationClip,
+    time: f32,
+    speed: f32,
+    /// Not advanced yet: keyframes at time 0 still have to fire their events
+    fresh: bool,
+}
+
+/// Plays clips over time, cross-fading from the previous clip when a new one starts
+#[derive(Debug, Clone, Default)]
+pub struct ClipPlayer {
+    current: Option<ClipState>,
+    previous: Option<ClipState>,
+    fade_elapsed: f32,
+    fade_duration: f32,
+}
+
+impl ClipPlayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start `clip`, blending out the current one over `fade_duration` seconds
+    pub fn play(&mut self, clip: AnimationClip, fade_duration: f32) {
+        self.previous = if fade_duration > 0.0 {
+            self.current.take()
+        } else {
+            None
+        };
+        self.current = Some(ClipState {
+            clip,
+            time: 0.0,
+            speed: 1.0,
+            fresh: true,
+        });
+        self.fade_elapsed = 0.0;
+        self.fade_duration = fade_duration.max(0.0);
+    }
+
+    pub fn set_speed(&mut self, speed: f32) {
+        if let Some(current) = &mut self.current {
+            current.speed = speed;
+        }
+    }
+
+    /// Playback time of the current clip
+    pub fn time(&self) -> f32 {
+        self.current.as_ref().map_or(0.0, |state| state.time)
+    }
+
+    /// A non-looping clip that reached its end
+    pub fn is_finished(&self) -> bool {
+        self.current
+            .as_ref()
+            .is_none_or(|state| !state.clip.looping && state.time >= state.clip.duration())
+    }
+
+    pub fn is_fading(&self) -> bool {
+        self.previous.is_some()
+    }
+
+    /// Advance playback by `dt`; returns events of keyframes passed by the current clip
+    pub fn advance(&mut self, dt: f32) -> Vec<String> {
+        let mut events = Vec::new();
+
+        if let Some(current) = &mut self.current {
+            let from = if current.fresh { -1.0 } else { current.time };
+            current.fresh = false;
+            current.time += dt * current.speed;
+            if !current.clip.looping {
+                current.time = current.time.min(current.clip.duration());
+            }
+            events = current
+                .clip
+                .events_between(from, current.time)
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+        }
+
+        if let Some(previous) = &mut self.previous {
+            previous.time += dt * previous.speed;
+            self.fade_elapsed += dt;
+            if self.fade_elapsed >= self.fade_duration {
+                self.previous = None;
+            }
+        }
+
+        events
+    }
+
+    /// Current blended pose (None before the first `play`)
+    pub fn sample(&self) -> Option<BlockPose> {
+        let current = self.current.as_ref()?;
+        let pose = current.clip.sample(current.time);
+
+        match &self.previous {
+            Some(previous) if self.fade_duration > 0.0 => {
+                let t = (self.fade_elapsed / self.fade_duration).clamp(0.0, 1.0);
+                Some(blend_poses(&previous.clip.sample(previous.time), &pose, t))
+            }
+            _ => Some(pose),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Quat;
+
+    fn pose(angle: f32) -> BlockPose {
+        BlockPose {
+            parts: vec![PartPose::new("Arm", Quat::from_rotation_x(angle))],
+        }
+    }
+
+    fn arm_angle(pose: &BlockPose) -> f32 {
+        let (axis, angle) = pose.get("Arm").unwrap().rotation.to_axis_angle();
+        angle * axis.x.signum()
+    }
+
+    fn slash() -> AnimationClip {
+        AnimationClip::new(
+            vec![
+                Keyframe::new(0.0, pose(0.0)),
+                Keyframe::new(0.2, pose(-1.0)).with_easing(Easing::EaseOut),
+                Keyframe::new(0.3, pose(1.0)).with_event("hit"),
+                Keyframe::new(0.6, pose(0.0)).with_easing(Easing::EaseInOut),
+            ],
+            false,
+        )
+    }
+
+    #[test]
+    fn test_easing_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::Step,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+        }
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+    }
+
+    #[test]
+    fn test_sample_interpolates() {
+        let clip = slash();
+        assert_eq!(clip.duration(), 0.6);
+
+        assert!(arm_angle(&clip.sample(0.0)).abs() < 1e-5);
+        assert!((arm_angle(&clip.sample(0.25)) - 0.0).abs() < 1e-4);
+        assert!((arm_angle(&clip.sample(0.3)) - 1.0).abs() < 1e-4);
+        // Past the end of a non-looping clip: hold the last pose
+        assert!(arm_angle(&clip.sample(5.0)).abs() < 1e-4);
+
+        // EaseOut segment is ahead of linear at its midpoint
+        assert!(arm_angle(&clip.sample(0.1)) < -0.5);
+    }
+
+    #[test]
+    fn test_events() {
+        let clip = slash();
+        assert!(clip.events_between(0.0, 0.29).is_empty());
+        assert_eq!(clip.events_between(0.29, 0.31), vec!["hit"]);
+        assert!(clip.events_between(0.31, 1.0).is_empty());
+
+        let mut looping = slash();
+        looping.looping = true;
+        // Two full cycles pass the hit twice
+        assert_eq!(looping.events_between(0.0, 1.2), vec!["hit", "hit"]);
+    }
+
+    #[test]
+    fn test_player_cross_fade() {
+        let mut player = ClipPlayer::new();
+        player.play(
+            AnimationClip::new(vec![Keyframe::new(0.0, pose(1.0))], false),
+            0.0,
+        );
+        player.advance(0.1);
+
+        player.play(
+            AnimationClip::new(vec![Keyframe::new(0.0, pose(-1.0))], false),
+            0.4,
+        );
+        assert!(player.is_fading());
+
+        player.advance(0.2);
+        assert!(arm_angle(&player.sample().unwrap()).abs() < 1e-4);
+
+        player.advance(0.3);
+        assert!(!player.is_fading());
+        assert!((arm_angle(&player.sample().unwrap()) + 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_player_fires_events_once() {
+        let mut player = ClipPlayer::new();
+        player.play(slash(), 0.0);
+
+        let mut fired = Vec::new();
+        for _ in 0..40 {
+            fired.extend(player.advance(1.0 / 60.0));
+        }
+
+        assert_eq!(fired, vec!["hit".to_string()]);
+        assert!(player.is_finished());
+    }
+
+    #[test]
+    fn test_shipped_clips_load() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/animations/jimbo");
+        for name in ["bite", "idle", "slash", "walk"] {
+            let path = format!("{}/{}.clip.ron", dir, name);
+            let clip =
+                AnimationClip::load_from_file(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+            assert!(clip.duration() > 0.0, "{}", name);
+        }
+
+        let slash = AnimationClip::load_from_file(format!("{}/slash.clip.ron", dir)).unwrap();
+        assert!(slash
+            .keyframes
+            .iter()
+            .any(|key| key.events.iter().any(|e| e == "hit")));
+    }
+}
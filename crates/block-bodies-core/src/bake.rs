@@ -0,0 +1,283 @@
+use crate::body::BlockBody;
+use crate::bounds::Obb;
+use crate::error::BlockBodyError;
+use crate::model::{BlockModelFile, MaterialPalette, DEFAULT_MATERIAL};
+use crate::pose::BlockPose;
+use glam::{Affine3A, Vec3};
+
+/// Vertices per baked cuboid (four per face, so normals and UVs stay flat)
+pub const VERTICES_PER_PART: usize = 24;
+/// Triangle indices per baked cuboid
+pub const INDICES_PER_PART: usize = 36;
+
+/// Face normal with two tangents such that `u × v = normal`
+const FACES: [(Vec3, Vec3, Vec3); 6] = [
+    (Vec3::X, Vec3::NEG_Z, Vec3::Y),
+    (Vec3::NEG_X, Vec3::Z, Vec3::Y),
+    (Vec3::Y, Vec3::X, Vec3::NEG_Z),
+    (Vec3::NEG_Y, Vec3::X, Vec3::Z),
+    (Vec3::Z, Vec3::X, Vec3::Y),
+    (Vec3::NEG_Z, Vec3::NEG_X, Vec3::Y),
+];
+
+/// Corner signs along (u, v) and their UVs, counter-clockwise seen from outside
+const CORNERS: [(f32, f32, [f32; 2]); 4] = [
+    (-1.0, -1.0, [0.0, 1.0]),
+    (1.0, -1.0, [1.0, 1.0]),
+    (1.0, 1.0, [1.0, 0.0]),
+    (-1.0, 1.0, [0.0, 0.0]),
+];
+
+/// All parts merged into one indexed triangle list in body space
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BakedMesh {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub uvs: Vec<[f32; 2]>,
+
+    /// sRGB color of the part's material, per vertex
+    pub colors: Vec<[f32; 4]>,
+
+    /// Index into `materials`, per vertex
+    pub material_indices: Vec<u32>,
+
+    /// Material names in order of first use
+    pub materials: Vec<String>,
+
+    pub indices: Vec<u32>,
+}
+
+/// One box of a compound collider, in body space
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColliderShape {
+    pub part: String,
+    pub obb: Obb,
+}
+
+/// One cuboid per part, matching the baked mesh
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompoundCollider {
+    pub shapes: Vec<ColliderShape>,
+}
+
+/// Mesh and collider of a body frozen at one pose
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BakedBody {
+    pub mesh: BakedMesh,
+    pub collider: CompoundCollider,
+}
+
+impl BakedMesh {
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    fn push_cuboid(&mut self, obb: &Obb, color: [f32; 4], material: u32) {
+        for (normal, u, v) in FACES {
+            let base = self.positions.len() as u32;
+            let world_normal = (obb.rotation * normal).to_array();
+
+            for (su, sv, uv) in CORNERS {
+                let local = (normal + u * su + v * sv) * obb.half_extents;
+                self.positions
+                    .push((obb.center + obb.rotation * local).to_array());
+                self.normals.push(world_normal);
+                self.uvs.push(uv);
+                self.colors.push(color);
+                self.material_indices.push(material);
+            }
+
+            self.indices
+                .extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+    }
+
+    fn material_index(&mut self, name: &str) -> u32 {
+        let index = self
+            .materials
+            .iter()
+            .position(|m| m == name)
+            .unwrap_or_else(|| {
+                self.materials.push(name.to_string());
+                self.materials.len() - 1
+            });
+        index as u32
+    }
+}
+
+impl BlockBody {
+    /// Bake all parts into one mesh and compound collider, in body space.
+    /// With `pose` the body is baked as posed, otherwise in its current local transforms.
+    /// Every part uses the default material.
+    pub fn bake(&self, pose: Option<&BlockPose>) -> Result<BakedBody, BlockBodyError> {
+        bake(self, pose, &MaterialPalette::default(), |_| {
+            DEFAULT_MATERIAL
+        })
+    }
+}
+
+impl BlockModelFile {
+    /// Like `BlockBody::bake`, with part materials resolved through `palette`
+    pub fn bake(
+        &self,
+        palette: &MaterialPalette,
+        pose: Option<&BlockPose>,
+    ) -> Result<BakedBody, BlockBodyError> {
+        let body = self.to_body()?;
+        bake(&body, pose, palette, |name| {
+            self.get(name)
+                .map_or(DEFAULT_MATERIAL, |part| part.material.as_str())
+        })
+    }
+}
+
+fn bake<'a>(
+    body: &BlockBody,
+    pose: Option<&BlockPose>,
+    palette: &MaterialPalette,
+    material_of: impl Fn(&str) -> &'a str,
+) -> Result<BakedBody, BlockBodyError> {
+    let mut posed = body.clone();
+    if let Some(pose) = pose {
+        posed.apply_pose(pose, 1.0)?;
+    }
+    posed.update_world_transforms(Affine3A::IDENTITY);
+
+    let mut baked = BakedBody::default();
+    for (name, obb) in posed.world_obbs() {
+        let material_name = material_of(name);
+        let material = baked.mesh.material_index(material_name);
+        let color = palette.resolve(material_name).color;
+
+        baked.mesh.push_cuboid(&obb, color, material);
+        baked.collider.shapes.push(ColliderShape {
+            part: name.to_string(),
+            obb,
+        });
+    }
+
+    Ok(baked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{BlockMaterial, ModelPart};
+    use crate::pose::PartPose;
+    use glam::Quat;
+
+    fn model() -> BlockModelFile {
+        let mut torso = ModelPart::new("Torso", Vec3::ZERO, Vec3::new(1.0, 2.0, 0.5));
+        torso.material = "cloth".into();
+
+        let mut arm = ModelPart::new("Arm", Vec3::new(0.75, 0.5, 0.0), Vec3::splat(0.5));
+        arm.parent = Some("Torso".into());
+
+        BlockModelFile {
+            version: crate::model::MODEL_SCHEMA_VERSION,
+            parts: vec![torso, arm],
+        }
+    }
+
+    fn palette() -> MaterialPalette {
+        MaterialPalette {
+            materials: [("cloth".to_string(), BlockMaterial::rgb(0.2, 0.3, 0.4))].into(),
+        }
+    }
+
+    #[test]
+    fn test_bake_buffers() {
+        let baked = model().bake(&palette(), None).unwrap();
+        let mesh = &baked.mesh;
+
+        assert_eq!(mesh.vertex_count(), 2 * VERTICES_PER_PART);
+        assert_eq!(mesh.indices.len(), 2 * INDICES_PER_PART);
+        assert_eq!(mesh.normals.len(), mesh.vertex_count());
+        assert_eq!(mesh.uvs.len(), mesh.vertex_count());
+        assert_eq!(mesh.colors.len(), mesh.vertex_count());
+        assert!(mesh
+            .indices
+            .iter()
+            .all(|&i| (i as usize) < mesh.vertex_count()));
+
+        // Torso vertices span exactly its size
+        let torso: Vec<Vec3> = mesh.positions[..VERTICES_PER_PART]
+            .iter()
+            .map(|&p| Vec3::from(p))
+            .collect();
+        let max = torso.iter().fold(Vec3::NEG_INFINITY, |a, &p| a.max(p));
+        assert!(max.abs_diff_eq(Vec3::new(0.5, 1.0, 0.25), 1e-5));
+    }
+
+    #[test]
+    fn test_winding_faces_outward() {
+        let body = model().to_body().unwrap();
+        let mesh = body.bake(None).unwrap().mesh;
+
+        for triangle in mesh.indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(mesh.positions[triangle[i] as usize]));
+            let normal = Vec3::from(mesh.normals[triangle[0] as usize]);
+            assert!((b - a).cross(c - a).normalize().abs_diff_eq(normal, 1e-5));
+        }
+    }
+
+    #[test]
+    fn test_materials() {
+        let mesh = model().bake(&palette(), None).unwrap().mesh;
+
+        assert_eq!(mesh.materials, vec!["cloth", DEFAULT_MATERIAL]);
+        assert_eq!(mesh.material_indices[0], 0);
+        assert_eq!(mesh.material_indices[VERTICES_PER_PART], 1);
+        assert_eq!(mesh.colors[0], [0.2, 0.3, 0.4, 1.0]);
+        // No default in the palette: gray fallback
+        assert_eq!(
+            mesh.colors[VERTICES_PER_PART],
+            BlockMaterial::default().color
+        );
+    }
+
+    #[test]
+    fn test_bake_at_pose() {
+        let body = model().to_body().unwrap();
+        let pose = BlockPose {
+            parts: vec![PartPose::new(
+                "Torso",
+                Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+            )],
+        };
+
+        let rest = body.bake(None).unwrap();
+        let posed = body.bake(Some(&pose)).unwrap();
+        assert_ne!(rest.mesh.positions, posed.mesh.positions);
+
+        // The arm swings up with the torso
+        let arm = &posed.collider.shapes[1];
+        assert_eq!(arm.part, "Arm");
+        assert!(arm.obb.center.abs_diff_eq(Vec3::new(-0.5, 0.75, 0.0), 1e-5));
+
+        // The body itself is left untouched
+        assert_eq!(
+            body.get_part_by_name("Torso").unwrap().rotation,
+            Quat::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_collider_matches_body() {
+        let mut body = model().to_body().unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+        let collider = body.bake(None).unwrap().collider;
+
+        let expected: Vec<(&str, Obb)> = body.world_obbs();
+        assert_eq!(collider.shapes.len(), expected.len());
+        for (shape, (name, obb)) in collider.shapes.iter().zip(expected) {
+            assert_eq!(shape.part, name);
+            assert!(shape.obb.center.abs_diff_eq(obb.center, 1e-6));
+            assert!(shape.obb.half_extents.abs_diff_eq(obb.half_extents, 1e-6));
+        }
+    }
+}
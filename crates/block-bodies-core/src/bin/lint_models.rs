@@ -0,0 +1,74 @@
+//! Check block body models for authoring errors.
+//!
+//! Usage: `cargo run -p block-bodies-core --bin lint_models -- [FILE_OR_DIR]...`
+//! Without arguments lints `assets/models`. Exits with status 1 if any model has errors.
+
+use block_bodies_core::BlockModelFile;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const DEFAULT_DIR: &str = "assets/models";
+
+fn main() -> ExitCode {
+    let mut args: Vec<PathBuf> = std::env::args_os().skip(1).map(PathBuf::from).collect();
+    if args.is_empty() {
+        args.push(PathBuf::from(DEFAULT_DIR));
+    }
+
+    let mut files = Vec::new();
+    for path in &args {
+        if let Err(err) = collect_models(path, &mut files) {
+            eprintln!("{}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    files.sort();
+
+    let mut failed = 0;
+    for file in &files {
+        if !lint(file) {
+            failed += 1;
+        }
+    }
+
+    println!("{} models checked, {failed} with errors", files.len());
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn collect_models(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "ron") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Print the report for one file; false if it has errors
+fn lint(path: &Path) -> bool {
+    let model = match BlockModelFile::load_from_file(path) {
+        Ok(model) => model,
+        Err(err) => {
+            println!("{}: error: {err}", path.display());
+            return false;
+        }
+    };
+
+    let report = model.validation_report();
+    if report.issues.is_empty() {
+        println!("{}: ok", path.display());
+    }
+    for line in report.to_string().lines() {
+        println!("{}: {line}", path.display());
+    }
+    report.is_valid()
+}
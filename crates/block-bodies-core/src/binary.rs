@@ -0,0 +1,164 @@
+use crate::joint::PartJoint;
+use crate::serialization::BlockBodyFile;
+use crate::{BlockBody, BlockBodyError, BlockPart};
+use glam::{Quat, Vec3};
+use serde::{Deserialize, Serialize};
+
+/// Leading bytes of every binary snapshot
+const MAGIC: [u8; 4] = *b"BLKB";
+
+/// Bumped on any change to the binary layout
+pub const BINARY_FORMAT_VERSION: u16 = 1;
+
+/// Binary layout of a part. Unlike `BlockPart` every field is always written:
+/// bincode is not self-describing, so skipped fields would break decoding.
+#[derive(Serialize, Deserialize)]
+struct BinaryPart {
+    index: u32,
+    name: String,
+    parent: Option<String>,
+    position: Vec3,
+    rotation: Quat,
+    size: Vec3,
+    joint: Option<PartJoint>,
+}
+
+impl BlockBody {
+    /// Compact snapshot for save files and network messages, stable indices included
+    pub fn to_bytes(&self) -> Result<Vec<u8>, BlockBodyError> {
+        let file = BlockBodyFile::from_body(self);
+        let parts: Vec<BinaryPart> = file
+            .parts
+            .into_iter()
+            .zip(file.indices)
+            .map(|(part, index)| BinaryPart {
+                index,
+                name: part.name,
+                parent: part.parent,
+                position: part.position,
+                rotation: part.rotation,
+                size: part.size,
+                joint: part.joint,
+            })
+            .collect();
+
+        let mut bytes = Vec::from(MAGIC);
+        bytes.extend(BINARY_FORMAT_VERSION.to_le_bytes());
+        bincode::serialize_into(&mut bytes, &parts)?;
+        Ok(bytes)
+    }
+
+    /// Restore a body from `to_bytes`; the result goes through the usual validation
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlockBodyError> {
+        let payload = bytes
+            .strip_prefix(&MAGIC)
+            .ok_or_else(|| BlockBodyError::UnsupportedFormat("not a binary body".to_string()))?;
+        let (version, payload) = payload.split_at_checked(2).ok_or_else(|| {
+            BlockBodyError::UnsupportedFormat("truncated binary body".to_string())
+        })?;
+        let version = u16::from_le_bytes([version[0], version[1]]);
+        if version != BINARY_FORMAT_VERSION {
+            return Err(BlockBodyError::UnsupportedFormat(format!(
+                "binary body version {version}"
+            )));
+        }
+
+        let parts: Vec<BinaryPart> = bincode::deserialize(payload)?;
+        let (indices, parts) = parts
+            .into_iter()
+            .map(|part| {
+                (
+                    part.index,
+                    BlockPart {
+                        name: part.name,
+                        position: part.position,
+                        rotation: part.rotation,
+                        size: part.size,
+                        parent: part.parent,
+                        joint: part.joint,
+                        world_transform: Default::default(),
+                    },
+                )
+            })
+            .unzip();
+
+        BlockBodyFile { parts, indices }.to_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body() -> BlockBody {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("Torso", Vec3::ZERO, Vec3::ONE))
+            .unwrap();
+        body.add_part(
+            BlockPart::with_parent("Arm", Vec3::X, Vec3::splat(0.5), "Torso")
+                .with_joint(PartJoint::revolute(2, -1.0, 1.0)),
+        )
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "Hand",
+            Vec3::X,
+            Vec3::splat(0.3),
+            "Arm",
+        ))
+        .unwrap();
+        body.set_local_rotation("Arm", Quat::from_rotation_z(0.5))
+            .unwrap();
+        body
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let body = body();
+        let restored = BlockBody::from_bytes(&body.to_bytes().unwrap()).unwrap();
+
+        assert!(body.diff(&restored).is_empty());
+        assert_eq!(
+            restored.get_part_by_name("Arm").unwrap().joint,
+            Some(PartJoint::revolute(2, -1.0, 1.0))
+        );
+        for name in ["Torso", "Arm", "Hand"] {
+            assert_eq!(restored.part_index(name), body.part_index(name));
+        }
+    }
+
+    #[test]
+    fn test_severed_snapshot_keeps_indices() {
+        let mut body = body();
+        let severed = body.sever_at("Arm").unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("limb.bin");
+        severed.to_file(&path).unwrap();
+        let restored = BlockBody::from_file(&path).unwrap();
+
+        assert_eq!(restored.part_index("Hand"), Some(2));
+        assert!(restored.get_part_by_name("Arm").unwrap().is_root());
+    }
+
+    #[test]
+    fn test_smaller_than_ron() {
+        let body = body();
+        let ron = ron::to_string(&BlockBodyFile::from_body(&body)).unwrap();
+        assert!(body.to_bytes().unwrap().len() < ron.len());
+    }
+
+    #[test]
+    fn test_rejects_foreign_bytes() {
+        assert!(matches!(
+            BlockBody::from_bytes(b"(parts: [])"),
+            Err(BlockBodyError::UnsupportedFormat(_))
+        ));
+
+        let mut bytes = body().to_bytes().unwrap();
+        bytes[4] = 99;
+        assert!(matches!(
+            BlockBody::from_bytes(&bytes),
+            Err(BlockBodyError::UnsupportedFormat(_))
+        ));
+    }
+}
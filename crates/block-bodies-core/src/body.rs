@@ -1,4 +1,7 @@
+use crate::bounds::{Aabb, Obb};
+use crate::joint::Joint;
 use crate::{BlockBodyError, BlockPart, PartId};
+use glam::{Affine3A, Quat, Vec3};
 use slotmap::SlotMap;
 use std::collections::HashMap;
 
@@ -16,6 +19,15 @@ pub struct BlockBody {
 
     /// Root part IDs (parts with no parent)
     pub roots: Vec<PartId>,
+
+    /// Body placement used by the last `update_world_transforms`
+    pub root_transform: Affine3A,
+
+    /// Stable index of each part, kept across save/load (SlotMap keys are not)
+    indices: HashMap<PartId, u32>,
+
+    /// Next index to hand out; indices are never reused within a body
+    next_index: u32,
 }
 
 impl BlockBody {
@@ -26,11 +38,31 @@ impl BlockBody {
             name_to_id: HashMap::new(),
             children: HashMap::new(),
             roots: Vec::new(),
+            root_transform: Affine3A::IDENTITY,
+            indices: HashMap::new(),
+            next_index: 0,
         }
     }
 
     /// Add a part to the body
     pub fn add_part(&mut self, part: BlockPart) -> Result<PartId, BlockBodyError> {
+        self.insert_part(part, None)
+    }
+
+    /// Add a part under a known stable index (when restoring a saved body)
+    pub fn add_part_with_index(
+        &mut self,
+        part: BlockPart,
+        index: u32,
+    ) -> Result<PartId, BlockBodyError> {
+        self.insert_part(part, Some(index))
+    }
+
+    fn insert_part(
+        &mut self,
+        part: BlockPart,
+        index: Option<u32>,
+    ) -> Result<PartId, BlockBodyError> {
         // Validate part name
         if part.name.is_empty() {
             return Err(BlockBodyError::InvalidPartName(part.name.clone()));
@@ -56,6 +88,12 @@ impl BlockBody {
             None
         };
 
+        if let Some(index) = index {
+            if self.indices.values().any(|&used| used == index) {
+                return Err(BlockBodyError::PartIndexTaken(index));
+            }
+        }
+
         let is_root = part.is_root();
         let name = part.name.clone();
 
@@ -65,6 +103,10 @@ impl BlockBody {
         // Update name mapping
         self.name_to_id.insert(name, id);
 
+        let index = index.unwrap_or(self.next_index);
+        self.indices.insert(id, index);
+        self.next_index = self.next_index.max(index + 1);
+
         // Update children cache
         if let Some(parent_id) = parent_id {
             self.children.entry(parent_id).or_default().push(id);
@@ -96,6 +138,7 @@ impl BlockBody {
             if let Some(part) = self.parts.remove(id) {
                 self.name_to_id.remove(&part.name);
                 self.children.remove(&id);
+                self.indices.remove(&id);
 
                 // Remove from parent's children list
                 if let Some(parent_name) = &part.parent {
@@ -116,6 +159,18 @@ impl BlockBody {
         Ok(removed_parts)
     }
 
+    /// Stable index of a part
+    pub fn part_index(&self, name: &str) -> Option<u32> {
+        let id = self.name_to_id.get(name)?;
+        self.indices.get(id).copied()
+    }
+
+    /// Find a part by its stable index
+    pub fn get_part_by_index(&self, index: u32) -> Option<&BlockPart> {
+        let (&id, _) = self.indices.iter().find(|(_, &i)| i == index)?;
+        self.parts.get(id)
+    }
+
     /// Get a part by name
     pub fn get_part_by_name(&self, name: &str) -> Option<&BlockPart> {
         let id = self.name_to_id.get(name)?;
@@ -205,6 +260,27 @@ impl BlockBody {
         chain
     }
 
+    /// Names of the parts from `start` down to `end`, inclusive (for chain IK)
+    pub fn get_chain_between(&self, start: &str, end: &str) -> Result<Vec<String>, BlockBodyError> {
+        if self.get_part_by_name(start).is_none() {
+            return Err(BlockBodyError::PartNotFound(start.to_string()));
+        }
+        if self.get_part_by_name(end).is_none() {
+            return Err(BlockBodyError::PartNotFound(end.to_string()));
+        }
+
+        let mut chain = Vec::new();
+        for part in self.get_chain_to_root(end) {
+            chain.push(part.name.clone());
+            if part.name == start {
+                chain.reverse();
+                return Ok(chain);
+            }
+        }
+
+        Err(BlockBodyError::InvalidChain(end.to_string()))
+    }
+
     /// Get all descendants of a part (recursive)
     pub fn get_all_descendants(&self, parent_name: &str) -> Vec<&BlockPart> {
         let parent_id = match self.name_to_id.get(parent_name) {
@@ -219,6 +295,15 @@ impl BlockBody {
 
     /// Sever at a part - remove part and all descendants, return them as new body
     pub fn sever_at(&mut self, part_name: &str) -> Result<BlockBody, BlockBodyError> {
+        // Severed parts keep their indices, so snapshots of both halves line up
+        let indices: HashMap<String, u32> = self
+            .get_all_descendants(part_name)
+            .into_iter()
+            .map(|p| p.name.as_str())
+            .chain([part_name])
+            .filter_map(|name| Some((name.to_string(), self.part_index(name)?)))
+            .collect();
+
         let mut removed_parts = self.remove_part(part_name)?;
 
         // Fix parent references: the severed part becomes a root
@@ -229,7 +314,8 @@ impl BlockBody {
         // Create new body from removed parts
         let mut new_body = BlockBody::new();
         for part in removed_parts {
-            new_body.add_part(part)?;
+            let index = indices.get(&part.name).copied();
+            new_body.insert_part(part, index)?;
         }
 
         Ok(new_body)
@@ -273,6 +359,136 @@ impl BlockBody {
         Ok(())
     }
 
+    /// Set a part's local rotation, clamped by its joint limits.
+    /// Returns the rotation that was actually applied.
+    pub fn set_local_rotation(
+        &mut self,
+        name: &str,
+        rotation: Quat,
+    ) -> Result<Quat, BlockBodyError> {
+        let part = self
+            .get_part_by_name_mut(name)
+            .ok_or_else(|| BlockBodyError::PartNotFound(name.to_string()))?;
+
+        let clamped = part.constrain_rotation(rotation.normalize());
+        part.rotation = clamped;
+        Ok(clamped)
+    }
+
+    /// Joints between parts and their parents, parents before children
+    pub fn joints(&self) -> Vec<Joint> {
+        self.topological_order()
+            .into_iter()
+            .filter_map(|id| self.parts.get(id))
+            .filter_map(|part| {
+                let joint = part.joint.as_ref()?;
+                Some(Joint {
+                    parent_id: part.parent.clone()?,
+                    child_id: part.name.clone(),
+                    joint_type: joint.joint_type,
+                    limits: joint.limits.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Forward kinematics: recompute `world_transform` of every part.
+    /// Parents are always processed before their children; `root` places the whole body.
+    pub fn update_world_transforms(&mut self, root: Affine3A) {
+        self.root_transform = root;
+        let mut stack: Vec<(PartId, Affine3A)> =
+            self.roots.iter().rev().map(|&id| (id, root)).collect();
+
+        while let Some((id, parent_world)) = stack.pop() {
+            let Some(part) = self.parts.get_mut(id) else {
+                continue;
+            };
+            let world = parent_world * part.local_transform();
+            part.world_transform = world;
+
+            if let Some(children) = self.children.get(&id) {
+                stack.extend(children.iter().rev().map(|&child| (child, world)));
+            }
+        }
+    }
+
+    /// Forward kinematics for one part and its descendants only.
+    /// The parent's `world_transform` (or `root_transform` for roots) is taken as is.
+    pub fn update_subtree_transforms(&mut self, name: &str) -> Result<(), BlockBodyError> {
+        let id = self
+            .get_part_id(name)
+            .ok_or_else(|| BlockBodyError::PartNotFound(name.to_string()))?;
+
+        let parent_world = self
+            .parts
+            .get(id)
+            .and_then(|part| part.parent.as_deref())
+            .and_then(|parent| self.get_part_by_name(parent))
+            .map_or(self.root_transform, |parent| parent.world_transform);
+
+        let mut stack = vec![(id, parent_world)];
+        while let Some((id, parent_world)) = stack.pop() {
+            let Some(part) = self.parts.get_mut(id) else {
+                continue;
+            };
+            let world = parent_world * part.local_transform();
+            part.world_transform = world;
+
+            if let Some(children) = self.children.get(&id) {
+                stack.extend(children.iter().rev().map(|&child| (child, world)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Part IDs ordered so that every parent comes before its children
+    pub fn topological_order(&self) -> Vec<PartId> {
+        let mut order = Vec::with_capacity(self.parts.len());
+        let mut stack: Vec<PartId> = self.roots.iter().rev().copied().collect();
+
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            if let Some(children) = self.children.get(&id) {
+                stack.extend(children.iter().rev());
+            }
+        }
+
+        order
+    }
+
+    /// World transform of a part (as of the last `update_world_transforms`)
+    pub fn world_transform(&self, name: &str) -> Option<Affine3A> {
+        self.get_part_by_name(name).map(|part| part.world_transform)
+    }
+
+    /// World position of a part's center (as of the last `update_world_transforms`)
+    pub fn world_position(&self, name: &str) -> Option<Vec3> {
+        self.get_part_by_name(name).map(BlockPart::world_position)
+    }
+
+    /// World-space oriented box of a part
+    pub fn world_obb(&self, name: &str) -> Option<Obb> {
+        self.get_part_by_name(name).map(BlockPart::world_obb)
+    }
+
+    /// World-space oriented boxes of all parts, parents before children
+    pub fn world_obbs(&self) -> Vec<(&str, Obb)> {
+        self.topological_order()
+            .into_iter()
+            .filter_map(|id| self.parts.get(id))
+            .map(|part| (part.name.as_str(), part.world_obb()))
+            .collect()
+    }
+
+    /// Box containing the whole body (None for an empty body)
+    pub fn world_aabb(&self) -> Option<Aabb> {
+        self.parts
+            .values()
+            .map(|part| part.world_obb().aabb())
+            .reduce(|a, b| a.union(&b))
+    }
+
     /// Get all root parts
     pub fn get_roots(&self) -> Vec<&BlockPart> {
         self.roots
@@ -302,7 +518,23 @@ impl BlockBody {
             }
         }
 
-        // TODO: Check for cycles in the hierarchy
+        // A parent chain longer than the body itself must loop
+        for part in self.parts.values() {
+            let mut current = part;
+            for _ in 0..self.parts.len() {
+                let Some(parent) = current
+                    .parent
+                    .as_ref()
+                    .and_then(|p| self.get_part_by_name(p))
+                else {
+                    break;
+                };
+                if parent.name == part.name {
+                    return Err(BlockBodyError::CircularDependency(part.name.clone()));
+                }
+                current = parent;
+            }
+        }
 
         Ok(())
     }
@@ -346,7 +578,7 @@ impl Default for BlockBody {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{BlockPart, Vec3};
+    use crate::{BlockPart, Quat, Vec3};
 
     #[test]
     fn test_empty_body() {
@@ -516,6 +748,29 @@ mod tests {
         assert_eq!(roots[0].name, "arm");
     }
 
+    #[test]
+    fn test_stable_indices() {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("torso", Vec3::ZERO, Vec3::ONE))
+            .unwrap();
+        body.add_part(BlockPart::with_parent("arm", Vec3::X, Vec3::ONE, "torso"))
+            .unwrap();
+        body.add_part(BlockPart::with_parent("hand", Vec3::X, Vec3::ONE, "arm"))
+            .unwrap();
+
+        let severed = body.sever_at("arm").unwrap();
+        assert_eq!(severed.part_index("hand"), Some(2));
+        assert_eq!(severed.get_part_by_index(1).unwrap().name, "arm");
+
+        // Removed indices are not handed out again
+        body.add_part(BlockPart::with_parent("head", Vec3::Y, Vec3::ONE, "torso"))
+            .unwrap();
+        assert_eq!(body.part_index("head"), Some(3));
+
+        let result = body.add_part_with_index(BlockPart::new("tail", Vec3::ZERO, Vec3::ONE), 0);
+        assert!(matches!(result, Err(BlockBodyError::PartIndexTaken(0))));
+    }
+
     #[test]
     fn test_attach_bodies() {
         let mut main_body = BlockBody::new();
@@ -551,4 +806,84 @@ mod tests {
         assert_eq!(children.len(), 1);
         assert_eq!(children[0].name, "sword");
     }
+
+    #[test]
+    fn test_forward_kinematics() {
+        let mut body = BlockBody::new();
+        let mut torso = BlockPart::new("torso", Vec3::new(0.0, 1.0, 0.0), Vec3::ONE);
+        torso.rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        body.add_part(torso).unwrap();
+        body.add_part(BlockPart::with_parent("arm", Vec3::X, Vec3::ONE, "torso"))
+            .unwrap();
+        body.add_part(BlockPart::with_parent("hand", Vec3::X, Vec3::ONE, "arm"))
+            .unwrap();
+
+        body.update_world_transforms(Affine3A::from_translation(Vec3::new(10.0, 0.0, 0.0)));
+
+        // Torso turned 90° around Y: its local +X points to world -Z
+        let hand = body.world_position("hand").unwrap();
+        assert!(hand.abs_diff_eq(Vec3::new(10.0, 1.0, -2.0), 1e-5));
+
+        let order = body.topological_order();
+        assert_eq!(order.len(), 3);
+        assert_eq!(order[0], body.get_part_id("torso").unwrap());
+    }
+
+    #[test]
+    fn test_world_bounds() {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new(
+            "torso",
+            Vec3::ZERO,
+            Vec3::new(2.0, 2.0, 2.0),
+        ))
+        .unwrap();
+        let mut arm = BlockPart::with_parent("arm", Vec3::new(2.0, 0.0, 0.0), Vec3::ONE, "torso");
+        arm.rotation = Quat::from_rotation_z(std::f32::consts::FRAC_PI_4);
+        body.add_part(arm).unwrap();
+
+        body.update_world_transforms(Affine3A::IDENTITY);
+
+        let obb = body.world_obb("arm").unwrap();
+        assert!(obb.contains_point(Vec3::new(2.0, 0.6, 0.0)));
+        assert!(!obb.contains_point(Vec3::new(2.5, 0.5, 0.0)));
+
+        // Rotated unit cube spans sqrt(2)/2 along X
+        let aabb = body.world_aabb().unwrap();
+        let half_diag = std::f32::consts::SQRT_2 * 0.5;
+        assert!(aabb.min.abs_diff_eq(Vec3::splat(-1.0), 1e-5));
+        assert!((aabb.max.x - (2.0 + half_diag)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_set_local_rotation_respects_joint() {
+        use crate::joint::PartJoint;
+
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("upper", Vec3::ZERO, Vec3::ONE))
+            .unwrap();
+        body.add_part(
+            BlockPart::with_parent("lower", Vec3::new(0.0, -1.0, 0.0), Vec3::ONE, "upper")
+                .with_joint(PartJoint::revolute(0, 0.0, 2.5)),
+        )
+        .unwrap();
+
+        // Over the limit: clamped to 2.5 rad
+        let applied = body
+            .set_local_rotation("lower", Quat::from_rotation_x(3.0))
+            .unwrap();
+        assert!(applied.abs_diff_eq(Quat::from_rotation_x(2.5), 1e-5));
+        assert_eq!(body.get_part_by_name("lower").unwrap().rotation, applied);
+
+        // Parts without joints are free
+        let free = Quat::from_rotation_y(1.0);
+        assert_eq!(body.set_local_rotation("upper", free).unwrap(), free);
+
+        assert!(body.set_local_rotation("missing", free).is_err());
+
+        let joints = body.joints();
+        assert_eq!(joints.len(), 1);
+        assert_eq!(joints[0].parent_id, "upper");
+        assert_eq!(joints[0].child_id, "lower");
+    }
 }
@@ -0,0 +1,143 @@
+// crates/block-bodies-core/src/bounds.rs
+//
+// Ограничивающие объёмы частей в мировых координатах.
+
+use glam::{Affine3A, Quat, Vec3};
+
+/// Axis-aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Smallest box containing all points (None for an empty iterator)
+    pub fn from_points(points: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |aabb, p| Self {
+            min: aabb.min.min(p),
+            max: aabb.max.max(p),
+        }))
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+}
+
+/// Oriented bounding box of a single part
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb {
+    pub center: Vec3,
+    pub rotation: Quat,
+    pub half_extents: Vec3,
+}
+
+impl Obb {
+    /// Box of `size` centered at the origin of `transform` (scale is applied to the extents)
+    pub fn from_transform(transform: &Affine3A, size: Vec3) -> Self {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        Self {
+            center: translation,
+            rotation,
+            half_extents: size * scale.abs() * 0.5,
+        }
+    }
+
+    /// Local X, Y, Z axes in world space
+    pub fn axes(&self) -> [Vec3; 3] {
+        [
+            self.rotation * Vec3::X,
+            self.rotation * Vec3::Y,
+            self.rotation * Vec3::Z,
+        ]
+    }
+
+    /// The eight corners in world space
+    pub fn corners(&self) -> [Vec3; 8] {
+        let [x, y, z] = self.axes();
+        let (x, y, z) = (
+            x * self.half_extents.x,
+            y * self.half_extents.y,
+            z * self.half_extents.z,
+        );
+        let c = self.center;
+        [
+            c - x - y - z,
+            c + x - y - z,
+            c - x + y - z,
+            c + x + y - z,
+            c - x - y + z,
+            c + x - y + z,
+            c - x + y + z,
+            c + x + y + z,
+        ]
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        let local = self.rotation.inverse() * (point - self.center);
+        local.abs().cmple(self.half_extents).all()
+    }
+
+    pub fn aabb(&self) -> Aabb {
+        // Проекция полуразмеров на мировые оси
+        let [x, y, z] = self.axes();
+        let extent = x.abs() * self.half_extents.x
+            + y.abs() * self.half_extents.y
+            + z.abs() * self.half_extents.z;
+        Aabb::new(self.center - extent, self.center + extent)
+    }
+
+    /// Half-size of the box projected onto `axis`
+    fn projected_radius(&self, axis: Vec3) -> f32 {
+        let [x, y, z] = self.axes();
+        x.dot(axis).abs() * self.half_extents.x
+            + y.dot(axis).abs() * self.half_extents.y
+            + z.dot(axis).abs() * self.half_extents.z
+    }
+
+    /// Separating axis test; boxes that only touch do not intersect
+    pub fn intersects(&self, other: &Obb) -> bool {
+        let a = self.axes();
+        let b = other.axes();
+        let offset = other.center - self.center;
+
+        let mut axes = Vec::with_capacity(15);
+        axes.extend(a);
+        axes.extend(b);
+        for x in a {
+            for y in b {
+                // Параллельные рёбра дают нулевую ось — она уже проверена гранями
+                let cross = x.cross(y);
+                if cross.length_squared() > 1e-6 {
+                    axes.push(cross.normalize());
+                }
+            }
+        }
+
+        axes.into_iter().all(|axis| {
+            offset.dot(axis).abs() < self.projected_radius(axis) + other.projected_radius(axis)
+        })
+    }
+}
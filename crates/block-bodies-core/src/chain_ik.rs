@@ -0,0 +1,439 @@
+// crates/block-bodies-core/src/chain_ik.rs
+//
+// Многозвенный IK (FABRIK и CCD) поверх цепочек BlockBody.
+// Цепочка — имена частей от корня к концу, каждая следующая — ребёнок предыдущей.
+// Сустав части — её центр: ротация части двигает всех её потомков.
+// Перед вызовом мировые трансформы должны быть актуальны (update_world_transforms).
+
+use crate::{BlockBody, BlockBodyError};
+use glam::{Quat, Vec3};
+
+/// Настройки сходимости
+#[derive(Debug, Clone, Copy)]
+pub struct ChainIkSettings {
+    /// Расстояние до цели, при котором решение считается найденным
+    pub tolerance: f32,
+    /// Максимум итераций
+    pub max_iterations: usize,
+}
+
+impl Default for ChainIkSettings {
+    fn default() -> Self {
+        Self {
+            tolerance: 0.01,
+            max_iterations: 16,
+        }
+    }
+}
+
+/// Результат решения цепочки
+#[derive(Debug, Clone, Copy)]
+pub struct ChainIkResult {
+    /// Итоговое расстояние от конца цепочки до цели
+    pub distance: f32,
+    /// Сколько итераций потрачено
+    pub iterations: usize,
+    /// Достигнута ли цель в пределах tolerance
+    pub target_reached: bool,
+}
+
+/// FABRIK: прямой/обратный проход по позициям суставов,
+/// затем позиции переводятся в ротации с учётом лимитов суставов.
+pub fn solve_fabrik(
+    body: &mut BlockBody,
+    chain: &[&str],
+    target: Vec3,
+    pole: Option<Vec3>,
+    settings: &ChainIkSettings,
+) -> Result<ChainIkResult, BlockBodyError> {
+    validate_chain(body, chain)?;
+    if let Some(pole) = pole {
+        orient_towards_pole(body, chain, target, pole)?;
+    }
+
+    let mut distance = end_distance(body, chain, target);
+    let mut iterations = 0;
+
+    while distance > settings.tolerance && iterations < settings.max_iterations {
+        iterations += 1;
+
+        let mut positions = joint_positions(body, chain);
+        let lengths: Vec<f32> = positions.windows(2).map(|w| w[0].distance(w[1])).collect();
+        let root = positions[0];
+        let last = positions.len() - 1;
+
+        // Назад: конец ставим в цель
+        positions[last] = target;
+        for i in (0..last).rev() {
+            let dir = (positions[i] - positions[i + 1]).normalize_or_zero();
+            positions[i] = positions[i + 1] + dir * lengths[i];
+        }
+
+        // Вперёд: корень возвращаем на место
+        positions[0] = root;
+        for i in 0..last {
+            let dir = (positions[i + 1] - positions[i]).normalize_or_zero();
+            positions[i + 1] = positions[i] + dir * lengths[i];
+        }
+
+        if let Some(pole) = pole {
+            apply_pole(&mut positions, pole);
+        }
+
+        // Позиции → ротации (лимиты могут не дать встать точно)
+        for i in 0..last {
+            let current = world_position(body, chain[i + 1]) - world_position(body, chain[i]);
+            let desired = positions[i + 1] - world_position(body, chain[i]);
+            rotate_part_towards(body, chain[i], current, desired)?;
+        }
+
+        distance = end_distance(body, chain, target);
+    }
+
+    Ok(ChainIkResult {
+        distance,
+        iterations,
+        target_reached: distance <= settings.tolerance,
+    })
+}
+
+/// CCD: от конца к корню каждый сустав поворачивается так,
+/// чтобы конец цепочки смотрел на цель.
+pub fn solve_ccd(
+    body: &mut BlockBody,
+    chain: &[&str],
+    target: Vec3,
+    pole: Option<Vec3>,
+    settings: &ChainIkSettings,
+) -> Result<ChainIkResult, BlockBodyError> {
+    validate_chain(body, chain)?;
+    if let Some(pole) = pole {
+        orient_towards_pole(body, chain, target, pole)?;
+    }
+
+    let end = chain[chain.len() - 1];
+    let mut distance = end_distance(body, chain, target);
+    let mut iterations = 0;
+
+    while distance > settings.tolerance && iterations < settings.max_iterations {
+        iterations += 1;
+
+        for &name in chain[..chain.len() - 1].iter().rev() {
+            let joint = world_position(body, name);
+            let current = world_position(body, end) - joint;
+            let desired = target - joint;
+            rotate_part_towards(body, name, current, desired)?;
+        }
+
+        distance = end_distance(body, chain, target);
+    }
+
+    Ok(ChainIkResult {
+        distance,
+        iterations,
+        target_reached: distance <= settings.tolerance,
+    })
+}
+
+/// Цепочка должна быть непрерывной: минимум две части, каждая — ребёнок предыдущей
+fn validate_chain(body: &BlockBody, chain: &[&str]) -> Result<(), BlockBodyError> {
+    let Some(first) = chain.first() else {
+        return Err(BlockBodyError::InvalidChain(String::new()));
+    };
+    if body.get_part_by_name(first).is_none() {
+        return Err(BlockBodyError::PartNotFound(first.to_string()));
+    }
+    if chain.len() < 2 {
+        return Err(BlockBodyError::InvalidChain(first.to_string()));
+    }
+
+    for pair in chain.windows(2) {
+        let child = body
+            .get_part_by_name(pair[1])
+            .ok_or_else(|| BlockBodyError::PartNotFound(pair[1].to_string()))?;
+        if child.parent.as_deref() != Some(pair[0]) {
+            return Err(BlockBodyError::InvalidChain(pair[1].to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+fn world_position(body: &BlockBody, name: &str) -> Vec3 {
+    body.world_position(name).unwrap_or(Vec3::ZERO)
+}
+
+fn joint_positions(body: &BlockBody, chain: &[&str]) -> Vec<Vec3> {
+    chain
+        .iter()
+        .map(|name| world_position(body, name))
+        .collect()
+}
+
+fn end_distance(body: &BlockBody, chain: &[&str], target: Vec3) -> f32 {
+    world_position(body, chain[chain.len() - 1]).distance(target)
+}
+
+/// Поворачивает часть в мире так, чтобы `current` совпал с `desired`
+fn rotate_part_towards(
+    body: &mut BlockBody,
+    name: &str,
+    current: Vec3,
+    desired: Vec3,
+) -> Result<(), BlockBodyError> {
+    if current.length_squared() < 1e-8 || desired.length_squared() < 1e-8 {
+        return Ok(());
+    }
+
+    let delta = Quat::from_rotation_arc(current.normalize(), desired.normalize());
+    rotate_part_world(body, name, delta)
+}
+
+/// Применяет мировой поворот `delta` к части, ограничивает лимитами сустава
+/// и обновляет трансформы потомков
+fn rotate_part_world(body: &mut BlockBody, name: &str, delta: Quat) -> Result<(), BlockBodyError> {
+    let part = body
+        .get_part_by_name(name)
+        .ok_or_else(|| BlockBodyError::PartNotFound(name.to_string()))?;
+    let (_, world_rotation, _) = part.world_transform.to_scale_rotation_translation();
+    let parent_rotation = world_rotation * part.rotation.inverse();
+
+    let local = parent_rotation.inverse() * delta * world_rotation;
+
+    body.set_local_rotation(name, local)?;
+    body.update_subtree_transforms(name)
+}
+
+/// Поворачивает корень цепочки вокруг оси корень→цель,
+/// чтобы изгиб цепочки смотрел в сторону pole
+fn orient_towards_pole(
+    body: &mut BlockBody,
+    chain: &[&str],
+    target: Vec3,
+    pole: Vec3,
+) -> Result<(), BlockBodyError> {
+    let positions = joint_positions(body, chain);
+    let root = positions[0];
+    let axis = (target - root).normalize_or_zero();
+    if axis == Vec3::ZERO {
+        return Ok(());
+    }
+
+    // Средняя точка цепочки относительно оси
+    let middle = positions[positions.len() / 2] - root;
+    let middle = middle - axis * middle.dot(axis);
+    let to_pole = pole - root;
+    let to_pole = to_pole - axis * to_pole.dot(axis);
+    if middle.length_squared() < 1e-6 || to_pole.length_squared() < 1e-6 {
+        return Ok(());
+    }
+
+    let angle = middle.normalize().angle_between(to_pole.normalize());
+    let sign = axis.dot(middle.cross(to_pole)).signum();
+    let twist = Quat::from_axis_angle(axis, angle * sign);
+
+    // Ось проходит через корень, так что это просто поворот корня цепочки в мире
+    rotate_part_world(body, chain[0], twist)
+}
+
+/// Каждый внутренний сустав поворачивается вокруг линии соседей к pole
+fn apply_pole(positions: &mut [Vec3], pole: Vec3) {
+    for i in 1..positions.len() - 1 {
+        let prev = positions[i - 1];
+        let next = positions[i + 1];
+        let axis = (next - prev).normalize_or_zero();
+        if axis == Vec3::ZERO {
+            continue;
+        }
+
+        let joint = positions[i] - prev;
+        let joint_perp = joint - axis * joint.dot(axis);
+        let to_pole = pole - prev;
+        let pole_perp = to_pole - axis * to_pole.dot(axis);
+        if pole_perp.length_squared() < 1e-8 {
+            continue;
+        }
+
+        // Прямая цепочка не может согнуться сама — подталкиваем сустав к pole
+        if joint_perp.length_squared() < 1e-8 {
+            positions[i] += pole_perp.normalize() * joint.length() * 0.05;
+            continue;
+        }
+
+        let rotation = Quat::from_rotation_arc(joint_perp.normalize(), pole_perp.normalize());
+        positions[i] = prev + rotation * joint;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joint::{JointLimits, PartJoint};
+    use crate::BlockPart;
+    use glam::Affine3A;
+
+    /// Хвост Jimbo: TailBase → TailMid → TailTip → TailSpike
+    fn tail() -> BlockBody {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("TailBase", Vec3::ZERO, Vec3::splat(0.5)))
+            .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "TailMid",
+            Vec3::new(0.0, -0.05, 0.55),
+            Vec3::splat(0.4),
+            "TailBase",
+        ))
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "TailTip",
+            Vec3::new(0.0, 0.0, 0.45),
+            Vec3::splat(0.35),
+            "TailMid",
+        ))
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "TailSpike",
+            Vec3::new(0.0, 0.2, 0.4),
+            Vec3::splat(0.3),
+            "TailTip",
+        ))
+        .unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+        body
+    }
+
+    const TAIL: [&str; 4] = ["TailBase", "TailMid", "TailTip", "TailSpike"];
+
+    #[test]
+    fn test_fabrik_reaches_target() {
+        let mut body = tail();
+        let target = Vec3::new(0.6, 0.5, 0.8);
+
+        let result =
+            solve_fabrik(&mut body, &TAIL, target, None, &ChainIkSettings::default()).unwrap();
+
+        assert!(result.target_reached, "distance {}", result.distance);
+        assert!(body.world_position("TailSpike").unwrap().distance(target) < 0.01);
+        // Корень остаётся на месте
+        assert!(body
+            .world_position("TailBase")
+            .unwrap()
+            .abs_diff_eq(Vec3::ZERO, 1e-5));
+    }
+
+    #[test]
+    fn test_ccd_reaches_target() {
+        let mut body = tail();
+        let target = Vec3::new(-0.4, 0.6, 0.9);
+
+        let settings = ChainIkSettings {
+            tolerance: 0.01,
+            max_iterations: 64,
+        };
+        let result = solve_ccd(&mut body, &TAIL, target, None, &settings).unwrap();
+
+        assert!(result.target_reached, "distance {}", result.distance);
+    }
+
+    #[test]
+    fn test_unreachable_target_stops_at_cap() {
+        let mut body = tail();
+        let settings = ChainIkSettings {
+            tolerance: 0.001,
+            max_iterations: 5,
+        };
+
+        let result =
+            solve_fabrik(&mut body, &TAIL, Vec3::new(0.0, 0.0, 10.0), None, &settings).unwrap();
+
+        assert!(!result.target_reached);
+        assert_eq!(result.iterations, 5);
+        // Цепочка вытягивается к цели
+        assert!(body.world_position("TailSpike").unwrap().z > 1.3);
+    }
+
+    #[test]
+    fn test_joint_limits_respected() {
+        let mut body = tail();
+        let limits = JointLimits::none()
+            .with_axis(0, -0.3, 0.3)
+            .with_axis(1, -0.3, 0.3)
+            .with_axis(2, -0.1, 0.1)
+            .with_twist_axis(2);
+        for name in ["TailMid", "TailTip"] {
+            body.get_part_by_name_mut(name).unwrap().joint =
+                Some(PartJoint::spherical(limits.clone()));
+        }
+
+        // Цель за спиной хвоста: с лимитами не достать
+        let target = Vec3::new(0.0, 0.0, -0.5);
+        let result =
+            solve_ccd(&mut body, &TAIL, target, None, &ChainIkSettings::default()).unwrap();
+        assert!(!result.target_reached);
+
+        for name in ["TailMid", "TailTip"] {
+            let rotation = body.get_part_by_name(name).unwrap().rotation;
+            let clamped = PartJoint::spherical(limits.clone()).clamp_rotation(rotation, rotation);
+            assert!(rotation.abs_diff_eq(clamped, 1e-4));
+        }
+    }
+
+    #[test]
+    fn test_pole_picks_bend_side() {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("a", Vec3::ZERO, Vec3::ONE))
+            .unwrap();
+        body.add_part(BlockPart::with_parent("b", Vec3::Z, Vec3::ONE, "a"))
+            .unwrap();
+        body.add_part(BlockPart::with_parent("c", Vec3::Z, Vec3::ONE, "b"))
+            .unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+
+        // Цель ближе длины цепочки — средний сустав должен уйти к pole (+X)
+        let result = solve_fabrik(
+            &mut body,
+            &["a", "b", "c"],
+            Vec3::new(0.0, 0.0, 1.2),
+            Some(Vec3::new(5.0, 0.0, 0.6)),
+            &ChainIkSettings::default(),
+        )
+        .unwrap();
+
+        assert!(result.target_reached);
+        assert!(body.world_position("b").unwrap().x > 0.5);
+    }
+
+    #[test]
+    fn test_worm_chain() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../assets/models/worm.model.ron"
+        );
+        let mut body = crate::BlockModelFile::load_from_file(path)
+            .unwrap()
+            .to_body()
+            .unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+
+        let names = body.get_chain_between("Head", "Tail").unwrap();
+        let chain: Vec<&str> = names.iter().map(String::as_str).collect();
+        assert_eq!(chain.len(), 6);
+
+        // Хвост тянется вбок, оставаясь в лимитах сегментов
+        let target = Vec3::new(1.5, 0.5, 3.5);
+        let result =
+            solve_fabrik(&mut body, &chain, target, None, &ChainIkSettings::default()).unwrap();
+        assert!(result.target_reached, "distance {}", result.distance);
+
+        assert!(matches!(
+            solve_ccd(
+                &mut body,
+                &["Head", "Seg2"],
+                target,
+                None,
+                &ChainIkSettings::default()
+            ),
+            Err(BlockBodyError::InvalidChain(_))
+        ));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::body::BlockBody;
+use glam::Vec3;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Size and position changes below this are ignored
+const DIFF_EPSILON: f32 = 1e-5;
+
+/// A part that moved to another parent
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reparented {
+    pub part: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// A part whose box changed size
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resized {
+    pub part: String,
+    pub from: Vec3,
+    pub to: Vec3,
+}
+
+/// Structural difference between two bodies, every list sorted by part name
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BodyDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub reparented: Vec<Reparented>,
+    pub resized: Vec<Resized>,
+    /// Same parent, different local offset or rotation
+    pub moved: Vec<String>,
+}
+
+impl BodyDiff {
+    /// What changed going from `old` to `new`
+    pub fn between(old: &BlockBody, new: &BlockBody) -> Self {
+        let names = |body: &BlockBody| -> BTreeSet<String> {
+            body.parts.values().map(|p| p.name.clone()).collect()
+        };
+        let old_names = names(old);
+        let new_names = names(new);
+
+        let mut diff = Self {
+            added: new_names.difference(&old_names).cloned().collect(),
+            removed: old_names.difference(&new_names).cloned().collect(),
+            ..Self::default()
+        };
+
+        for name in old_names.intersection(&new_names) {
+            let (Some(before), Some(after)) =
+                (old.get_part_by_name(name), new.get_part_by_name(name))
+            else {
+                continue;
+            };
+
+            if before.parent != after.parent {
+                diff.reparented.push(Reparented {
+                    part: name.clone(),
+                    from: before.parent.clone(),
+                    to: after.parent.clone(),
+                });
+            } else if !before.position.abs_diff_eq(after.position, DIFF_EPSILON)
+                || !before.rotation.abs_diff_eq(after.rotation, DIFF_EPSILON)
+            {
+                diff.moved.push(name.clone());
+            }
+
+            if !before.size.abs_diff_eq(after.size, DIFF_EPSILON) {
+                diff.resized.push(Resized {
+                    part: name.clone(),
+                    from: before.size,
+                    to: after.size,
+                });
+            }
+        }
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.reparented.is_empty()
+            && self.resized.is_empty()
+            && self.moved.is_empty()
+    }
+}
+
+impl BlockBody {
+    /// Structural difference from this body to `other`
+    pub fn diff(&self, other: &BlockBody) -> BodyDiff {
+        BodyDiff::between(self, other)
+    }
+}
+
+impl fmt::Display for BodyDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parent = |p: &Option<String>| p.clone().unwrap_or_else(|| "<root>".to_string());
+
+        let mut lines = Vec::new();
+        lines.extend(self.added.iter().map(|p| format!("+ {p}")));
+        lines.extend(self.removed.iter().map(|p| format!("- {p}")));
+        lines.extend(self.reparented.iter().map(|r| {
+            format!(
+                "~ {}: parent {} -> {}",
+                r.part,
+                parent(&r.from),
+                parent(&r.to)
+            )
+        }));
+        lines.extend(
+            self.resized
+                .iter()
+                .map(|r| format!("~ {}: size {} -> {}", r.part, r.from, r.to)),
+        );
+        lines.extend(self.moved.iter().map(|p| format!("~ {p}: moved")));
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockPart;
+    use glam::Quat;
+
+    fn body() -> BlockBody {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("Torso", Vec3::ZERO, Vec3::ONE))
+            .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "Arm",
+            Vec3::new(0.75, 0.0, 0.0),
+            Vec3::splat(0.5),
+            "Torso",
+        ))
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "Hand",
+            Vec3::new(0.5, 0.0, 0.0),
+            Vec3::splat(0.3),
+            "Arm",
+        ))
+        .unwrap();
+        body
+    }
+
+    #[test]
+    fn test_identical_bodies() {
+        let diff = body().diff(&body());
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn test_structural_changes() {
+        let old = body();
+        let mut new = body();
+        new.remove_part("Hand").unwrap();
+        new.add_part(BlockPart::with_parent(
+            "Hand",
+            Vec3::new(0.0, -0.5, 0.0),
+            Vec3::splat(0.3),
+            "Torso",
+        ))
+        .unwrap();
+        new.add_part(BlockPart::with_parent(
+            "Head",
+            Vec3::Y,
+            Vec3::splat(0.4),
+            "Torso",
+        ))
+        .unwrap();
+        new.get_part_by_name_mut("Arm").unwrap().size = Vec3::new(0.6, 0.5, 0.5);
+        new.set_local_rotation("Torso", Quat::from_rotation_y(0.5))
+            .unwrap();
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.added, vec!["Head"]);
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.reparented,
+            vec![Reparented {
+                part: "Hand".into(),
+                from: Some("Arm".into()),
+                to: Some("Torso".into()),
+            }]
+        );
+        assert_eq!(diff.resized.len(), 1);
+        assert_eq!(diff.resized[0].part, "Arm");
+        assert_eq!(diff.moved, vec!["Torso"]);
+
+        // The reverse diff swaps added and removed
+        let back = new.diff(&old);
+        assert_eq!(back.removed, vec!["Head"]);
+        assert!(back.added.is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let old = body();
+        let mut new = body();
+        new.remove_part("Hand").unwrap();
+
+        assert_eq!(old.diff(&new).to_string(), "- Hand");
+    }
+}
@@ -0,0 +1,210 @@
+use crate::body::BlockBody;
+use crate::error::BlockBodyError;
+use crate::model::BlockModelFile;
+use glam::Affine3A;
+use std::collections::{BTreeSet, HashMap};
+
+/// Per-part health and capabilities of a living body.
+///
+/// Only parts with `health` in the model can be severed. Damage to any other part goes to
+/// the nearest severable ancestor, so a hit on a hand counts against the whole arm.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BodyDamage {
+    health: HashMap<String, f32>,
+    part_capabilities: HashMap<String, Vec<String>>,
+    capabilities: BTreeSet<String>,
+}
+
+/// Result of severing a part
+#[derive(Debug, Clone)]
+pub struct SeveredPart {
+    /// The detached subtree; the severed part is its root and keeps its world transform
+    pub body: BlockBody,
+
+    /// Capabilities no remaining part provides any more
+    pub lost_capabilities: Vec<String>,
+}
+
+impl BodyDamage {
+    /// Health and capabilities as declared in the model
+    pub fn from_model(model: &BlockModelFile) -> Self {
+        let health = model
+            .parts
+            .iter()
+            .filter_map(|p| Some((p.name.clone(), p.health?)))
+            .collect();
+        let part_capabilities: HashMap<String, Vec<String>> = model
+            .parts
+            .iter()
+            .filter(|p| !p.capabilities.is_empty())
+            .map(|p| (p.name.clone(), p.capabilities.clone()))
+            .collect();
+        let capabilities = part_capabilities.values().flatten().cloned().collect();
+
+        Self {
+            health,
+            part_capabilities,
+            capabilities,
+        }
+    }
+
+    /// Remaining health of a severable part
+    pub fn health(&self, part: &str) -> Option<f32> {
+        self.health.get(part).copied()
+    }
+
+    pub fn is_severable(&self, part: &str) -> bool {
+        self.health.contains_key(part)
+    }
+
+    /// Check if any remaining part provides the capability
+    pub fn has_capability(&self, capability: &str) -> bool {
+        self.capabilities.contains(capability)
+    }
+
+    pub fn capabilities(&self) -> impl Iterator<Item = &str> {
+        self.capabilities.iter().map(String::as_str)
+    }
+
+    /// Apply damage to the part that was hit.
+    /// Returns the part to sever once its health runs out.
+    pub fn damage(&mut self, body: &BlockBody, part: &str, amount: f32) -> Option<String> {
+        let target = body
+            .get_chain_to_root(part)
+            .into_iter()
+            .map(|p| p.name.clone())
+            .find(|name| self.health.contains_key(name))?;
+
+        let health = self.health.get_mut(&target)?;
+        *health -= amount;
+        (*health <= 0.0).then_some(target)
+    }
+
+    /// Sever a part with its subtree from the body.
+    /// Severed parts stop counting towards health and capabilities.
+    pub fn sever(
+        &mut self,
+        body: &mut BlockBody,
+        part: &str,
+    ) -> Result<SeveredPart, BlockBodyError> {
+        let world = body
+            .world_transform(part)
+            .ok_or_else(|| BlockBodyError::PartNotFound(part.to_string()))?;
+
+        let mut severed = body.sever_at(part)?;
+
+        // The severed part becomes a root: its local transform is now its world transform
+        if let Some(root) = severed.get_part_by_name_mut(part) {
+            let (_, rotation, translation) = world.to_scale_rotation_translation();
+            root.position = translation;
+            root.rotation = rotation;
+        }
+        severed.update_world_transforms(Affine3A::IDENTITY);
+
+        for removed in severed.parts.values() {
+            self.health.remove(&removed.name);
+            self.part_capabilities.remove(&removed.name);
+        }
+
+        let remaining: BTreeSet<String> =
+            self.part_capabilities.values().flatten().cloned().collect();
+        let lost_capabilities = self.capabilities.difference(&remaining).cloned().collect();
+        self.capabilities = remaining;
+
+        Ok(SeveredPart {
+            body: severed,
+            lost_capabilities,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ModelPart;
+    use glam::{Quat, Vec3};
+
+    fn model() -> BlockModelFile {
+        let mut torso = ModelPart::new("Torso", Vec3::ZERO, Vec3::ONE);
+        torso.capabilities = vec!["walk".into()];
+
+        let mut arm = ModelPart::new("RightArm", Vec3::new(0.75, 0.0, 0.0), Vec3::splat(0.5));
+        arm.parent = Some("Torso".into());
+        arm.health = Some(20.0);
+        arm.capabilities = vec!["attack_right".into()];
+
+        let mut hand = ModelPart::new("RightHand", Vec3::new(0.5, 0.0, 0.0), Vec3::splat(0.3));
+        hand.parent = Some("RightArm".into());
+        hand.capabilities = vec!["grab".into()];
+
+        let mut left = ModelPart::new("LeftArm", Vec3::new(-0.75, 0.0, 0.0), Vec3::splat(0.5));
+        left.parent = Some("Torso".into());
+        left.health = Some(20.0);
+        left.capabilities = vec!["attack_left".into(), "grab".into()];
+
+        BlockModelFile {
+            version: crate::model::MODEL_SCHEMA_VERSION,
+            parts: vec![torso, arm, hand, left],
+        }
+    }
+
+    fn body() -> BlockBody {
+        let mut body = model().to_body().unwrap();
+        body.update_world_transforms(Affine3A::from_translation(Vec3::new(0.0, 2.0, 0.0)));
+        body
+    }
+
+    #[test]
+    fn test_damage_goes_to_severable_ancestor() {
+        let body = body();
+        let mut damage = BodyDamage::from_model(&model());
+
+        assert!(damage.is_severable("RightArm"));
+        assert!(!damage.is_severable("RightHand"));
+
+        assert_eq!(damage.damage(&body, "RightHand", 15.0), None);
+        assert_eq!(damage.health("RightArm"), Some(5.0));
+        assert_eq!(
+            damage.damage(&body, "RightHand", 5.0),
+            Some("RightArm".to_string())
+        );
+
+        // The torso can't be severed and has no severable ancestor
+        assert_eq!(damage.damage(&body, "Torso", 100.0), None);
+    }
+
+    #[test]
+    fn test_sever_loses_capabilities() {
+        let mut body = body();
+        let mut damage = BodyDamage::from_model(&model());
+        assert!(damage.has_capability("attack_right"));
+
+        let severed = damage.sever(&mut body, "RightArm").unwrap();
+
+        assert_eq!(severed.body.parts.len(), 2);
+        assert!(body.get_part_by_name("RightHand").is_none());
+        assert_eq!(severed.lost_capabilities, vec!["attack_right".to_string()]);
+
+        // The left arm still grabs
+        assert!(damage.has_capability("grab"));
+        assert!(!damage.is_severable("RightArm"));
+        assert_eq!(damage.damage(&body, "Torso", 100.0), None);
+    }
+
+    #[test]
+    fn test_severed_part_stays_in_place() {
+        let mut body = body();
+        body.set_local_rotation("RightArm", Quat::from_rotation_z(0.5))
+            .unwrap();
+        body.update_world_transforms(Affine3A::from_translation(Vec3::new(0.0, 2.0, 0.0)));
+        let hand_before = body.world_position("RightHand").unwrap();
+
+        let mut damage = BodyDamage::from_model(&model());
+        let severed = damage.sever(&mut body, "RightArm").unwrap();
+
+        let arm = severed.body.get_part_by_name("RightArm").unwrap();
+        assert!(arm.parent.is_none());
+        let hand_after = severed.body.world_position("RightHand").unwrap();
+        assert!(hand_before.abs_diff_eq(hand_after, 1e-5));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::validation::ValidationReport;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -14,15 +15,34 @@ pub enum BlockBodyError {
     #[error("Circular dependency detected: part '{0}' cannot be its own ancestor")]
     CircularDependency(String),
 
+    #[error("Part index {0} is already taken")]
+    PartIndexTaken(u32),
+
     #[error("Invalid part name '{0}': names cannot be empty")]
     InvalidPartName(String),
 
+    #[error("Invalid chain: part '{0}' is not a child of the previous part")]
+    InvalidChain(String),
+
+    #[error("Invalid body:\n{0}")]
+    InvalidBody(ValidationReport),
+
+    #[error("Unsupported model schema version {0}")]
+    UnsupportedModelVersion(u32),
+
+    #[error("Unsupported body format: {0}")]
+    UnsupportedFormat(String),
+
     #[error("Serialization error: {0}")]
     SerializationError(#[from] ron::Error),
 
     #[error("Serialization error: {0}")]
     SpannedError(#[from] ron::error::SpannedError),
 
+    #[cfg(feature = "binary")]
+    #[error("Binary serialization error: {0}")]
+    BinaryError(#[from] bincode::Error),
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 }
@@ -3,6 +3,7 @@
 // Two-Bone Inverse Kinematics Solver
 // Используется для рук, ног, и любых двухзвенных цепочек.
 
+use crate::joint::PartJoint;
 use glam::{Quat, Vec3};
 
 /// Результат IK решения для двухзвенной цепочки
@@ -18,6 +19,21 @@ pub struct TwoBoneIkResult {
     pub target_reached: bool,
 }
 
+impl TwoBoneIkResult {
+    /// Ограничивает локальную ротацию нижнего сегмента лимитами сустава (локоть/колено).
+    /// Если ротацию пришлось обрезать, цель считается недостигнутой.
+    pub fn with_lower_limits(mut self, joint: &PartJoint) -> Self {
+        let clamped = joint.clamp_rotation(self.lower_rotation, self.lower_rotation);
+        if !clamped.abs_diff_eq(self.lower_rotation, 1e-4)
+            && !clamped.abs_diff_eq(-self.lower_rotation, 1e-4)
+        {
+            self.lower_rotation = clamped;
+            self.target_reached = false;
+        }
+        self
+    }
+}
+
 /// Решает 2-bone IK для цепочки root → middle → end
 pub fn solve_two_bone_ik(
     root_pos: Vec3,
@@ -186,4 +202,30 @@ mod tests {
         );
         assert!(!result.target_reached);
     }
+
+    #[test]
+    fn test_elbow_limits() {
+        let result = solve_arm_ik(
+            Vec3::ZERO,
+            0.3,
+            0.3,
+            Vec3::new(0.0, 0.0, -0.3),
+            Vec3::new(0.0, -1.0, 0.0),
+        );
+        assert!(result.target_reached);
+
+        // Локоть не даёт согнуться больше чем на 30°
+        let bend = (result.lower_rotation * Vec3::NEG_Z).angle_between(Vec3::NEG_Z);
+        let limit = bend * 0.5;
+        let elbow = PartJoint::revolute(0, -limit, limit);
+        let clamped = result.with_lower_limits(&elbow);
+
+        assert!(!clamped.target_reached);
+        let clamped_bend = (clamped.lower_rotation * Vec3::NEG_Z).angle_between(Vec3::NEG_Z);
+        assert!((clamped_bend - limit).abs() < 1e-3);
+
+        // Свободный локоть ничего не меняет
+        let free = PartJoint::revolute(0, -std::f32::consts::PI, std::f32::consts::PI);
+        assert!(result.with_lower_limits(&free).target_reached);
+    }
 }
@@ -1,4 +1,6 @@
+use glam::{Quat, Vec3};
 use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
 
 /// Types of joints that can connect parts
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -27,8 +29,10 @@ pub struct Joint {
     pub limits: JointLimits,
 }
 
-/// Constraints on joint movement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Constraints on joint movement.
+/// Angles are measured from the identity local rotation (the part's frame in its parent).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct JointLimits {
     /// Minimum rotation angles (radians) for each axis
     pub min_rotation: [f32; 3],
@@ -38,6 +42,19 @@ pub struct JointLimits {
 
     /// Whether each axis is constrained
     pub constrained: [bool; 3],
+
+    /// Axis the part twists around (0=X, 1=Y, 2=Z), usually along the bone.
+    /// The other two axes limit the swing.
+    pub twist_axis: usize,
+}
+
+/// Joint attached to a part, connecting it to its parent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartJoint {
+    pub joint_type: JointType,
+
+    #[serde(default)]
+    pub limits: JointLimits,
 }
 
 impl Joint {
@@ -59,10 +76,9 @@ impl Joint {
         max_angle: f32,
         axis: usize, // 0=X, 1=Y, 2=Z
     ) -> Self {
-        let mut limits = JointLimits::none();
-        limits.min_rotation[axis] = min_angle;
-        limits.max_rotation[axis] = max_angle;
-        limits.constrained[axis] = true;
+        let limits = JointLimits::none()
+            .with_axis(axis, min_angle, max_angle)
+            .with_twist_axis(axis);
 
         Self {
             parent_id: parent_id.into(),
@@ -81,15 +97,210 @@ impl Joint {
             limits: JointLimits::none(),
         }
     }
+
+    /// Per-part description of this joint (parent/child are implied by the hierarchy)
+    pub fn part_joint(&self) -> PartJoint {
+        PartJoint {
+            joint_type: self.joint_type,
+            limits: self.limits.clone(),
+        }
+    }
 }
 
 impl JointLimits {
     /// No constraints on any axis
     pub fn none() -> Self {
         Self {
-            min_rotation: [-std::f32::consts::PI; 3],
-            max_rotation: [std::f32::consts::PI; 3],
+            min_rotation: [-PI; 3],
+            max_rotation: [PI; 3],
             constrained: [false; 3],
+            twist_axis: 1,
+        }
+    }
+
+    /// Limit one axis to [min, max]
+    pub fn with_axis(mut self, axis: usize, min: f32, max: f32) -> Self {
+        self.min_rotation[axis] = min;
+        self.max_rotation[axis] = max;
+        self.constrained[axis] = true;
+        self
+    }
+
+    pub fn with_twist_axis(mut self, axis: usize) -> Self {
+        self.twist_axis = axis;
+        self
+    }
+
+    fn clamp_axis(&self, axis: usize, angle: f32) -> f32 {
+        if self.constrained[axis] {
+            angle.clamp(self.min_rotation[axis], self.max_rotation[axis])
+        } else {
+            angle
+        }
+    }
+
+    /// Hinge axis of a revolute joint: the first constrained axis, or the twist axis
+    fn hinge_axis(&self) -> usize {
+        self.constrained
+            .iter()
+            .position(|&c| c)
+            .unwrap_or(self.twist_axis)
+    }
+}
+
+impl Default for JointLimits {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl PartJoint {
+    pub fn fixed() -> Self {
+        Self {
+            joint_type: JointType::Fixed,
+            limits: JointLimits::none(),
+        }
+    }
+
+    /// Hinge around `axis` limited to [min, max]
+    pub fn revolute(axis: usize, min: f32, max: f32) -> Self {
+        Self {
+            joint_type: JointType::Revolute,
+            limits: JointLimits::none()
+                .with_axis(axis, min, max)
+                .with_twist_axis(axis),
+        }
+    }
+
+    pub fn spherical(limits: JointLimits) -> Self {
+        Self {
+            joint_type: JointType::Spherical,
+            limits,
+        }
+    }
+
+    /// Bring a local rotation within the joint's limits.
+    /// `current` is returned for fixed joints, which cannot move.
+    pub fn clamp_rotation(&self, current: Quat, requested: Quat) -> Quat {
+        let limits = &self.limits;
+        match self.joint_type {
+            JointType::Fixed => current,
+            JointType::Revolute => {
+                // Only the twist around the hinge survives
+                let axis = limits.hinge_axis();
+                let (_, twist) = swing_twist(requested, axis_vector(axis));
+                let angle = limits.clamp_axis(axis, twist_angle(twist, axis_vector(axis)));
+                Quat::from_axis_angle(axis_vector(axis), angle)
+            }
+            JointType::Spherical => {
+                let axis = limits.twist_axis.min(2);
+                let (swing, twist) = swing_twist(requested, axis_vector(axis));
+
+                let twist_angle = limits.clamp_axis(axis, twist_angle(twist, axis_vector(axis)));
+                let twist = Quat::from_axis_angle(axis_vector(axis), twist_angle);
+
+                // Swing as a rotation vector; clamp its components on the other two axes
+                let swing = if swing.w < 0.0 { -swing } else { swing };
+                let mut swing_vec = swing.to_scaled_axis();
+                for other in (0..3).filter(|&a| a != axis) {
+                    swing_vec[other] = limits.clamp_axis(other, swing_vec[other]);
+                }
+                swing_vec[axis] = 0.0;
+
+                (Quat::from_scaled_axis(swing_vec) * twist).normalize()
+            }
         }
     }
 }
+
+/// Splits `rotation` into `swing * twist`, where `twist` rotates around `axis`
+pub fn swing_twist(rotation: Quat, axis: Vec3) -> (Quat, Quat) {
+    let r = Vec3::new(rotation.x, rotation.y, rotation.z);
+    let p = axis * r.dot(axis);
+    let twist = Quat::from_xyzw(p.x, p.y, p.z, rotation.w);
+
+    // 180° swing: twist is undefined, treat it as none
+    if twist.length_squared() < 1e-8 {
+        return (rotation, Quat::IDENTITY);
+    }
+
+    let twist = twist.normalize();
+    (rotation * twist.inverse(), twist)
+}
+
+/// Signed angle of a twist quaternion around `axis`, in [-PI, PI]
+fn twist_angle(twist: Quat, axis: Vec3) -> f32 {
+    let sin = Vec3::new(twist.x, twist.y, twist.z).dot(axis);
+    let angle = 2.0 * sin.atan2(twist.w);
+    if angle > PI {
+        angle - 2.0 * PI
+    } else if angle < -PI {
+        angle + 2.0 * PI
+    } else {
+        angle
+    }
+}
+
+fn axis_vector(axis: usize) -> Vec3 {
+    match axis {
+        0 => Vec3::X,
+        1 => Vec3::Y,
+        _ => Vec3::Z,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swing_twist_recomposes() {
+        let q = Quat::from_rotation_y(0.7) * Quat::from_rotation_x(0.4);
+        let (swing, twist) = swing_twist(q, Vec3::X);
+        assert!((swing * twist).abs_diff_eq(q, 1e-5));
+        assert!((twist_angle(twist, Vec3::X) - 0.4).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_revolute_clamps_and_drops_swing() {
+        let elbow = PartJoint::revolute(0, 0.0, 2.0);
+
+        // Bending the wrong way stops at the limit
+        let bent_back = elbow.clamp_rotation(Quat::IDENTITY, Quat::from_rotation_x(-1.0));
+        assert!(bent_back.abs_diff_eq(Quat::IDENTITY, 1e-5));
+
+        // Off-axis component is removed
+        let requested = Quat::from_rotation_y(0.5) * Quat::from_rotation_x(1.0);
+        let clamped = elbow.clamp_rotation(Quat::IDENTITY, requested);
+        assert!(clamped.abs_diff_eq(Quat::from_rotation_x(1.0), 1e-5));
+    }
+
+    #[test]
+    fn test_spherical_limits_swing_and_twist() {
+        let neck = PartJoint::spherical(
+            JointLimits::none()
+                .with_axis(0, -0.5, 0.5)
+                .with_axis(1, -0.3, 0.3)
+                .with_axis(2, -0.5, 0.5),
+        );
+
+        let twisted = neck.clamp_rotation(Quat::IDENTITY, Quat::from_rotation_y(1.0));
+        assert!(twisted.abs_diff_eq(Quat::from_rotation_y(0.3), 1e-5));
+
+        let nodded = neck.clamp_rotation(Quat::IDENTITY, Quat::from_rotation_x(1.2));
+        assert!(nodded.abs_diff_eq(Quat::from_rotation_x(0.5), 1e-5));
+
+        // Within limits: untouched
+        let small = Quat::from_rotation_z(0.2);
+        assert!(neck
+            .clamp_rotation(Quat::IDENTITY, small)
+            .abs_diff_eq(small, 1e-5));
+    }
+
+    #[test]
+    fn test_fixed_keeps_current() {
+        let current = Quat::from_rotation_z(0.3);
+        let clamped = PartJoint::fixed().clamp_rotation(current, Quat::from_rotation_x(1.0));
+        assert_eq!(clamped, current);
+    }
+}
@@ -1,17 +1,44 @@
 // crates/block-bodies-core/src/lib.rs
 
+pub mod animation;
+pub mod bake;
+#[cfg(feature = "binary")]
+pub mod binary;
 pub mod body;
+pub mod bounds;
+pub mod chain_ik;
+pub mod diff;
+pub mod dismemberment;
 pub mod error;
 pub mod ik;
 pub mod joint;
+pub mod mirror;
+pub mod model;
 pub mod part;
+pub mod pose;
+pub mod ragdoll;
+pub mod retarget;
 pub mod serialization;
+pub mod validation;
 
+pub use animation::{AnimationClip, ClipPlayer, Easing, Keyframe};
+pub use bake::{BakedBody, BakedMesh, ColliderShape, CompoundCollider};
 pub use body::BlockBody;
+pub use bounds::{Aabb, Obb};
+pub use chain_ik::{solve_ccd, solve_fabrik, ChainIkResult, ChainIkSettings};
+pub use diff::{BodyDiff, Reparented, Resized};
+pub use dismemberment::{BodyDamage, SeveredPart};
 pub use error::BlockBodyError;
 pub use ik::{solve_arm_ik, solve_leg_ik, solve_two_bone_ik, TwoBoneIkResult};
-pub use joint::Joint;
+pub use joint::{Joint, JointLimits, JointType, PartJoint};
+pub use mirror::{MarkerPlacement, MirrorAxis, MirrorSettings, Side, SideNaming};
+pub use model::{BlockMaterial, BlockModelFile, MaterialPalette, ModelPart, SocketKind};
 pub use part::BlockPart;
+pub use pose::{BlockPose, PartPose, PoseMask};
+pub use ragdoll::{Ragdoll, RagdollJoint, RagdollPart, RagdollSettings};
+pub use retarget::RetargetMap;
+pub use serialization::{BlockBodyFile, BodyFormat};
+pub use validation::{Severity, ValidationIssue, ValidationReport};
 
 // Re-export types for convenience
 pub use glam::{Affine3A, Mat4, Quat, Vec3};
@@ -0,0 +1,374 @@
+use crate::joint::{JointLimits, PartJoint};
+use crate::pose::{BlockPose, PartPose};
+use crate::{BlockBody, BlockBodyError, BlockPart};
+use glam::{Quat, Vec3};
+use serde::{Deserialize, Serialize};
+
+/// Axis normal to the mirror plane (X mirrors left and right)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MirrorAxis {
+    #[default]
+    X,
+    Y,
+    Z,
+}
+
+/// Side of a part according to its name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Where the side marker goes in a part name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarkerPlacement {
+    /// `ArmL`, `arm_l`
+    Suffix,
+    /// `LeftArm`, `l_arm`
+    Prefix,
+}
+
+/// Naming convention for paired parts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SideNaming {
+    pub left: String,
+    pub right: String,
+    pub placement: MarkerPlacement,
+}
+
+/// How to mirror bodies and poses
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct MirrorSettings {
+    pub axis: MirrorAxis,
+    pub naming: SideNaming,
+}
+
+impl MirrorAxis {
+    fn index(self) -> usize {
+        match self {
+            MirrorAxis::X => 0,
+            MirrorAxis::Y => 1,
+            MirrorAxis::Z => 2,
+        }
+    }
+
+    /// Reflect a point or offset across the mirror plane
+    pub fn mirror_vec3(self, v: Vec3) -> Vec3 {
+        let mut v = v;
+        v[self.index()] = -v[self.index()];
+        v
+    }
+
+    /// Reflect a rotation: the axis component along the normal is kept,
+    /// the two in-plane components flip
+    pub fn mirror_rotation(self, q: Quat) -> Quat {
+        let axis = Vec3::new(q.x, q.y, q.z);
+        let mut v = -axis;
+        v[self.index()] = axis[self.index()];
+        Quat::from_xyzw(v.x, v.y, v.z, q.w)
+    }
+
+    /// Reflect joint limits: angles around the in-plane axes change sign
+    pub fn mirror_limits(self, limits: &JointLimits) -> JointLimits {
+        let mut mirrored = limits.clone();
+        for axis in 0..3 {
+            if axis != self.index() {
+                mirrored.min_rotation[axis] = -limits.max_rotation[axis];
+                mirrored.max_rotation[axis] = -limits.min_rotation[axis];
+            }
+        }
+        mirrored
+    }
+
+    fn mirror_joint(self, joint: &PartJoint) -> PartJoint {
+        PartJoint {
+            joint_type: joint.joint_type,
+            limits: self.mirror_limits(&joint.limits),
+        }
+    }
+}
+
+impl Default for SideNaming {
+    /// `ShoulderL` / `ShoulderR`, as in the bundled models
+    fn default() -> Self {
+        Self::suffix("L", "R")
+    }
+}
+
+impl SideNaming {
+    pub fn suffix(left: impl Into<String>, right: impl Into<String>) -> Self {
+        Self {
+            left: left.into(),
+            right: right.into(),
+            placement: MarkerPlacement::Suffix,
+        }
+    }
+
+    pub fn prefix(left: impl Into<String>, right: impl Into<String>) -> Self {
+        Self {
+            left: left.into(),
+            right: right.into(),
+            placement: MarkerPlacement::Prefix,
+        }
+    }
+
+    /// Side of a part, None for parts on the mirror plane
+    pub fn side(&self, name: &str) -> Option<Side> {
+        if self.strip(name, &self.left).is_some() {
+            Some(Side::Left)
+        } else if self.strip(name, &self.right).is_some() {
+            Some(Side::Right)
+        } else {
+            None
+        }
+    }
+
+    /// Name of the paired part; unpaired names map to themselves
+    pub fn mirror_name(&self, name: &str) -> String {
+        let swap = |base: &str, marker: &str| match self.placement {
+            MarkerPlacement::Suffix => format!("{base}{marker}"),
+            MarkerPlacement::Prefix => format!("{marker}{base}"),
+        };
+
+        if let Some(base) = self.strip(name, &self.left) {
+            swap(base, &self.right)
+        } else if let Some(base) = self.strip(name, &self.right) {
+            swap(base, &self.left)
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn strip<'a>(&self, name: &'a str, marker: &str) -> Option<&'a str> {
+        // The marker alone is not a side ("L" is not the left part of "")
+        let base = match self.placement {
+            MarkerPlacement::Suffix => name.strip_suffix(marker)?,
+            MarkerPlacement::Prefix => name.strip_prefix(marker)?,
+        };
+        (!base.is_empty()).then_some(base)
+    }
+}
+
+impl MirrorSettings {
+    fn mirror_part(&self, part: &BlockPart) -> BlockPart {
+        BlockPart {
+            name: self.naming.mirror_name(&part.name),
+            position: self.axis.mirror_vec3(part.position),
+            rotation: self.axis.mirror_rotation(part.rotation),
+            size: part.size,
+            parent: part.parent.as_ref().map(|p| self.naming.mirror_name(p)),
+            joint: part.joint.as_ref().map(|j| self.axis.mirror_joint(j)),
+            world_transform: Default::default(),
+        }
+    }
+
+    fn mirror_part_pose(&self, part: &PartPose) -> PartPose {
+        PartPose {
+            name: self.naming.mirror_name(&part.name),
+            offset: part.offset.map(|o| self.axis.mirror_vec3(o)),
+            rotation: self.axis.mirror_rotation(part.rotation),
+        }
+    }
+}
+
+impl BlockBody {
+    /// Mirror image of the whole body: geometry is reflected and paired parts swap names
+    pub fn mirrored(&self, settings: &MirrorSettings) -> Result<BlockBody, BlockBodyError> {
+        let mut mirrored = BlockBody::new();
+        for id in self.topological_order() {
+            if let Some(part) = self.get_part(id) {
+                mirrored.add_part(settings.mirror_part(part))?;
+            }
+        }
+        Ok(mirrored)
+    }
+
+    /// Rebuild the opposite side from `source`: parts of the other side are replaced
+    /// by mirrored copies, parts on the mirror plane are kept as they are
+    pub fn symmetrized(
+        &self,
+        source: Side,
+        settings: &MirrorSettings,
+    ) -> Result<BlockBody, BlockBodyError> {
+        let naming = &settings.naming;
+        let order: Vec<_> = self
+            .topological_order()
+            .into_iter()
+            .filter_map(|id| self.get_part(id))
+            .collect();
+
+        let mut symmetric = BlockBody::new();
+        for part in &order {
+            match naming.side(&part.name) {
+                Some(side) if side != source => {}
+                _ => {
+                    symmetric.add_part((*part).clone())?;
+                }
+            }
+        }
+        for part in &order {
+            if naming.side(&part.name) == Some(source) {
+                symmetric.add_part(settings.mirror_part(part))?;
+            }
+        }
+        Ok(symmetric)
+    }
+}
+
+impl BlockPose {
+    /// Mirror image of the pose: paired parts swap, rotations and offsets are reflected
+    pub fn mirrored(&self, settings: &MirrorSettings) -> BlockPose {
+        BlockPose {
+            parts: self
+                .parts
+                .iter()
+                .map(|p| settings.mirror_part_pose(p))
+                .collect(),
+        }
+    }
+
+    /// Copy the `source` side onto the other side, e.g. a left-arm pose onto both arms.
+    /// Parts on the mirror plane are kept.
+    pub fn symmetrized(&self, source: Side, settings: &MirrorSettings) -> BlockPose {
+        let naming = &settings.naming;
+        let mut parts: Vec<PartPose> = self
+            .parts
+            .iter()
+            .filter(|p| naming.side(&p.name).is_none_or(|side| side == source))
+            .cloned()
+            .collect();
+        let mirrored: Vec<PartPose> = parts
+            .iter()
+            .filter(|p| naming.side(&p.name) == Some(source))
+            .map(|p| settings.mirror_part_pose(p))
+            .collect();
+        parts.extend(mirrored);
+        BlockPose { parts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockModelFile;
+    use glam::Affine3A;
+
+    const JIMBO: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../assets/models/jimbo.model.ron"
+    );
+
+    fn jimbo() -> BlockBody {
+        let mut body = BlockModelFile::load_from_file(JIMBO)
+            .unwrap()
+            .to_body()
+            .unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+        body
+    }
+
+    #[test]
+    fn test_naming() {
+        let naming = SideNaming::default();
+        assert_eq!(naming.mirror_name("ShoulderL"), "ShoulderR");
+        assert_eq!(naming.mirror_name("HandR"), "HandL");
+        assert_eq!(naming.mirror_name("Torso"), "Torso");
+        assert_eq!(naming.mirror_name("L"), "L");
+        assert_eq!(naming.side("ArmL"), Some(Side::Left));
+        assert_eq!(naming.side("TailBase"), None);
+
+        let prefix = SideNaming::prefix("Left", "Right");
+        assert_eq!(prefix.mirror_name("LeftFoot"), "RightFoot");
+        assert_eq!(prefix.side("RightFoot"), Some(Side::Right));
+    }
+
+    #[test]
+    fn test_mirror_rotation_is_reflection() {
+        let axis = MirrorAxis::X;
+        let q = Quat::from_euler(glam::EulerRot::XYZ, 0.3, -0.7, 1.1);
+        let v = Vec3::new(0.2, 0.5, -0.9);
+
+        // Reflecting the rotated vector equals rotating the reflected one by the mirrored rotation
+        let expected = axis.mirror_vec3(q * v);
+        let actual = axis.mirror_rotation(q) * axis.mirror_vec3(v);
+        assert!(expected.abs_diff_eq(actual, 1e-5));
+    }
+
+    #[test]
+    fn test_mirrored_jimbo_matches_hand_authored_side() {
+        let body = jimbo();
+        let mut mirrored = body.mirrored(&MirrorSettings::default()).unwrap();
+        mirrored.update_world_transforms(Affine3A::IDENTITY);
+
+        // jimbo.ron is authored symmetric, so the mirror lands on the same places
+        for name in ["ShoulderL", "ArmL", "HandR", "Head", "TailSpike"] {
+            let original = body.world_position(name).unwrap();
+            let reflected = mirrored.world_position(name).unwrap();
+            assert!(original.abs_diff_eq(reflected, 1e-5), "{name}");
+        }
+        assert_eq!(
+            mirrored
+                .get_part_by_name("HandR")
+                .unwrap()
+                .parent
+                .as_deref(),
+            Some("ArmR")
+        );
+    }
+
+    #[test]
+    fn test_symmetrize_body_from_left() {
+        let mut body = jimbo();
+        body.get_part_by_name_mut("ArmL").unwrap().size = Vec3::new(0.3, 0.8, 0.3);
+
+        let mut symmetric = body
+            .symmetrized(Side::Left, &MirrorSettings::default())
+            .unwrap();
+        symmetric.update_world_transforms(Affine3A::IDENTITY);
+
+        assert_eq!(symmetric.parts.len(), body.parts.len());
+        assert_eq!(
+            symmetric.get_part_by_name("ArmR").unwrap().size,
+            Vec3::new(0.3, 0.8, 0.3)
+        );
+        let left = symmetric.world_position("HandL").unwrap();
+        let right = symmetric.world_position("HandR").unwrap();
+        assert!(MirrorAxis::X.mirror_vec3(left).abs_diff_eq(right, 1e-5));
+    }
+
+    #[test]
+    fn test_mirror_joint_limits() {
+        let limits = JointLimits::none().with_axis(1, -0.2, 1.0);
+        let mirrored = MirrorAxis::X.mirror_limits(&limits);
+        assert_eq!(mirrored.min_rotation[1], -1.0);
+        assert_eq!(mirrored.max_rotation[1], 0.2);
+        assert!(mirrored.constrained[1]);
+    }
+
+    #[test]
+    fn test_mirror_pose() {
+        let settings = MirrorSettings::default();
+        let raise = Quat::from_rotation_z(-1.2);
+        let pose = BlockPose {
+            parts: vec![
+                PartPose::new("ShoulderL", raise),
+                PartPose::new("Torso", Quat::from_rotation_y(0.4)),
+            ],
+        };
+
+        let mirrored = pose.mirrored(&settings);
+        let right = mirrored.get("ShoulderR").unwrap();
+        assert!(right.rotation.abs_diff_eq(Quat::from_rotation_z(1.2), 1e-5));
+        assert!(mirrored.get("ShoulderL").is_none());
+        let torso = mirrored.get("Torso").unwrap();
+        assert!(torso
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_y(-0.4), 1e-5));
+
+        let both = pose.symmetrized(Side::Left, &settings);
+        assert_eq!(both.parts.len(), 3);
+        assert_eq!(both.get("ShoulderL").unwrap().rotation, raise);
+        assert!(both.get("ShoulderR").is_some());
+    }
+}
@@ -0,0 +1,430 @@
+use crate::joint::PartJoint;
+use crate::serialization::BlockBodyFile;
+use crate::{BlockBody, BlockBodyError, BlockPart};
+use glam::{Quat, Vec3};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Current `BlockModelFile` schema version.
+///
+/// - 0: `(parts: {"Name": (name, pos, size)})` map without hierarchy
+/// - 1: `BlockModelFile(parts: [(name, parent, local_offset, size, material, socket)])`
+/// - 2: version 1 plus `version`, per-part `rotation` and `joint`
+pub const MODEL_SCHEMA_VERSION: u32 = 2;
+
+/// Material used when a part names none, or a name missing from the palette
+pub const DEFAULT_MATERIAL: &str = "default";
+
+/// Attachment point for equipment on a part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SocketKind {
+    Helmet,
+    Shield,
+    WeaponMain,
+}
+
+/// A part of a model file: geometry, hierarchy, material and optional socket
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelPart {
+    pub name: String,
+
+    #[serde(default)]
+    pub parent: Option<String>,
+
+    /// Local position relative to the parent (`position` in `BlockBodyFile` terms)
+    #[serde(alias = "position")]
+    pub local_offset: Vec3,
+
+    #[serde(default = "identity", skip_serializing_if = "is_identity")]
+    pub rotation: Quat,
+
+    pub size: Vec3,
+
+    #[serde(default = "default_material")]
+    pub material: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket: Option<SocketKind>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joint: Option<PartJoint>,
+
+    /// Health of the part before it is severed; None means it can't be severed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<f32>,
+
+    /// Capabilities the creature loses together with this part (e.g. "bite")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
+}
+
+/// Block model with named materials, stored as `BlockModelFile(version, parts: [...])`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockModelFile {
+    /// Schema version; files without it are version 1
+    #[serde(default = "legacy_version")]
+    pub version: u32,
+
+    pub parts: Vec<ModelPart>,
+}
+
+/// Version 0 layout: parts keyed by name, no parents
+#[derive(Debug, Deserialize)]
+struct ModelFileV0 {
+    parts: BTreeMap<String, ModelPartV0>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelPartV0 {
+    name: String,
+    pos: Vec3,
+    size: Vec3,
+}
+
+/// Render parameters of a named material
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockMaterial {
+    /// sRGB color with alpha
+    pub color: [f32; 4],
+
+    #[serde(default)]
+    pub metallic: f32,
+
+    #[serde(default = "default_roughness")]
+    pub roughness: f32,
+
+    #[serde(default)]
+    pub emissive: [f32; 3],
+}
+
+/// Named materials, stored as `MaterialPalette(materials: {"red": (color: ...)})`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaterialPalette {
+    pub materials: HashMap<String, BlockMaterial>,
+}
+
+impl ModelPart {
+    pub fn new(name: impl Into<String>, local_offset: Vec3, size: Vec3) -> Self {
+        Self {
+            name: name.into(),
+            parent: None,
+            local_offset,
+            rotation: Quat::IDENTITY,
+            size,
+            material: DEFAULT_MATERIAL.to_string(),
+            socket: None,
+            joint: None,
+            health: None,
+            capabilities: Vec::new(),
+        }
+    }
+
+    pub(crate) fn to_block_part(&self) -> BlockPart {
+        BlockPart {
+            name: self.name.clone(),
+            position: self.local_offset,
+            rotation: self.rotation,
+            size: self.size,
+            parent: self.parent.clone(),
+            joint: self.joint.clone(),
+            world_transform: Default::default(),
+        }
+    }
+
+    fn from_block_part(part: &BlockPart) -> Self {
+        Self {
+            name: part.name.clone(),
+            parent: part.parent.clone(),
+            local_offset: part.position,
+            rotation: part.rotation,
+            size: part.size,
+            material: DEFAULT_MATERIAL.to_string(),
+            socket: None,
+            joint: part.joint.clone(),
+            health: None,
+            capabilities: Vec::new(),
+        }
+    }
+}
+
+impl BlockModelFile {
+    /// Model from a body; all parts get the default material
+    pub fn from_body(body: &BlockBody) -> Self {
+        let parts = body
+            .topological_order()
+            .into_iter()
+            .filter_map(|id| body.get_part(id))
+            .map(ModelPart::from_block_part)
+            .collect();
+
+        Self {
+            version: MODEL_SCHEMA_VERSION,
+            parts,
+        }
+    }
+
+    /// Build the runtime body (materials and sockets stay in the model)
+    pub fn to_body(&self) -> Result<BlockBody, BlockBodyError> {
+        BlockBodyFile {
+            parts: self.parts.iter().map(ModelPart::to_block_part).collect(),
+            indices: Vec::new(),
+        }
+        .to_body()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ModelPart> {
+        self.parts.iter().find(|part| part.name == name)
+    }
+
+    /// Parts that carry an equipment socket
+    pub fn sockets(&self) -> impl Iterator<Item = (&str, SocketKind)> {
+        self.parts
+            .iter()
+            .filter_map(|part| Some((part.name.as_str(), part.socket?)))
+    }
+
+    /// Bring an older schema up to `MODEL_SCHEMA_VERSION`
+    pub fn migrate(mut self) -> Result<Self, BlockBodyError> {
+        if self.version > MODEL_SCHEMA_VERSION {
+            return Err(BlockBodyError::UnsupportedModelVersion(self.version));
+        }
+        // 1 → 2: new fields have defaults (identity rotation, no joint)
+        self.version = MODEL_SCHEMA_VERSION;
+        Ok(self)
+    }
+
+    /// Parse any known layout: `BlockModelFile` (v1/v2), `BlockBodyFile` or the v0 map
+    pub fn from_ron_str(content: &str) -> Result<Self, BlockBodyError> {
+        let model = match ron::from_str::<BlockModelFile>(content) {
+            Ok(model) => model,
+            Err(model_err) => {
+                if let Ok(body_file) = ron::from_str::<BlockBodyFile>(content) {
+                    Self::from_body_file(&body_file)
+                } else if let Ok(v0) = ron::from_str::<ModelFileV0>(content) {
+                    Self::from_v0(v0)
+                } else {
+                    return Err(model_err.into());
+                }
+            }
+        };
+
+        model.migrate()
+    }
+
+    fn from_body_file(body_file: &BlockBodyFile) -> Self {
+        Self {
+            version: MODEL_SCHEMA_VERSION,
+            parts: body_file
+                .parts
+                .iter()
+                .map(ModelPart::from_block_part)
+                .collect(),
+        }
+    }
+
+    fn from_v0(v0: ModelFileV0) -> Self {
+        Self {
+            version: 0,
+            parts: v0
+                .parts
+                .into_values()
+                .map(|part| ModelPart::new(part.name, part.pos, part.size))
+                .collect(),
+        }
+    }
+
+    /// Load from RON file, migrating older layouts
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, BlockBodyError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_ron_str(&content)
+    }
+
+    /// Save to RON file in the current schema
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), BlockBodyError> {
+        let model = Self {
+            version: MODEL_SCHEMA_VERSION,
+            parts: self.parts.clone(),
+        };
+        let content = to_string_pretty(&model, PrettyConfig::default().struct_names(true))?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+impl BlockMaterial {
+    pub fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self {
+            color: [r, g, b, 1.0],
+            metallic: 0.1,
+            roughness: default_roughness(),
+            emissive: [0.0; 3],
+        }
+    }
+}
+
+impl Default for BlockMaterial {
+    fn default() -> Self {
+        Self::rgb(0.6, 0.6, 0.6)
+    }
+}
+
+impl MaterialPalette {
+    /// Material by name; unknown names fall back to `DEFAULT_MATERIAL`, then to gray
+    pub fn resolve(&self, name: &str) -> BlockMaterial {
+        self.materials
+            .get(name)
+            .or_else(|| self.materials.get(DEFAULT_MATERIAL))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Material names used by `model` that the palette does not define
+    pub fn missing<'a>(&self, model: &'a BlockModelFile) -> Vec<&'a str> {
+        let mut missing: Vec<&str> = model
+            .parts
+            .iter()
+            .map(|part| part.material.as_str())
+            .filter(|name| !self.materials.contains_key(*name))
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, BlockBodyError> {
+        let content = std::fs::read_to_string(path)?;
+        let palette: MaterialPalette = ron::from_str(&content)?;
+        Ok(palette)
+    }
+}
+
+fn identity() -> Quat {
+    Quat::IDENTITY
+}
+
+fn is_identity(rotation: &Quat) -> bool {
+    *rotation == Quat::IDENTITY
+}
+
+fn default_material() -> String {
+    DEFAULT_MATERIAL.to_string()
+}
+
+fn legacy_version() -> u32 {
+    1
+}
+
+fn default_roughness() -> f32 {
+    0.8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    const ASSETS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets");
+
+    #[test]
+    fn test_legacy_jimbo_loads() -> Result<(), BlockBodyError> {
+        let model = BlockModelFile::load_from_file(format!("{}/models/jimbo.model.ron", ASSETS))?;
+        assert_eq!(model.version, MODEL_SCHEMA_VERSION);
+
+        let body = model.to_body()?;
+        assert_eq!(body.parts.len(), 14);
+        assert!(body
+            .get_chain_between("TailBase", "TailSpike")
+            .is_ok_and(|chain| chain.len() == 4));
+        assert_eq!(model.get("Torso").unwrap().material, "green");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_humanoid_sockets() -> Result<(), BlockBodyError> {
+        let model =
+            BlockModelFile::load_from_file(format!("{}/models/humanoid.model.ron", ASSETS))?;
+        let sockets: Vec<_> = model.sockets().collect();
+
+        assert!(sockets.contains(&("Head", SocketKind::Helmet)));
+        assert!(sockets.contains(&("HandL", SocketKind::Shield)));
+        assert!(sockets.contains(&("HandR", SocketKind::WeaponMain)));
+        model.to_body()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_other_layouts_migrate() -> Result<(), BlockBodyError> {
+        // BlockBodyFile with joints
+        let body_file = r#"BlockBodyFile(parts: [
+            (name: "Head", parent: None, position: (0.0, 0.5, 0.0),
+             rotation: (0.0, 0.0, 0.0, 1.0), size: (0.7, 0.7, 0.7)),
+            (name: "Seg1", parent: Some("Head"), position: (0.0, 0.0, 0.8),
+             rotation: (0.0, 0.0, 0.0, 1.0), size: (0.6, 0.6, 0.6),
+             joint: Some((joint_type: Spherical, limits: (
+                 min_rotation: (-0.7, -0.7, -0.17), max_rotation: (0.7, 0.7, 0.17),
+                 constrained: (true, true, true), twist_axis: 2)))),
+        ])"#;
+        let worm = BlockModelFile::from_ron_str(body_file)?;
+        assert_eq!(worm.get("Head").unwrap().material, DEFAULT_MATERIAL);
+        assert!(worm.get("Seg1").unwrap().joint.is_some());
+
+        // Version 0 map
+        let v0 =
+            r#"(parts: {"Head": (name: "Head", pos: (0.0, 1.0, 0.0), size: (1.0, 1.0, 1.0))})"#;
+        let model = BlockModelFile::from_ron_str(v0)?;
+        assert_eq!(model.version, MODEL_SCHEMA_VERSION);
+        assert_eq!(model.parts[0].local_offset, Vec3::Y);
+
+        // From the future
+        let future = r#"BlockModelFile(version: 99, parts: [])"#;
+        assert!(matches!(
+            BlockModelFile::from_ron_str(future),
+            Err(BlockBodyError::UnsupportedModelVersion(99))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_palette() -> Result<(), BlockBodyError> {
+        let palette = MaterialPalette::load_from_file(format!("{}/materials/palette.ron", ASSETS))?;
+
+        for name in ["jimbo", "humanoid", "worm"] {
+            let model =
+                BlockModelFile::load_from_file(format!("{}/models/{}.model.ron", ASSETS, name))?;
+            assert!(palette.missing(&model).is_empty(), "{}", name);
+        }
+
+        assert_eq!(
+            palette.resolve("no-such-material"),
+            palette.resolve(DEFAULT_MATERIAL)
+        );
+        assert_ne!(palette.resolve("red"), palette.resolve("green"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_roundtrip() -> Result<(), BlockBodyError> {
+        let mut head = ModelPart::new("Head", Vec3::Y, Vec3::ONE);
+        head.material = "red".to_string();
+        head.socket = Some(SocketKind::Helmet);
+        let model = BlockModelFile {
+            version: 1,
+            parts: vec![head],
+        };
+
+        let temp_file = NamedTempFile::new().unwrap();
+        model.save_to_file(temp_file.path())?;
+        let loaded = BlockModelFile::load_from_file(temp_file.path())?;
+
+        assert_eq!(loaded.version, MODEL_SCHEMA_VERSION);
+        assert_eq!(loaded.parts, model.parts);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use crate::bounds::Obb;
+use crate::joint::PartJoint;
 use glam::{Affine3A, Quat, Vec3};
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +21,10 @@ pub struct BlockPart {
     /// Optional parent part name (None for root parts)
     pub parent: Option<String>,
 
+    /// Joint to the parent; None means the rotation is unconstrained
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joint: Option<PartJoint>,
+
     /// World-space transform cache (updated by forward kinematics)
     /// Note: This field is not serialized as it's computed at runtime
     #[serde(skip)]
@@ -34,6 +40,7 @@ impl BlockPart {
             rotation: Quat::IDENTITY,
             size,
             parent: None,
+            joint: None,
             world_transform: Affine3A::IDENTITY,
         }
     }
@@ -51,15 +58,45 @@ impl BlockPart {
             rotation: Quat::IDENTITY,
             size,
             parent: Some(parent.into()),
+            joint: None,
             world_transform: Affine3A::IDENTITY,
         }
     }
 
+    /// Attach a joint to this part
+    pub fn with_joint(mut self, joint: PartJoint) -> Self {
+        self.joint = Some(joint);
+        self
+    }
+
+    /// Clamp a requested local rotation to the part's joint limits
+    pub fn constrain_rotation(&self, rotation: Quat) -> Quat {
+        match &self.joint {
+            Some(joint) => joint.clamp_rotation(self.rotation, rotation),
+            None => rotation,
+        }
+    }
+
+    /// Volume of the block
+    pub fn volume(&self) -> f32 {
+        self.size.x * self.size.y * self.size.z
+    }
+
     /// Get local transform matrix
     pub fn local_transform(&self) -> Affine3A {
         Affine3A::from_rotation_translation(self.rotation, self.position)
     }
 
+    /// World-space position of the part's center (valid after forward kinematics)
+    pub fn world_position(&self) -> Vec3 {
+        self.world_transform.translation.into()
+    }
+
+    /// World-space oriented box of the part (valid after forward kinematics)
+    pub fn world_obb(&self) -> Obb {
+        Obb::from_transform(&self.world_transform, self.size)
+    }
+
     /// Check if this is a root part (no parent)
     pub fn is_root(&self) -> bool {
         self.parent.is_none()
@@ -0,0 +1,426 @@
+use crate::{BlockBody, BlockBodyError};
+use glam::{Quat, Vec3};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Local transform of a single part within a pose
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartPose {
+    /// Name of the posed part
+    pub name: String,
+
+    /// Optional local position override, written as a plain tuple in RON
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_offset",
+        deserialize_with = "deserialize_offset"
+    )]
+    pub offset: Option<Vec3>,
+
+    /// Local rotation relative to the parent
+    pub rotation: Quat,
+}
+
+/// A set of local part rotations, stored as `BlockPose(parts: [(name, rotation)])`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlockPose {
+    pub parts: Vec<PartPose>,
+}
+
+/// Restricts pose application to a subset of parts (e.g. upper body only)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoseMask {
+    parts: HashSet<String>,
+}
+
+impl PartPose {
+    pub fn new(name: impl Into<String>, rotation: Quat) -> Self {
+        Self {
+            name: name.into(),
+            offset: None,
+            rotation,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+impl BlockPose {
+    /// Capture the current local rotations of every part, parents before children
+    pub fn capture(body: &BlockBody) -> Self {
+        let parts = body
+            .topological_order()
+            .into_iter()
+            .filter_map(|id| body.get_part(id))
+            .map(|part| PartPose::new(part.name.clone(), part.rotation))
+            .collect();
+
+        Self { parts }
+    }
+
+    /// Capture only the parts selected by `mask`
+    pub fn capture_masked(body: &BlockBody, mask: &PoseMask) -> Self {
+        let mut pose = Self::capture(body);
+        pose.parts.retain(|part| mask.contains(&part.name));
+        pose
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PartPose> {
+        self.parts.iter().find(|part| part.name == name)
+    }
+
+    /// Names in the pose that the body does not have
+    pub fn missing_parts(&self, body: &BlockBody) -> Vec<&str> {
+        self.parts
+            .iter()
+            .map(|part| part.name.as_str())
+            .filter(|name| body.get_part_by_name(name).is_none())
+            .collect()
+    }
+
+    /// Check that every part named in the pose exists in the body
+    pub fn validate(&self, body: &BlockBody) -> Result<(), BlockBodyError> {
+        match self.missing_parts(body).first() {
+            Some(name) => Err(BlockBodyError::PartNotFound(name.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Additive pose: rotation of each part relative to `reference`.
+    /// Parts missing from `reference` are taken relative to identity.
+    pub fn difference(&self, reference: &BlockPose) -> Self {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| {
+                let base = reference
+                    .get(&part.name)
+                    .map_or(Quat::IDENTITY, |base| base.rotation.normalize());
+                PartPose::new(
+                    part.name.clone(),
+                    base.inverse() * part.rotation.normalize(),
+                )
+            })
+            .collect();
+
+        Self { parts }
+    }
+
+    /// Parse a pose from RON text
+    pub fn from_ron_str(content: &str) -> Result<Self, BlockBodyError> {
+        Ok(ron::from_str(content)?)
+    }
+
+    /// Load from RON file
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, BlockBodyError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_ron_str(&content)
+    }
+
+    /// Save to RON file (keeps the `BlockPose(...)` header of hand-written files)
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), BlockBodyError> {
+        let config = PrettyConfig::default().struct_names(true);
+        let content = to_string_pretty(self, config)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+impl PoseMask {
+    pub fn from_parts<I, S>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            parts: parts.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// A part and all of its descendants
+    pub fn subtree(body: &BlockBody, root: &str) -> Self {
+        let mut mask = Self::from_parts(
+            body.get_all_descendants(root)
+                .into_iter()
+                .map(|part| part.name.clone()),
+        );
+        if body.get_part_by_name(root).is_some() {
+            mask.parts.insert(root.to_string());
+        }
+        mask
+    }
+
+    /// Remove a part and all of its descendants from the mask
+    pub fn without_subtree(mut self, body: &BlockBody, root: &str) -> Self {
+        for name in Self::subtree(body, root).parts {
+            self.parts.remove(&name);
+        }
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.parts.contains(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+}
+
+impl BlockBody {
+    /// Blend towards `pose` by `weight` (0 = unchanged, 1 = exact pose).
+    /// Rotations go through joint limits; fails without changes if a part is missing.
+    pub fn apply_pose(&mut self, pose: &BlockPose, weight: f32) -> Result<(), BlockBodyError> {
+        self.blend_pose(pose, weight, None, false)
+    }
+
+    /// Like `apply_pose`, but only for parts in `mask`
+    pub fn apply_pose_masked(
+        &mut self,
+        pose: &BlockPose,
+        weight: f32,
+        mask: &PoseMask,
+    ) -> Result<(), BlockBodyError> {
+        self.blend_pose(pose, weight, Some(mask), false)
+    }
+
+    /// Layer an additive pose (see `BlockPose::difference`) on top of the current one
+    pub fn apply_pose_additive(
+        &mut self,
+        pose: &BlockPose,
+        weight: f32,
+        mask: Option<&PoseMask>,
+    ) -> Result<(), BlockBodyError> {
+        self.blend_pose(pose, weight, mask, true)
+    }
+
+    fn blend_pose(
+        &mut self,
+        pose: &BlockPose,
+        weight: f32,
+        mask: Option<&PoseMask>,
+        additive: bool,
+    ) -> Result<(), BlockBodyError> {
+        pose.validate(self)?;
+        let weight = weight.clamp(0.0, 1.0);
+
+        for part_pose in &pose.parts {
+            if mask.is_some_and(|mask| !mask.contains(&part_pose.name)) {
+                continue;
+            }
+
+            let Some(part) = self.get_part_by_name(&part_pose.name) else {
+                continue;
+            };
+            let rotation = part_pose.rotation.normalize();
+            let current = part.rotation;
+            let target = if additive {
+                current * Quat::IDENTITY.slerp(rotation, weight)
+            } else {
+                current.slerp(rotation, weight)
+            };
+            let position = part_pose.offset.map(|offset| {
+                if additive {
+                    part.position + offset * weight
+                } else {
+                    part.position.lerp(offset, weight)
+                }
+            });
+
+            self.set_local_rotation(&part_pose.name, target)?;
+            if let (Some(position), Some(part)) =
+                (position, self.get_part_by_name_mut(&part_pose.name))
+            {
+                part.position = position;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn serialize_offset<S: Serializer>(
+    offset: &Option<Vec3>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match offset {
+        Some(offset) => offset.serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_offset<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec3>, D::Error> {
+    Vec3::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joint::PartJoint;
+    use crate::BlockPart;
+    use tempfile::NamedTempFile;
+
+    fn arm_body() -> BlockBody {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("Torso", Vec3::ZERO, Vec3::ONE))
+            .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "ShoulderL",
+            Vec3::new(-0.45, 0.3, 0.0),
+            Vec3::splat(0.2),
+            "Torso",
+        ))
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "ArmL",
+            Vec3::new(0.0, -0.3, 0.0),
+            Vec3::splat(0.2),
+            "ShoulderL",
+        ))
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "ThighL",
+            Vec3::new(-0.15, -0.5, 0.0),
+            Vec3::splat(0.2),
+            "Torso",
+        ))
+        .unwrap();
+        body
+    }
+
+    #[test]
+    fn test_shipped_poses_parse() {
+        let root = concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/poses");
+        let mut stack = vec![std::path::PathBuf::from(root)];
+        let mut count = 0;
+
+        while let Some(dir) = stack.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    stack.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "ron") {
+                    let pose = BlockPose::load_from_file(&path)
+                        .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+                    assert!(!pose.parts.is_empty(), "{}", path.display());
+                    count += 1;
+                }
+            }
+        }
+
+        assert!(count >= 17);
+    }
+
+    #[test]
+    fn test_apply_and_capture() {
+        let mut body = arm_body();
+        let raised = Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2);
+        let pose = BlockPose {
+            parts: vec![PartPose::new("ShoulderL", raised)],
+        };
+
+        body.apply_pose(&pose, 0.5).unwrap();
+        let half = body.get_part_by_name("ShoulderL").unwrap().rotation;
+        assert!(half.abs_diff_eq(Quat::IDENTITY.slerp(raised, 0.5), 1e-5));
+
+        body.apply_pose(&pose, 1.0).unwrap();
+        let captured = BlockPose::capture(&body);
+        assert_eq!(captured.parts.len(), 4);
+        assert_eq!(captured.parts[0].name, "Torso");
+        assert!(captured
+            .get("ShoulderL")
+            .unwrap()
+            .rotation
+            .abs_diff_eq(raised, 1e-5));
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut body = arm_body();
+        let pose = BlockPose {
+            parts: vec![
+                PartPose::new("ArmL", Quat::from_rotation_x(0.5)),
+                PartPose::new("TailBase", Quat::IDENTITY),
+            ],
+        };
+
+        assert_eq!(pose.missing_parts(&body), vec!["TailBase"]);
+        assert!(matches!(
+            body.apply_pose(&pose, 1.0),
+            Err(BlockBodyError::PartNotFound(name)) if name == "TailBase"
+        ));
+        // Nothing was applied
+        assert_eq!(
+            body.get_part_by_name("ArmL").unwrap().rotation,
+            Quat::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_masked_and_additive() {
+        let mut body = arm_body();
+        body.get_part_by_name_mut("ArmL").unwrap().joint = Some(PartJoint::revolute(0, 0.0, 1.0));
+
+        let bend = Quat::from_rotation_x(0.8);
+        let pose = BlockPose {
+            parts: vec![PartPose::new("ArmL", bend), PartPose::new("ThighL", bend)],
+        };
+
+        // Upper body only: everything except the leg
+        let upper = PoseMask::subtree(&body, "Torso").without_subtree(&body, "ThighL");
+        assert_eq!(upper.len(), 3);
+        body.apply_pose_masked(&pose, 1.0, &upper).unwrap();
+        assert!(body
+            .get_part_by_name("ArmL")
+            .unwrap()
+            .rotation
+            .abs_diff_eq(bend, 1e-5));
+        assert_eq!(
+            body.get_part_by_name("ThighL").unwrap().rotation,
+            Quat::IDENTITY
+        );
+
+        // Additive on top: 0.8 + 0.8 is clamped by the elbow limit
+        let delta = pose.difference(&BlockPose::default());
+        body.apply_pose_additive(&delta, 1.0, Some(&upper)).unwrap();
+        assert!(body
+            .get_part_by_name("ArmL")
+            .unwrap()
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_x(1.0), 1e-5));
+    }
+
+    #[test]
+    fn test_file_roundtrip() -> Result<(), BlockBodyError> {
+        let pose = BlockPose {
+            parts: vec![
+                PartPose::new("ThighL", Quat::from_rotation_x(0.2))
+                    .with_offset(Vec3::new(-0.15, 0.75, 0.2)),
+                PartPose::new("ArmL", Quat::IDENTITY),
+            ],
+        };
+
+        let temp_file = NamedTempFile::new().unwrap();
+        pose.save_to_file(temp_file.path())?;
+
+        let content = std::fs::read_to_string(temp_file.path())?;
+        assert!(content.starts_with("BlockPose("));
+
+        let loaded = BlockPose::load_from_file(temp_file.path())?;
+        assert_eq!(loaded, pose);
+
+        Ok(())
+    }
+}
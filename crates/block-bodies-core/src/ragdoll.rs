@@ -0,0 +1,304 @@
+use crate::body::BlockBody;
+use crate::joint::{JointLimits, PartJoint};
+use glam::{Affine3A, Quat, Vec3};
+use std::collections::HashMap;
+
+/// Parameters for turning a body into a physics ragdoll
+#[derive(Debug, Clone)]
+pub struct RagdollSettings {
+    /// Mass per unit of part volume
+    pub density: f32,
+
+    /// Lower bound for a part's mass, so thin plates don't explode the solver
+    pub min_mass: f32,
+
+    /// Joint used for parts that don't define one
+    pub fallback_joint: PartJoint,
+}
+
+impl Default for RagdollSettings {
+    fn default() -> Self {
+        Self {
+            density: 1.0,
+            min_mass: 0.05,
+            fallback_joint: PartJoint::spherical(
+                JointLimits::none()
+                    .with_axis(0, -0.6, 0.6)
+                    .with_axis(1, -0.6, 0.6)
+                    .with_axis(2, -0.6, 0.6),
+            ),
+        }
+    }
+}
+
+/// Joint between a ragdoll part and its parent.
+/// Anchors are in the local frames of the parent and the child and meet at the same world point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RagdollJoint {
+    pub joint: PartJoint,
+    pub anchor_on_parent: Vec3,
+    pub anchor_on_child: Vec3,
+
+    /// Child's rotation in the parent's frame where the joint angles are zero,
+    /// so the limit axes follow the part's orientation
+    pub rest_rotation: Quat,
+}
+
+/// One rigid body of a ragdoll
+#[derive(Debug, Clone, PartialEq)]
+pub struct RagdollPart {
+    pub name: String,
+    pub parent: Option<String>,
+
+    /// World-space placement of the part's center
+    pub translation: Vec3,
+    pub rotation: Quat,
+
+    pub half_extents: Vec3,
+    pub mass: f32,
+
+    /// None for root parts
+    pub joint: Option<RagdollJoint>,
+}
+
+/// Engine-agnostic description of a jointed ragdoll built from a body
+#[derive(Debug, Clone, Default)]
+pub struct Ragdoll {
+    /// Parts in topological order: a parent always comes before its children
+    pub parts: Vec<RagdollPart>,
+}
+
+impl Ragdoll {
+    /// Build a ragdoll from the body's current world transforms
+    /// (call `update_world_transforms` first)
+    pub fn from_body(body: &BlockBody, settings: &RagdollSettings) -> Self {
+        Self::from_body_posed(body, settings, &HashMap::new())
+    }
+
+    /// Build a ragdoll where some parts are placed by live world transforms
+    /// (e.g. taken from physics entities). Missing parts use the body's world transforms.
+    ///
+    /// Joints are anchored between the current part centers, so the ragdoll starts at rest
+    /// in whatever pose it was in.
+    pub fn from_body_posed(
+        body: &BlockBody,
+        settings: &RagdollSettings,
+        world: &HashMap<String, Affine3A>,
+    ) -> Self {
+        let mut placed: HashMap<String, Affine3A> = HashMap::new();
+        let mut parts = Vec::new();
+
+        for id in body.topological_order() {
+            let Some(part) = body.get_part(id) else {
+                continue;
+            };
+
+            let transform = world
+                .get(&part.name)
+                .copied()
+                .unwrap_or(part.world_transform);
+            let (_, rotation, translation) = transform.to_scale_rotation_translation();
+
+            let joint = part
+                .parent
+                .as_ref()
+                .and_then(|parent| placed.get(parent))
+                .map(|parent_transform| {
+                    // Pivot halfway between the centers, like the seam between two blocks
+                    let parent_center: Vec3 = parent_transform.translation.into();
+                    let pivot = (parent_center + translation) * 0.5;
+                    RagdollJoint {
+                        joint: part
+                            .joint
+                            .clone()
+                            .unwrap_or_else(|| settings.fallback_joint.clone()),
+                        anchor_on_parent: parent_transform.inverse().transform_point3(pivot),
+                        anchor_on_child: transform.inverse().transform_point3(pivot),
+                        rest_rotation: part.rotation,
+                    }
+                });
+
+            parts.push(RagdollPart {
+                name: part.name.clone(),
+                parent: part.parent.clone(),
+                translation,
+                rotation,
+                half_extents: part.size * 0.5,
+                mass: (part.volume() * settings.density).max(settings.min_mass),
+                joint,
+            });
+            placed.insert(part.name.clone(), transform);
+        }
+
+        Self { parts }
+    }
+
+    /// Find a part by name
+    pub fn get(&self, name: &str) -> Option<&RagdollPart> {
+        self.parts.iter().find(|p| p.name == name)
+    }
+
+    /// Sum of all part masses
+    pub fn total_mass(&self) -> f32 {
+        self.parts.iter().map(|p| p.mass).sum()
+    }
+
+    /// Mass-weighted center of the ragdoll in world space
+    pub fn center_of_mass(&self) -> Vec3 {
+        let total = self.total_mass();
+        if total <= 0.0 {
+            return Vec3::ZERO;
+        }
+        self.parts
+            .iter()
+            .map(|p| p.translation * p.mass)
+            .sum::<Vec3>()
+            / total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::joint::JointType;
+    use crate::BlockPart;
+
+    fn arm() -> BlockBody {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new(
+            "Torso",
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.5),
+        ))
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            "UpperArm",
+            Vec3::new(0.75, 0.0, 0.0),
+            Vec3::new(0.5, 0.2, 0.2),
+            "Torso",
+        ))
+        .unwrap();
+        body.add_part(
+            BlockPart::with_parent(
+                "Forearm",
+                Vec3::new(0.5, 0.0, 0.0),
+                Vec3::new(0.5, 0.2, 0.2),
+                "UpperArm",
+            )
+            .with_joint(PartJoint::revolute(1, 0.0, 2.5)),
+        )
+        .unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+        body
+    }
+
+    #[test]
+    fn test_mass_from_volume() {
+        let ragdoll = Ragdoll::from_body(&arm(), &RagdollSettings::default());
+
+        assert!((ragdoll.get("Torso").unwrap().mass - 0.5).abs() < 1e-5);
+        assert!((ragdoll.get("UpperArm").unwrap().mass - 0.05).abs() < 1e-5);
+        assert!((ragdoll.total_mass() - 0.6).abs() < 1e-5);
+
+        let dense = RagdollSettings {
+            density: 10.0,
+            ..Default::default()
+        };
+        let ragdoll = Ragdoll::from_body(&arm(), &dense);
+        assert!((ragdoll.get("Torso").unwrap().mass - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_min_mass() {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new(
+            "Plate",
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.01, 1.0),
+        ))
+        .unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+
+        let ragdoll = Ragdoll::from_body(&body, &RagdollSettings::default());
+        assert_eq!(ragdoll.parts[0].mass, 0.05);
+    }
+
+    #[test]
+    fn test_parents_first_and_joints() {
+        let ragdoll = Ragdoll::from_body(&arm(), &RagdollSettings::default());
+        let names: Vec<_> = ragdoll.parts.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["Torso", "UpperArm", "Forearm"]);
+
+        assert!(ragdoll.get("Torso").unwrap().joint.is_none());
+        // No joint in the model: the fallback spherical joint is used
+        let shoulder = ragdoll.get("UpperArm").unwrap().joint.as_ref().unwrap();
+        assert_eq!(shoulder.joint.joint_type, JointType::Spherical);
+        let elbow = ragdoll.get("Forearm").unwrap().joint.as_ref().unwrap();
+        assert_eq!(elbow.joint.joint_type, JointType::Revolute);
+    }
+
+    #[test]
+    fn test_anchors_meet_in_world() {
+        let mut body = arm();
+        body.set_local_rotation("Forearm", Quat::from_rotation_y(1.0))
+            .unwrap();
+        body.update_world_transforms(Affine3A::from_rotation_translation(
+            Quat::from_rotation_z(0.4),
+            Vec3::new(3.0, 0.0, -2.0),
+        ));
+
+        let ragdoll = Ragdoll::from_body(&body, &RagdollSettings::default());
+        for part in &ragdoll.parts {
+            let Some(joint) = &part.joint else {
+                continue;
+            };
+            let parent = ragdoll.get(part.parent.as_deref().unwrap()).unwrap();
+            let on_parent = parent.translation + parent.rotation * joint.anchor_on_parent;
+            let on_child = part.translation + part.rotation * joint.anchor_on_child;
+            assert!(on_parent.abs_diff_eq(on_child, 1e-4), "{}", part.name);
+        }
+
+        let elbow = ragdoll.get("Forearm").unwrap().joint.as_ref().unwrap();
+        assert!(elbow
+            .rest_rotation
+            .abs_diff_eq(Quat::from_rotation_y(1.0), 1e-5));
+    }
+
+    #[test]
+    fn test_posed_overrides() {
+        let body = arm();
+        let mut world = HashMap::new();
+        world.insert(
+            "Forearm".to_string(),
+            Affine3A::from_translation(Vec3::new(1.25, 0.5, 0.0)),
+        );
+
+        let ragdoll = Ragdoll::from_body_posed(&body, &RagdollSettings::default(), &world);
+        let forearm = ragdoll.get("Forearm").unwrap();
+        assert_eq!(forearm.translation, Vec3::new(1.25, 0.5, 0.0));
+
+        // The pivot sits halfway between the live centers
+        let joint = forearm.joint.as_ref().unwrap();
+        assert!(joint
+            .anchor_on_child
+            .abs_diff_eq(Vec3::new(-0.25, 0.25, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn test_worm_model_ragdoll() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../assets/models/worm.model.ron"
+        );
+        let mut body = crate::BlockModelFile::load_from_file(path)
+            .unwrap()
+            .to_body()
+            .unwrap();
+        body.update_world_transforms(Affine3A::IDENTITY);
+
+        let ragdoll = Ragdoll::from_body(&body, &RagdollSettings::default());
+        assert_eq!(ragdoll.parts.len(), 6);
+        assert!(ragdoll.parts.iter().skip(1).all(|p| p.joint.is_some()));
+        assert!(ragdoll.get("Head").unwrap().mass > ragdoll.get("Tail").unwrap().mass);
+    }
+}
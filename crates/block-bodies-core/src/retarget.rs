@@ -0,0 +1,249 @@
+use crate::pose::{BlockPose, PartPose};
+use crate::{BlockBody, BlockBodyError};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Offsets shorter than this are treated as zero-length bones
+const MIN_BONE_LENGTH: f32 = 1e-4;
+
+/// Source part name → target part name, stored as `RetargetMap(parts: {"ForearmL": "ArmL"})`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RetargetMap {
+    pub parts: BTreeMap<String, String>,
+}
+
+impl RetargetMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map every part that exists under the same name in both bodies
+    pub fn by_name(source: &BlockBody, target: &BlockBody) -> Self {
+        let parts = source
+            .parts
+            .values()
+            .filter(|p| target.get_part_by_name(&p.name).is_some())
+            .map(|p| (p.name.clone(), p.name.clone()))
+            .collect();
+        Self { parts }
+    }
+
+    /// Add or override a single mapping
+    pub fn with(mut self, source: impl Into<String>, target: impl Into<String>) -> Self {
+        self.parts.insert(source.into(), target.into());
+        self
+    }
+
+    pub fn target_of(&self, source: &str) -> Option<&str> {
+        self.parts.get(source).map(String::as_str)
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, BlockBodyError> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(ron::from_str(&content)?)
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), BlockBodyError> {
+        let config = PrettyConfig::new().struct_names(true);
+        let content = to_string_pretty(self, config)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+impl BlockPose {
+    /// Move a pose authored for `source` onto `target`.
+    ///
+    /// Rotations are taken relative to the source rest pose and reapplied on top of the
+    /// target rest pose, so skeletons with different rest orientations still match.
+    /// Offsets are scaled by the ratio of bone lengths. Parts without a mapping, or whose
+    /// mapping points at a missing part, are dropped.
+    pub fn retarget(
+        &self,
+        source: &BlockBody,
+        target: &BlockBody,
+        map: &RetargetMap,
+    ) -> Result<BlockPose, BlockBodyError> {
+        let mut parts = Vec::new();
+
+        for pose in &self.parts {
+            let Some(target_name) = map.target_of(&pose.name) else {
+                continue;
+            };
+            let source_part = source
+                .get_part_by_name(&pose.name)
+                .ok_or_else(|| BlockBodyError::PartNotFound(pose.name.clone()))?;
+            let Some(target_part) = target.get_part_by_name(target_name) else {
+                continue;
+            };
+
+            let delta = source_part.rotation.inverse() * pose.rotation;
+            let rotation = (target_part.rotation * delta).normalize();
+
+            let offset = pose.offset.map(|offset| {
+                let source_len = source_part.position.length();
+                let target_len = target_part.position.length();
+                if source_len < MIN_BONE_LENGTH {
+                    // No bone to compare with: move the target by the same amount
+                    target_part.position + (offset - source_part.position)
+                } else {
+                    offset * (target_len / source_len)
+                }
+            });
+
+            parts.push(PartPose {
+                name: target_name.to_string(),
+                offset,
+                rotation,
+            });
+        }
+
+        Ok(BlockPose { parts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockPart;
+    use glam::{Affine3A, Quat, Vec3};
+
+    fn arm(name: &str, upper: f32, lower: f32, rest: Quat) -> BlockBody {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("Torso", Vec3::ZERO, Vec3::ONE))
+            .unwrap();
+        let mut shoulder = BlockPart::with_parent(
+            format!("{name}Upper"),
+            Vec3::new(0.5, 0.0, 0.0),
+            Vec3::splat(0.2),
+            "Torso",
+        );
+        shoulder.rotation = rest;
+        body.add_part(shoulder).unwrap();
+        body.add_part(BlockPart::with_parent(
+            format!("{name}Lower"),
+            Vec3::new(0.0, -upper, 0.0),
+            Vec3::splat(0.2),
+            format!("{name}Upper"),
+        ))
+        .unwrap();
+        body.add_part(BlockPart::with_parent(
+            format!("{name}Hand"),
+            Vec3::new(0.0, -lower, 0.0),
+            Vec3::splat(0.1),
+            format!("{name}Lower"),
+        ))
+        .unwrap();
+        body
+    }
+
+    #[test]
+    fn test_by_name() {
+        let source = arm("Arm", 0.5, 0.4, Quat::IDENTITY);
+        let mut target = arm("Arm", 0.6, 0.6, Quat::IDENTITY);
+        target.remove_part("ArmHand").unwrap();
+
+        let map = RetargetMap::by_name(&source, &target);
+        assert_eq!(map.target_of("ArmUpper"), Some("ArmUpper"));
+        assert_eq!(map.target_of("ArmHand"), None);
+    }
+
+    #[test]
+    fn test_retarget_rotation_relative_to_rest() {
+        let source = arm("Arm", 0.5, 0.4, Quat::IDENTITY);
+        let target = arm("Limb", 0.5, 0.4, Quat::from_rotation_x(0.3));
+        let map = RetargetMap::new()
+            .with("ArmUpper", "LimbUpper")
+            .with("ArmLower", "LimbLower");
+
+        let raise = Quat::from_rotation_z(1.0);
+        let pose = BlockPose {
+            parts: vec![
+                PartPose::new("ArmUpper", raise),
+                PartPose::new("ArmLower", Quat::IDENTITY),
+                PartPose::new("Torso", Quat::IDENTITY),
+            ],
+        };
+
+        let retargeted = pose.retarget(&source, &target, &map).unwrap();
+        assert_eq!(retargeted.parts.len(), 2);
+
+        let upper = retargeted.get("LimbUpper").unwrap();
+        assert!(upper
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_x(0.3) * raise, 1e-5));
+        assert!(retargeted.validate(&target).is_ok());
+    }
+
+    #[test]
+    fn test_bone_length_compensation() {
+        let source = arm("Arm", 0.5, 0.4, Quat::IDENTITY);
+        let target = arm("Arm", 1.0, 0.8, Quat::IDENTITY);
+        let map = RetargetMap::by_name(&source, &target);
+
+        // Stretch the forearm out by 20% on the source
+        let pose = BlockPose {
+            parts: vec![
+                PartPose::new("ArmLower", Quat::IDENTITY).with_offset(Vec3::new(0.0, -0.6, 0.0))
+            ],
+        };
+
+        let retargeted = pose.retarget(&source, &target, &map).unwrap();
+        let offset = retargeted.get("ArmLower").unwrap().offset.unwrap();
+        assert!(offset.abs_diff_eq(Vec3::new(0.0, -1.2, 0.0), 1e-5));
+
+        // The hand reaches proportionally further on the bigger skeleton
+        let mut posed = target.clone();
+        posed.apply_pose(&retargeted, 1.0).unwrap();
+        posed.update_world_transforms(Affine3A::IDENTITY);
+        assert!((posed.world_position("ArmHand").unwrap().y - -2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_missing_source_part() {
+        let source = arm("Arm", 0.5, 0.4, Quat::IDENTITY);
+        let map = RetargetMap::new().with("Tail", "Tail");
+        let pose = BlockPose {
+            parts: vec![PartPose::new("Tail", Quat::IDENTITY)],
+        };
+
+        assert!(matches!(
+            pose.retarget(&source, &source, &map),
+            Err(BlockBodyError::PartNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_map_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("humanoid_to_jimbo.ron");
+        let map = RetargetMap::new()
+            .with("ForearmL", "ArmL")
+            .with("HandL", "HandL");
+
+        map.save_to_file(&path).unwrap();
+        assert_eq!(RetargetMap::load_from_file(&path).unwrap(), map);
+    }
+
+    #[test]
+    fn test_humanoid_pose_on_jimbo() {
+        let assets = concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/models");
+        let load = |name: &str| {
+            crate::BlockModelFile::load_from_file(format!("{assets}/{name}.model.ron"))
+                .unwrap()
+                .to_body()
+                .unwrap()
+        };
+        let humanoid = load("humanoid");
+        let jimbo = load("jimbo");
+
+        let map = RetargetMap::by_name(&humanoid, &jimbo);
+        let pose = BlockPose::capture(&humanoid);
+
+        let retargeted = pose.retarget(&humanoid, &jimbo, &map).unwrap();
+        assert!(retargeted.get("ShoulderL").is_some());
+        assert!(retargeted.validate(&jimbo).is_ok());
+    }
+}
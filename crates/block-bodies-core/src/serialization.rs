@@ -1,29 +1,83 @@
 use crate::{BlockBody, BlockBodyError, BlockPart};
 use ron::ser::{to_string_pretty, PrettyConfig};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
+/// Extension of RON body files
+pub const RON_EXTENSION: &str = "ron";
+/// Extension of binary body snapshots
+pub const BINARY_EXTENSION: &str = "bin";
+
 /// Serializable representation of a BlockBody for RON files
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockBodyFile {
     pub parts: Vec<BlockPart>,
+
+    /// Stable index of each entry in `parts`.
+    /// Empty in hand-written files: parts are then numbered in file order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indices: Vec<u32>,
+}
+
+/// On-disk format of a body, chosen by file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyFormat {
+    Ron,
+    /// Compact bincode snapshot, needs the `binary` feature
+    Binary,
+}
+
+impl BodyFormat {
+    pub fn from_path(path: &Path) -> Result<Self, BlockBodyError> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        match extension {
+            RON_EXTENSION => Ok(Self::Ron),
+            BINARY_EXTENSION => Ok(Self::Binary),
+            other => Err(BlockBodyError::UnsupportedFormat(format!(
+                "unknown extension '{other}'"
+            ))),
+        }
+    }
 }
 
 impl BlockBodyFile {
     /// Convert from runtime BlockBody to serializable format
     pub fn from_body(body: &BlockBody) -> Self {
-        let mut parts: Vec<BlockPart> = body.parts.values().cloned().collect();
+        let mut parts: Vec<(u32, BlockPart)> = body
+            .parts
+            .values()
+            .filter_map(|part| Some((body.part_index(&part.name)?, part.clone())))
+            .collect();
 
-        // Sort by name for consistent serialization
-        parts.sort_by(|a, b| a.name.cmp(&b.name));
+        // Sort by stable index for consistent serialization
+        parts.sort_by_key(|(index, _)| *index);
 
-        Self { parts }
+        let (indices, parts) = parts.into_iter().unzip();
+        Self { parts, indices }
     }
 
     /// Convert to runtime BlockBody from serializable format
     pub fn to_body(self) -> Result<BlockBody, BlockBodyError> {
+        // Report every authoring error at once instead of failing on the first
+        let report = self.validation_report();
+        if report.has_errors() {
+            return Err(BlockBodyError::InvalidBody(report));
+        }
+
         let mut body = BlockBody::new();
 
+        let indices: HashMap<String, u32> = if self.indices.len() == self.parts.len() {
+            let names = self.parts.iter().map(|p| p.name.clone());
+            names.zip(self.indices.iter().copied()).collect()
+        } else {
+            let names = self.parts.iter().map(|p| p.name.clone());
+            names.zip(0..).collect()
+        };
+
         // Sort parts to ensure parents are added before children
         let mut remaining_parts = self.parts;
         let mut added_parts = std::collections::HashSet::new();
@@ -44,7 +98,10 @@ impl BlockBodyFile {
             // Add all ready parts
             for part in ready_parts {
                 let part_name = part.name.clone();
-                body.add_part(part)?;
+                match indices.get(&part_name) {
+                    Some(&index) => body.add_part_with_index(part, index)?,
+                    None => body.add_part(part)?,
+                };
                 added_parts.insert(part_name);
             }
 
@@ -94,6 +151,49 @@ impl BlockBody {
         let body_file = BlockBodyFile::from_body(self);
         body_file.save_to_file(path)
     }
+
+    /// Save as RON (`.ron`) or a binary snapshot (`.bin`), picked by extension
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), BlockBodyError> {
+        let path = path.as_ref();
+        match BodyFormat::from_path(path)? {
+            BodyFormat::Ron => self.save_to_file(path),
+            BodyFormat::Binary => std::fs::write(path, self.binary_bytes()?).map_err(Into::into),
+        }
+    }
+
+    /// Load a body saved with `to_file`
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, BlockBodyError> {
+        let path = path.as_ref();
+        match BodyFormat::from_path(path)? {
+            BodyFormat::Ron => Self::load_from_file(path),
+            BodyFormat::Binary => Self::from_binary_bytes(&std::fs::read(path)?),
+        }
+    }
+
+    #[cfg(feature = "binary")]
+    fn binary_bytes(&self) -> Result<Vec<u8>, BlockBodyError> {
+        self.to_bytes()
+    }
+
+    #[cfg(feature = "binary")]
+    fn from_binary_bytes(bytes: &[u8]) -> Result<Self, BlockBodyError> {
+        Self::from_bytes(bytes)
+    }
+
+    #[cfg(not(feature = "binary"))]
+    fn binary_bytes(&self) -> Result<Vec<u8>, BlockBodyError> {
+        Err(binary_disabled())
+    }
+
+    #[cfg(not(feature = "binary"))]
+    fn from_binary_bytes(_bytes: &[u8]) -> Result<Self, BlockBodyError> {
+        Err(binary_disabled())
+    }
+}
+
+#[cfg(not(feature = "binary"))]
+fn binary_disabled() -> BlockBodyError {
+    BlockBodyError::UnsupportedFormat("binary bodies need the `binary` feature".to_string())
 }
 
 #[cfg(test)]
@@ -147,4 +247,79 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_joint_roundtrip() -> Result<(), BlockBodyError> {
+        use crate::joint::PartJoint;
+
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("torso", Vec3::ZERO, Vec3::ONE))?;
+        body.add_part(
+            BlockPart::with_parent("forearm", Vec3::NEG_Y, Vec3::ONE, "torso")
+                .with_joint(PartJoint::revolute(0, 0.0, 2.5)),
+        )?;
+
+        let temp_file = NamedTempFile::new().unwrap();
+        body.save_to_file(temp_file.path())?;
+        let loaded = BlockBody::load_from_file(temp_file.path())?;
+
+        assert_eq!(
+            loaded.get_part_by_name("forearm").unwrap().joint,
+            Some(PartJoint::revolute(0, 0.0, 2.5))
+        );
+        assert!(loaded.get_part_by_name("torso").unwrap().joint.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_indices_survive_roundtrip() -> Result<(), BlockBodyError> {
+        let mut body = BlockBody::new();
+        body.add_part(BlockPart::new("torso", Vec3::ZERO, Vec3::ONE))?;
+        body.add_part(BlockPart::with_parent("arm", Vec3::X, Vec3::ONE, "torso"))?;
+        body.add_part(BlockPart::with_parent("head", Vec3::Y, Vec3::ONE, "torso"))?;
+        let severed = body.sever_at("arm")?;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("body.ron");
+        body.to_file(&path)?;
+        let loaded = BlockBody::from_file(&path)?;
+        assert_eq!(loaded.part_index("head"), Some(2));
+        assert_eq!(loaded.part_index("torso"), Some(0));
+
+        severed.to_file(&path)?;
+        assert_eq!(BlockBody::from_file(&path)?.part_index("arm"), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_by_extension() {
+        assert_eq!(
+            BodyFormat::from_path(Path::new("a/worm.ron")).unwrap(),
+            BodyFormat::Ron
+        );
+        assert_eq!(
+            BodyFormat::from_path(Path::new("save/worm.bin")).unwrap(),
+            BodyFormat::Binary
+        );
+        assert!(matches!(
+            BlockBody::new().to_file("worm.json"),
+            Err(BlockBodyError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_worm_model_has_joints() -> Result<(), BlockBodyError> {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../assets/models/worm.model.ron"
+        );
+        let body = crate::BlockModelFile::load_from_file(path)?.to_body()?;
+
+        assert!(body.get_part_by_name("Head").unwrap().joint.is_none());
+        assert_eq!(body.joints().len(), body.parts.len() - 1);
+
+        Ok(())
+    }
 }
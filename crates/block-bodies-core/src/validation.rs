@@ -0,0 +1,420 @@
+use crate::body::BlockBody;
+use crate::bounds::Obb;
+use crate::part::BlockPart;
+use crate::serialization::BlockBodyFile;
+use crate::BlockModelFile;
+use glam::{Affine3A, Vec3};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// How far sibling boxes may sink into each other before it counts as overlap
+pub const OVERLAP_TOLERANCE: f32 = 1e-3;
+
+/// Allowed deviation of a rotation quaternion's length from 1
+const ROTATION_TOLERANCE: f32 = 1e-3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Loads fine but is probably an authoring mistake
+    Warning,
+    /// The body cannot be built or would behave incorrectly
+    Error,
+}
+
+/// A single problem found in a list of parts
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    EmptyName,
+    DuplicateName(String),
+    Orphan {
+        part: String,
+        parent: String,
+    },
+    /// Parts whose parent chain loops back on itself, starting from the smallest name
+    Cycle(Vec<String>),
+    InvalidSize {
+        part: String,
+        size: Vec3,
+    },
+    UnnormalizedRotation {
+        part: String,
+        length: f32,
+    },
+    OverlappingSiblings {
+        first: String,
+        second: String,
+    },
+}
+
+impl ValidationIssue {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::OverlappingSiblings { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "part with an empty name"),
+            Self::DuplicateName(name) => write!(f, "duplicate part name '{name}'"),
+            Self::Orphan { part, parent } => {
+                write!(f, "part '{part}' has missing parent '{parent}'")
+            }
+            Self::Cycle(parts) => write!(f, "parent cycle: {} -> {}", parts.join(" -> "), parts[0]),
+            Self::InvalidSize { part, size } => {
+                write!(f, "part '{part}' has non-positive size {size}")
+            }
+            Self::UnnormalizedRotation { part, length } => {
+                write!(
+                    f,
+                    "part '{part}' has a non-normalized rotation (length {length})"
+                )
+            }
+            Self::OverlappingSiblings { first, second } => {
+                write!(f, "sibling parts '{first}' and '{second}' overlap")
+            }
+        }
+    }
+}
+
+/// Every problem found in a body, not just the first one
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Check a flat list of parts as stored in a file.
+    /// Cycles and duplicates can only exist here: `BlockBody::add_part` rejects them.
+    pub fn check(parts: &[BlockPart]) -> Self {
+        let mut issues = Vec::new();
+
+        let mut by_name: HashMap<&str, &BlockPart> = HashMap::new();
+        let mut duplicates = HashSet::new();
+        for part in parts {
+            if part.name.is_empty() {
+                issues.push(ValidationIssue::EmptyName);
+            } else if by_name.insert(&part.name, part).is_some() && duplicates.insert(&part.name) {
+                issues.push(ValidationIssue::DuplicateName(part.name.clone()));
+            }
+        }
+
+        for part in parts {
+            if let Some(parent) = &part.parent {
+                if !by_name.contains_key(parent.as_str()) {
+                    issues.push(ValidationIssue::Orphan {
+                        part: part.name.clone(),
+                        parent: parent.clone(),
+                    });
+                }
+            }
+            if !part.size.cmpgt(Vec3::ZERO).all() {
+                issues.push(ValidationIssue::InvalidSize {
+                    part: part.name.clone(),
+                    size: part.size,
+                });
+            }
+            let length = part.rotation.length();
+            if (length - 1.0).abs() > ROTATION_TOLERANCE {
+                issues.push(ValidationIssue::UnnormalizedRotation {
+                    part: part.name.clone(),
+                    length,
+                });
+            }
+        }
+
+        issues.extend(
+            find_cycles(&by_name)
+                .into_iter()
+                .map(ValidationIssue::Cycle),
+        );
+        issues.extend(overlapping_siblings(parts, &by_name));
+
+        Self { issues }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.has_errors()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity() == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity() == Severity::Warning)
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let label = match issue.severity() {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            write!(f, "{label}: {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+impl BlockBody {
+    /// Full validation pass over the current parts
+    pub fn validation_report(&self) -> ValidationReport {
+        let parts: Vec<BlockPart> = self.parts.values().cloned().collect();
+        ValidationReport::check(&parts)
+    }
+}
+
+impl BlockBodyFile {
+    pub fn validation_report(&self) -> ValidationReport {
+        ValidationReport::check(&self.parts)
+    }
+}
+
+impl BlockModelFile {
+    pub fn validation_report(&self) -> ValidationReport {
+        let parts: Vec<BlockPart> = self.parts.iter().map(|p| p.to_block_part()).collect();
+        ValidationReport::check(&parts)
+    }
+}
+
+/// Each parent cycle once, rotated to start at its smallest name
+fn find_cycles(by_name: &HashMap<&str, &BlockPart>) -> Vec<Vec<String>> {
+    let mut cycles = Vec::new();
+    let mut done: HashSet<&str> = HashSet::new();
+
+    let mut names: Vec<&str> = by_name.keys().copied().collect();
+    names.sort_unstable();
+
+    for start in names {
+        let mut path: Vec<&str> = Vec::new();
+        let mut current = Some(start);
+
+        while let Some(name) = current {
+            if done.contains(name) {
+                break;
+            }
+            if let Some(index) = path.iter().position(|&p| p == name) {
+                let mut cycle: Vec<String> = path[index..].iter().map(|p| p.to_string()).collect();
+                let smallest = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+                cycle.rotate_left(smallest);
+                cycles.push(cycle);
+                break;
+            }
+            path.push(name);
+            current = by_name
+                .get(name)
+                .and_then(|part| part.parent.as_deref())
+                .filter(|parent| by_name.contains_key(parent));
+        }
+
+        done.extend(path);
+    }
+
+    cycles
+}
+
+/// Rest transform in body space; None for parts under a cycle or a missing parent
+fn rest_transform(part: &BlockPart, by_name: &HashMap<&str, &BlockPart>) -> Option<Affine3A> {
+    let mut transform = part.local_transform();
+    let mut current = part;
+
+    for _ in 0..by_name.len() {
+        let Some(parent) = &current.parent else {
+            return Some(transform);
+        };
+        current = by_name.get(parent.as_str())?;
+        transform = current.local_transform() * transform;
+    }
+
+    None
+}
+
+fn overlapping_siblings(
+    parts: &[BlockPart],
+    by_name: &HashMap<&str, &BlockPart>,
+) -> Vec<ValidationIssue> {
+    let mut siblings: BTreeMap<Option<&str>, Vec<(&str, Obb)>> = BTreeMap::new();
+    for part in parts {
+        let Some(transform) = rest_transform(part, by_name) else {
+            continue;
+        };
+        let mut obb = Obb::from_transform(&transform, part.size);
+        obb.half_extents = (obb.half_extents - OVERLAP_TOLERANCE).max(Vec3::ZERO);
+        siblings
+            .entry(part.parent.as_deref())
+            .or_default()
+            .push((&part.name, obb));
+    }
+
+    let mut issues = Vec::new();
+    for group in siblings.values() {
+        for (i, (first, a)) in group.iter().enumerate() {
+            for (second, b) in &group[i + 1..] {
+                if a.intersects(b) {
+                    issues.push(ValidationIssue::OverlappingSiblings {
+                        first: first.to_string(),
+                        second: second.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Quat;
+
+    fn parts() -> Vec<BlockPart> {
+        vec![
+            BlockPart::new("Torso", Vec3::ZERO, Vec3::ONE),
+            BlockPart::with_parent(
+                "ArmL",
+                Vec3::new(-0.75, 0.0, 0.0),
+                Vec3::splat(0.5),
+                "Torso",
+            ),
+            BlockPart::with_parent("ArmR", Vec3::new(0.75, 0.0, 0.0), Vec3::splat(0.5), "Torso"),
+        ]
+    }
+
+    #[test]
+    fn test_valid_parts() {
+        let report = ValidationReport::check(&parts());
+        assert_eq!(report.issues, vec![]);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_reports_every_problem() {
+        let mut parts = parts();
+        parts.push(BlockPart::with_parent(
+            "Hand",
+            Vec3::ZERO,
+            Vec3::ONE,
+            "Wrist",
+        ));
+        parts.push(BlockPart::with_parent(
+            "Foot",
+            Vec3::ZERO,
+            Vec3::ONE,
+            "Ankle",
+        ));
+        parts.push(BlockPart::new("ArmL", Vec3::ZERO, Vec3::ONE));
+        parts[0].size.y = 0.0;
+        parts[1].rotation = Quat::from_xyzw(0.0, 0.0, 0.0, 2.0);
+
+        let report = ValidationReport::check(&parts);
+        let orphans = report
+            .issues
+            .iter()
+            .filter(|issue| matches!(issue, ValidationIssue::Orphan { .. }))
+            .count();
+        assert_eq!(orphans, 2);
+        assert!(report
+            .issues
+            .contains(&ValidationIssue::DuplicateName("ArmL".into())));
+        assert!(report.issues.iter().any(
+            |issue| matches!(issue, ValidationIssue::InvalidSize { part, .. } if part == "Torso")
+        ));
+        assert!(report.issues.iter().any(
+            |issue| matches!(issue, ValidationIssue::UnnormalizedRotation { part, .. } if part == "ArmL")
+        ));
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn test_cycles() {
+        let mut parts = parts();
+        parts.push(BlockPart::with_parent("B", Vec3::ZERO, Vec3::ONE, "C"));
+        parts.push(BlockPart::with_parent("C", Vec3::ZERO, Vec3::ONE, "A"));
+        parts.push(BlockPart::with_parent("A", Vec3::ZERO, Vec3::ONE, "B"));
+        // Hangs off the cycle but is not part of it
+        parts.push(BlockPart::with_parent("D", Vec3::ZERO, Vec3::ONE, "A"));
+
+        let report = ValidationReport::check(&parts);
+        assert_eq!(
+            report.issues,
+            vec![ValidationIssue::Cycle(vec![
+                "A".into(),
+                "B".into(),
+                "C".into()
+            ])]
+        );
+        assert_eq!(report.to_string(), "error: parent cycle: A -> B -> C -> A");
+    }
+
+    #[test]
+    fn test_overlapping_siblings() {
+        let mut parts = parts();
+        parts[2].position.x = -0.5;
+
+        let report = ValidationReport::check(&parts);
+        assert_eq!(
+            report.issues,
+            vec![ValidationIssue::OverlappingSiblings {
+                first: "ArmL".into(),
+                second: "ArmR".into(),
+            }]
+        );
+        // Only a warning: the body still builds
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().count(), 1);
+    }
+
+    #[test]
+    fn test_body_file_reports_all_orphans() {
+        let mut parts = parts();
+        parts.push(BlockPart::with_parent(
+            "Hand",
+            Vec3::ZERO,
+            Vec3::ONE,
+            "Wrist",
+        ));
+        parts.push(BlockPart::with_parent(
+            "Foot",
+            Vec3::ZERO,
+            Vec3::ONE,
+            "Ankle",
+        ));
+
+        let Err(crate::BlockBodyError::InvalidBody(report)) = BlockBodyFile {
+            parts,
+            indices: Vec::new(),
+        }
+        .to_body() else {
+            panic!("expected an invalid body");
+        };
+        assert_eq!(report.errors().count(), 2);
+    }
+
+    #[test]
+    fn test_shipped_models_are_valid() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/models");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let model = BlockModelFile::load_from_file(&path).unwrap();
+            let report = model.validation_report();
+            assert!(report.is_valid(), "{}:\n{report}", path.display());
+        }
+    }
+}
@@ -10,6 +10,7 @@ use bevy_rapier3d::plugin::{NoUserData, RapierPhysicsPlugin};
 //use bevy_rapier3d::render::RapierDebugRenderPlugin;
 
 use crate::audio::plugin::SubAudioPlugin;
+use crate::block_body::BlockBodyPlugin;
 use crate::camera::plugin::CameraPlugin;
 use crate::core::fps_stats::FpsStatsPlugin;
 use crate::enemies::EnemiesPlugin;
@@ -20,6 +21,7 @@ use crate::inventory::InventoryPlugin;
 use crate::items::ItemsPlugin;
 use crate::player::PlayerArmPlugin;
 use crate::player::plugin::PlayerPlugin;
+use crate::save::SavePlugin;
 use crate::stats::plugin::StatsPlugin;
 use crate::ui::hud::plugin::HudUiPlugin;
 use crate::ui::{
@@ -60,8 +62,10 @@ pub fn run() {
         .add_plugins(CombatPlugin)
         .add_plugins(WorldPlugin)
         .add_plugins(EnemiesPlugin)
+        .add_plugins(BlockBodyPlugin)
         .add_plugins(ItemsPlugin)
         .add_plugins(InventoryPlugin)
+        .add_plugins(SavePlugin)
         .add_plugins(GameMenuPlugin)
         .add_plugins(SystemMenuPlugin)
         .add_plugins(MainMenuPlugin)
@@ -4,6 +4,9 @@ use bevy::prelude::*;
 pub enum AppState {
     #[default]
     MainMenu,
+    /// Один кадр между забегами при загрузке сохранения:
+    /// InGame → Loading → InGame заново запускает cleanup и init
+    Loading,
     InGame,
     Dead,
     Victory,
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+
+use crate::player::component::Player;
+use crate::stats::damage::DamageDodgedEvent;
+
+pub fn play_player_dodge_sfx(
+    mut evr: EventReader<DamageDodgedEvent>,
+    players: Query<(), With<Player>>,
+    audio: Res<Audio>,
+    asset_server: Res<AssetServer>,
+) {
+    for event in evr.read() {
+        if players.get(event.entity).is_err() {
+            continue;
+        }
+
+        // Отдельного звука уклонения нет — ускоренный свист броска
+        let sound = asset_server.load("sounds/weapons/cube_launch.wav");
+        audio.play(sound).with_volume(0.6).with_playback_rate(1.4);
+    }
+}
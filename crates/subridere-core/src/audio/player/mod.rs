@@ -1,2 +1,3 @@
 pub mod damage;
+pub mod dodge;
 pub mod events;
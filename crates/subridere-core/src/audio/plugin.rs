@@ -2,7 +2,9 @@ use bevy::prelude::*;
 
 use super::{
     impact::play_impact_sounds_system,
-    player::{damage::play_player_damage_sfx, events::PlayerDamageEvent},
+    player::{
+        damage::play_player_damage_sfx, dodge::play_player_dodge_sfx, events::PlayerDamageEvent,
+    },
     weapons::{
         events::{MagicBoltFireEvent, PhysicsCubeFireEvent},
         sounds::{play_magic_bolt_fire_sfx, play_physical_cube_fire_sfx},
@@ -32,6 +34,7 @@ impl Plugin for SubAudioPlugin {
                 (
                     play_impact_sounds_system,
                     play_player_damage_sfx,
+                    play_player_dodge_sfx,
                     play_worm_bite_sfx,
                     play_worm_hurt_sfx,
                     play_magic_bolt_fire_sfx,
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+
+use super::asset::BlockClipAsset;
+use super::components::{BlockBodyAnimator, BlockBodyClip, BlockBodyRig};
+
+/// Событие ключевого кадра (например "hit" на кадре удара)
+#[derive(Event, Debug, Clone)]
+pub struct BlockAnimationEvent {
+    pub entity: Entity,
+    pub name: String,
+}
+
+/// Вешает BlockBodyAnimator на собранные тела, чей клип уже загружен
+pub fn start_block_body_clips(
+    mut commands: Commands,
+    clips: Res<Assets<BlockClipAsset>>,
+    bodies: Query<(Entity, &BlockBodyClip), (With<BlockBodyRig>, Without<BlockBodyAnimator>)>,
+) {
+    for (entity, clip) in &bodies {
+        if let Some(asset) = clips.get(&clip.0) {
+            commands
+                .entity(entity)
+                .insert(BlockBodyAnimator::playing(asset.0.clone()));
+        }
+    }
+}
+
+/// Двигает клипы, применяет позу к BlockBody и переносит
+/// локальные трансформы частей на их сущности
+pub fn animate_block_bodies(
+    time: Res<Time>,
+    mut rigs: Query<(Entity, &mut BlockBodyAnimator, &mut BlockBodyRig)>,
+    mut part_transforms: Query<&mut Transform, Without<BlockBodyRig>>,
+    mut events: EventWriter<BlockAnimationEvent>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut animator, mut rig) in &mut rigs {
+        for name in animator.player.advance(dt) {
+            events.write(BlockAnimationEvent { entity, name });
+        }
+
+        let Some(pose) = animator.player.sample() else {
+            continue;
+        };
+
+        if let Err(err) = rig.body.apply_pose(&pose, 1.0) {
+            warn!("⚠️ Pose does not fit body on {:?}: {}", entity, err);
+            continue;
+        }
+
+        for part in &pose.parts {
+            let (Some(part_entity), Some(block)) =
+                (rig.part(&part.name), rig.body.get_part_by_name(&part.name))
+            else {
+                continue;
+            };
+            if let Ok(mut transform) = part_transforms.get_mut(part_entity) {
+                transform.translation = block.position;
+                transform.rotation = block.rotation;
+            }
+        }
+    }
+}
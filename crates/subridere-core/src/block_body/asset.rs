@@ -0,0 +1,121 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext, ReadAssetBytesError};
+use bevy::prelude::*;
+use block_bodies_core::animation::AnimationClipFile;
+use block_bodies_core::{
+    AnimationClip, BlockBody, BlockBodyError, BlockModelFile, BlockPose, MaterialPalette,
+};
+
+/// Общая палитра материалов моделей
+pub const MATERIAL_PALETTE_PATH: &str = "materials/palette.ron";
+
+/// Модель блочного тела (assets/models/*.model.ron) с разрешённой палитрой
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct BlockBodyAsset {
+    pub model: BlockModelFile,
+    pub body: BlockBody,
+    pub palette: MaterialPalette,
+}
+
+#[derive(Default)]
+pub struct BlockBodyAssetLoader;
+
+impl AssetLoader for BlockBodyAssetLoader {
+    type Asset = BlockBodyAsset;
+    type Settings = ();
+    type Error = BlockBodyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let model = BlockModelFile::from_ron_str(&String::from_utf8_lossy(&bytes))?;
+        // Ошибки модели приходят из to_body все сразу, предупреждения — только в лог
+        for warning in model.validation_report().warnings() {
+            warn!("⚠️ {}: {}", load_context.path().display(), warning);
+        }
+        let body = model.to_body()?;
+
+        // Палитра — зависимость модели: её правка тоже перезагружает модель
+        let palette = match load_context.read_asset_bytes(MATERIAL_PALETTE_PATH).await {
+            Ok(bytes) => ron::de::from_bytes(&bytes).unwrap_or_else(|err| {
+                warn!("⚠️ Bad material palette {}: {}", MATERIAL_PALETTE_PATH, err);
+                MaterialPalette::default()
+            }),
+            Err(err) => {
+                warn!("⚠️ No material palette {}: {}", MATERIAL_PALETTE_PATH, err);
+                MaterialPalette::default()
+            }
+        };
+
+        Ok(BlockBodyAsset {
+            model,
+            body,
+            palette,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["model.ron"]
+    }
+}
+
+/// Клип анимации (assets/animations/**/*.clip.ron) вместе с его позами
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct BlockClipAsset(pub AnimationClip);
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlockClipLoadError {
+    #[error(transparent)]
+    Parse(#[from] BlockBodyError),
+
+    #[error("Failed to read pose: {0}")]
+    Pose(#[from] ReadAssetBytesError),
+
+    #[error("Bad pose path {0}")]
+    PosePath(String),
+}
+
+#[derive(Default)]
+pub struct BlockClipAssetLoader;
+
+impl AssetLoader for BlockClipAssetLoader {
+    type Asset = BlockClipAsset;
+    type Settings = ();
+    type Error = BlockClipLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(BlockBodyError::from)?;
+        let file = AnimationClipFile::from_ron_str(&String::from_utf8_lossy(&bytes))?;
+
+        // Позы — пути относительно файла клипа; их правка перезагружает клип
+        let mut poses = Vec::with_capacity(file.keyframes.len());
+        for key in &file.keyframes {
+            let path = load_context
+                .asset_path()
+                .resolve_embed(&key.pose)
+                .map_err(|_| BlockClipLoadError::PosePath(key.pose.clone()))?;
+            let bytes = load_context.read_asset_bytes(path).await?;
+            poses.push(BlockPose::from_ron_str(&String::from_utf8_lossy(&bytes))?);
+        }
+
+        Ok(BlockClipAsset(file.into_clip(poses)))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["clip.ron"]
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy_rapier3d::prelude::*;
+use block_bodies_core::{BakedMesh, CompoundCollider};
+
+/// Запечённое тело → один меш Bevy (цвета частей — в вершинах)
+pub fn baked_mesh(baked: &BakedMesh) -> Mesh {
+    // В модели цвета sRGB, а вершинные цвета Bevy — линейные
+    let colors: Vec<[f32; 4]> = baked
+        .colors
+        .iter()
+        .map(|&[r, g, b, a]| Color::srgba(r, g, b, a).to_linear().to_f32_array())
+        .collect();
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, baked.positions.clone())
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, baked.normals.clone())
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, baked.uvs.clone())
+    .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+    .with_inserted_indices(Indices::U32(baked.indices.clone()))
+}
+
+/// Материал для запечённого меша: белый, цвет берётся из вершин
+pub fn baked_material() -> StandardMaterial {
+    StandardMaterial {
+        base_color: Color::WHITE,
+        metallic: 0.1,
+        perceptual_roughness: 0.8,
+        ..default()
+    }
+}
+
+/// Составной коллайдер: по кубоиду на часть
+pub fn compound_collider(collider: &CompoundCollider) -> Collider {
+    Collider::compound(
+        collider
+            .shapes
+            .iter()
+            .map(|shape| {
+                let half = shape.obb.half_extents;
+                (
+                    shape.obb.center,
+                    shape.obb.rotation,
+                    Collider::cuboid(half.x, half.y, half.z),
+                )
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use block_bodies_core::{AnimationClip, BlockBody, ClipPlayer};
+use std::collections::HashMap;
+
+use super::asset::BlockClipAsset;
+
+/// Заспавненное блочное тело: модель + сущности её частей по именам
+#[derive(Component)]
+pub struct BlockBodyRig {
+    pub body: BlockBody,
+    pub parts: HashMap<String, Entity>,
+}
+
+impl BlockBodyRig {
+    pub fn new(body: BlockBody, parts: HashMap<String, Entity>) -> Self {
+        Self { body, parts }
+    }
+
+    pub fn part(&self, name: &str) -> Option<Entity> {
+        self.parts.get(name).copied()
+    }
+}
+
+/// Проигрывает клипы на BlockBodyRig той же сущности
+#[derive(Component, Default)]
+pub struct BlockBodyAnimator {
+    pub player: ClipPlayer,
+}
+
+impl BlockBodyAnimator {
+    /// Сразу запускает клип, без перехода
+    pub fn playing(clip: AnimationClip) -> Self {
+        let mut player = ClipPlayer::new();
+        player.play(clip, 0.0);
+        Self { player }
+    }
+
+    /// Плавный переход на другой клип
+    pub fn cross_fade(&mut self, clip: AnimationClip, duration: f32) {
+        self.player.play(clip, duration);
+    }
+}
+
+/// Клип, который тело запустит, как только соберётся и клип загрузится
+#[derive(Component, Clone, Debug)]
+pub struct BlockBodyClip(pub Handle<BlockClipAsset>);
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use block_bodies_core::{BodyDamage, Ragdoll, RagdollSettings};
+use std::collections::HashMap;
+
+use super::components::BlockBodyRig;
+use super::ragdoll::{Ragdolled, live_transforms, make_parts_physical};
+use super::spawn::BlockBodyPart;
+use crate::fighting::events::MeleeHitEvent;
+
+/// Сколько лежит отрубленная часть
+const SEVERED_LIFETIME: f32 = 10.0;
+/// Импульс, с которым отлетает отрубленная часть
+const SEVER_IMPULSE: f32 = 3.0;
+
+/// Здоровье частей и способности тела, которые можно отрубить
+#[derive(Component, Debug, Clone)]
+pub struct Dismemberment(pub BodyDamage);
+
+impl Dismemberment {
+    pub fn has_capability(&self, capability: &str) -> bool {
+        self.0.has_capability(capability)
+    }
+}
+
+/// Отрубленная часть, исчезает по таймеру
+#[derive(Component)]
+pub struct SeveredLimb {
+    pub despawn_timer: Timer,
+}
+
+/// Событие: у тела отрубили часть (вместе с её потомками)
+#[derive(Event, Debug, Clone)]
+pub struct PartSeveredEvent {
+    /// Корень тела
+    pub entity: Entity,
+    pub part: String,
+    /// Способности, которых больше не даёт ни одна часть
+    pub lost_capabilities: Vec<String>,
+}
+
+/// Урон по месту попадания: хитбокс задел сущность части → здоровье этой части.
+/// Когда здоровье кончается, часть с потомками отваливается физическим объектом.
+pub fn damage_body_parts(
+    mut commands: Commands,
+    mut hit_events: EventReader<MeleeHitEvent>,
+    body_parts: Query<&BlockBodyPart>,
+    mut bodies: Query<(&mut BlockBodyRig, &mut Dismemberment), Without<Ragdolled>>,
+    transforms: Query<&GlobalTransform>,
+    mut severed_events: EventWriter<PartSeveredEvent>,
+) {
+    for event in hit_events.read() {
+        let Ok(part) = body_parts.get(event.hit_entity) else {
+            continue;
+        };
+        let Ok((mut rig, mut dismemberment)) = bodies.get_mut(part.root) else {
+            continue;
+        };
+
+        let Some(sever) = dismemberment.0.damage(&rig.body, &part.name, event.damage) else {
+            continue;
+        };
+
+        let severed = match dismemberment.0.sever(&mut rig.body, &sever) {
+            Ok(severed) => severed,
+            Err(err) => {
+                warn!("⚠️ Failed to sever '{}': {}", sever, err);
+                continue;
+            }
+        };
+
+        // Сущности отрубленных частей уходят из тела
+        let entities: HashMap<String, Entity> = severed
+            .body
+            .parts
+            .values()
+            .filter_map(|p| Some((p.name.clone(), rig.parts.remove(&p.name)?)))
+            .collect();
+
+        let world = live_transforms(&entities, &transforms);
+        let ragdoll = Ragdoll::from_body_posed(&severed.body, &RagdollSettings::default(), &world);
+        let impulse = (event.direction + Vec3::Y) * SEVER_IMPULSE;
+        make_parts_physical(&mut commands, &ragdoll, &entities, impulse);
+
+        for &entity in entities.values() {
+            commands
+                .entity(entity)
+                .remove::<BlockBodyPart>()
+                .insert(SeveredLimb {
+                    despawn_timer: Timer::from_seconds(SEVERED_LIFETIME, TimerMode::Once),
+                });
+        }
+
+        info!(
+            "🪓 Severed '{}' ({} parts), lost: {:?}",
+            sever,
+            entities.len(),
+            severed.lost_capabilities
+        );
+        severed_events.write(PartSeveredEvent {
+            entity: part.root,
+            part: sever,
+            lost_capabilities: severed.lost_capabilities,
+        });
+    }
+}
+
+/// Убирает отрубленные части
+pub fn despawn_severed_limbs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut limbs: Query<(Entity, &mut SeveredLimb)>,
+) {
+    for (entity, mut limb) in &mut limbs {
+        if limb.despawn_timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,18 @@
+pub mod animation;
+pub mod asset;
+pub mod bake;
+pub mod components;
+pub mod dismemberment;
+pub mod physics;
+pub mod plugin;
+pub mod ragdoll;
+pub mod spawn;
+
+pub use animation::{BlockAnimationEvent, animate_block_bodies, start_block_body_clips};
+pub use asset::{BlockBodyAsset, BlockBodyAssetLoader, BlockClipAsset, BlockClipAssetLoader};
+pub use bake::{baked_material, baked_mesh, compound_collider};
+pub use components::{BlockBodyAnimator, BlockBodyClip, BlockBodyRig};
+pub use dismemberment::{Dismemberment, PartSeveredEvent, SeveredLimb};
+pub use plugin::BlockBodyPlugin;
+pub use ragdoll::{RagdollEvent, Ragdolled, spawn_ragdoll};
+pub use spawn::{BlockBodyHandle, BlockBodyPart, BlockBodySpawnOptions, spawn_block_body};
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use block_bodies_core::{JointType, PartJoint, RagdollJoint};
+
+/// Rapier-сустав между частями рэгдолла
+pub fn ragdoll_joint(joint: &RagdollJoint) -> TypedJoint {
+    let mut rapier = rapier_joint(
+        Some(&joint.joint),
+        joint.anchor_on_parent,
+        joint.anchor_on_child,
+    );
+    // Углы сустава отсчитываются от локального поворота части, а не от осей родителя
+    let data: &mut GenericJoint = rapier.as_mut();
+    let basis1 = joint.rest_rotation * data.local_basis1();
+    data.set_local_basis1(basis1);
+    rapier
+}
+
+/// Rapier-сустав из описания сустава части.
+/// Точки крепления — в локальных системах родителя и ребёнка.
+pub fn rapier_joint(
+    joint: Option<&PartJoint>,
+    anchor_on_parent: Vec3,
+    anchor_on_child: Vec3,
+) -> TypedJoint {
+    let Some(joint) = joint else {
+        return FixedJointBuilder::new()
+            .local_anchor1(anchor_on_parent)
+            .local_anchor2(anchor_on_child)
+            .build()
+            .into();
+    };
+
+    let limits = &joint.limits;
+    match joint.joint_type {
+        JointType::Fixed => FixedJointBuilder::new()
+            .local_anchor1(anchor_on_parent)
+            .local_anchor2(anchor_on_child)
+            .build()
+            .into(),
+        JointType::Revolute => {
+            let axis = limits.constrained.iter().position(|&c| c).unwrap_or(0);
+            RevoluteJointBuilder::new(axis_vector(axis))
+                .local_anchor1(anchor_on_parent)
+                .local_anchor2(anchor_on_child)
+                .limits([limits.min_rotation[axis], limits.max_rotation[axis]])
+                .build()
+                .into()
+        }
+        JointType::Spherical => {
+            let mut builder = SphericalJointBuilder::new()
+                .local_anchor1(anchor_on_parent)
+                .local_anchor2(anchor_on_child);
+            for (i, axis) in [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ]
+                .into_iter()
+                .enumerate()
+            {
+                if limits.constrained[i] {
+                    builder =
+                        builder.limits(axis, [limits.min_rotation[i], limits.max_rotation[i]]);
+                }
+            }
+            builder.build().into()
+        }
+    }
+}
+
+fn axis_vector(axis: usize) -> Vec3 {
+    match axis {
+        0 => Vec3::X,
+        1 => Vec3::Y,
+        _ => Vec3::Z,
+    }
+}
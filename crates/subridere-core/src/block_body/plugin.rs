@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use crate::app::AppState;
+
+use super::animation::{BlockAnimationEvent, animate_block_bodies, start_block_body_clips};
+use super::asset::{BlockBodyAsset, BlockBodyAssetLoader, BlockClipAsset, BlockClipAssetLoader};
+use super::dismemberment::{PartSeveredEvent, damage_body_parts, despawn_severed_limbs};
+use super::ragdoll::{
+    RagdollEvent, despawn_expired_ragdolls, ragdoll_block_bodies, ragdoll_on_heavy_hit,
+    recover_ragdolls,
+};
+use super::spawn::{build_pending_block_bodies, reload_block_bodies};
+
+/// Блочные тела: загрузка моделей, сборка частей и анимация клипами
+pub struct BlockBodyPlugin;
+
+impl Plugin for BlockBodyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<BlockBodyAsset>()
+            .init_asset_loader::<BlockBodyAssetLoader>()
+            .init_asset::<BlockClipAsset>()
+            .init_asset_loader::<BlockClipAssetLoader>()
+            .add_event::<BlockAnimationEvent>()
+            .add_event::<RagdollEvent>()
+            .add_event::<PartSeveredEvent>()
+            .add_systems(
+                Update,
+                (reload_block_bodies, build_pending_block_bodies).chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    start_block_body_clips,
+                    animate_block_bodies,
+                    damage_body_parts,
+                    ragdoll_on_heavy_hit,
+                    ragdoll_block_bodies,
+                    recover_ragdolls,
+                    despawn_expired_ragdolls,
+                    despawn_severed_limbs,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,278 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use block_bodies_core::{Affine3A, Ragdoll, RagdollPart, RagdollSettings};
+use std::collections::HashMap;
+
+use super::components::{BlockBodyAnimator, BlockBodyRig};
+use super::physics::ragdoll_joint;
+use super::spawn::{BlockBodySpawnOptions, PendingBlockBody};
+use crate::fighting::events::MeleeHitEvent;
+
+/// Заряд удара, с которого тело обмякает
+const HEAVY_HIT_CHARGE: f32 = 0.9;
+/// Импульс тяжёлого удара по рэгдоллу
+const HEAVY_HIT_IMPULSE: f32 = 6.0;
+/// Через сколько секунд сбитое тяжёлым ударом тело встаёт
+const HEAVY_HIT_RECOVERY: f32 = 2.5;
+
+/// Сделать тело рэгдоллом (смерть, тяжёлый удар)
+#[derive(Event, Debug, Clone)]
+pub struct RagdollEvent {
+    /// Корень с BlockBodyRig
+    pub entity: Entity,
+    /// Импульс, распределяется по частям пропорционально массе
+    pub impulse: Vec3,
+    /// Удалить тело через столько секунд (None — оставить)
+    pub despawn_after: Option<f32>,
+    /// Вернуть частям прежнюю иерархию через столько секунд (None — не вставать)
+    pub recover_after: Option<f32>,
+}
+
+/// Тело уже обмякло
+#[derive(Component)]
+pub struct Ragdolled {
+    pub despawn_timer: Option<Timer>,
+    pub recover_timer: Option<Timer>,
+}
+
+/// Спавнит отдельные физические части рэгдолла, сцепленные суставами.
+/// Возвращает карту имя части → сущность.
+pub fn spawn_ragdoll(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    ragdoll: &Ragdoll,
+    mut material: impl FnMut(&RagdollPart) -> Handle<StandardMaterial>,
+) -> HashMap<String, Entity> {
+    let mut parts: HashMap<String, Entity> = HashMap::new();
+
+    for part in &ragdoll.parts {
+        let size = part.half_extents * 2.0;
+        let mut entity = commands.spawn((
+            Mesh3d(meshes.add(Cuboid::new(size.x, size.y, size.z))),
+            MeshMaterial3d(material(part)),
+            Transform::from_translation(part.translation).with_rotation(part.rotation),
+            Visibility::Visible,
+            Name::new(format!("Ragdoll_{}", part.name)),
+        ));
+        entity.insert(ragdoll_physics(part));
+
+        let parent = part.parent.as_ref().and_then(|name| parts.get(name));
+        if let (Some(&parent), Some(joint)) = (parent, &part.joint) {
+            entity.insert(ImpulseJoint::new(parent, ragdoll_joint(joint)));
+        }
+
+        parts.insert(part.name.clone(), entity.id());
+    }
+
+    parts
+}
+
+fn ragdoll_physics(part: &RagdollPart) -> impl Bundle {
+    let half = part.half_extents;
+    (
+        RigidBody::Dynamic,
+        Collider::cuboid(half.x, half.y, half.z),
+        ColliderMassProperties::Mass(part.mass),
+        Velocity::default(),
+        Damping {
+            linear_damping: 0.5,
+            angular_damping: 1.0,
+        },
+    )
+}
+
+/// Тяжёлый заряженный удар роняет блочное тело
+pub fn ragdoll_on_heavy_hit(
+    mut hit_events: EventReader<MeleeHitEvent>,
+    rigs: Query<Option<&BlockBodySpawnOptions>, (With<BlockBodyRig>, Without<Ragdolled>)>,
+    mut ragdoll_events: EventWriter<RagdollEvent>,
+) {
+    for event in hit_events.read() {
+        if event.charge_level < HEAVY_HIT_CHARGE {
+            continue;
+        }
+        // Уже физические тела (черви) и так отлетают от удара, у запечённых нет частей
+        let Ok(options) = rigs.get(event.target) else {
+            continue;
+        };
+        if options.is_some_and(|o| o.is_dynamic() || o.merged) {
+            continue;
+        }
+        ragdoll_events.write(RagdollEvent {
+            entity: event.target,
+            impulse: (event.direction + Vec3::Y * 0.5) * HEAVY_HIT_IMPULSE,
+            despawn_after: None,
+            recover_after: Some(HEAVY_HIT_RECOVERY),
+        });
+    }
+}
+
+/// Превращает части живого тела в физические, начиная с текущей позы
+pub fn ragdoll_block_bodies(
+    mut commands: Commands,
+    mut events: EventReader<RagdollEvent>,
+    rigs: Query<&BlockBodyRig, (Without<Ragdolled>, Without<PendingBlockBody>)>,
+    transforms: Query<&GlobalTransform>,
+) {
+    for event in events.read() {
+        let Ok(rig) = rigs.get(event.entity) else {
+            continue;
+        };
+
+        let world = live_transforms(&rig.parts, &transforms);
+        let ragdoll = Ragdoll::from_body_posed(&rig.body, &RagdollSettings::default(), &world);
+        make_parts_physical(&mut commands, &ragdoll, &rig.parts, event.impulse);
+
+        commands
+            .entity(event.entity)
+            .remove::<BlockBodyAnimator>()
+            .insert(Ragdolled {
+                despawn_timer: event
+                    .despawn_after
+                    .map(|secs| Timer::from_seconds(secs, TimerMode::Once)),
+                recover_timer: event
+                    .recover_after
+                    .map(|secs| Timer::from_seconds(secs, TimerMode::Once)),
+            });
+
+        info!(
+            "🪆 Block body {:?} went limp ({} parts)",
+            event.entity,
+            ragdoll.parts.len()
+        );
+    }
+}
+
+/// Мировые трансформы сущностей частей по именам
+pub(crate) fn live_transforms(
+    parts: &HashMap<String, Entity>,
+    transforms: &Query<&GlobalTransform>,
+) -> HashMap<String, Affine3A> {
+    parts
+        .iter()
+        .filter_map(|(name, &entity)| Some((name.clone(), transforms.get(entity).ok()?.affine())))
+        .collect()
+}
+
+/// Делает уже заспавненные части физическими, начиная с позы рэгдолла.
+/// Импульс распределяется по частям пропорционально массе.
+pub(crate) fn make_parts_physical(
+    commands: &mut Commands,
+    ragdoll: &Ragdoll,
+    entities: &HashMap<String, Entity>,
+    impulse: Vec3,
+) {
+    let total_mass = ragdoll.total_mass().max(f32::EPSILON);
+
+    for part in &ragdoll.parts {
+        let Some(&entity) = entities.get(&part.name) else {
+            continue;
+        };
+
+        // Части отцепляются от иерархии и держатся только суставами
+        let mut part_commands = commands.entity(entity);
+        part_commands
+            .remove::<ChildOf>()
+            .insert(Transform::from_translation(part.translation).with_rotation(part.rotation))
+            .insert(ragdoll_physics(part))
+            .insert(ExternalImpulse {
+                impulse: impulse * (part.mass / total_mass),
+                torque_impulse: Vec3::ZERO,
+            });
+
+        let parent = part.parent.as_ref().and_then(|name| entities.get(name));
+        match (parent, &part.joint) {
+            (Some(&parent), Some(joint)) => {
+                part_commands.insert(ImpulseJoint::new(parent, ragdoll_joint(joint)));
+            }
+            _ => {
+                part_commands.remove::<ImpulseJoint>();
+            }
+        }
+    }
+}
+
+/// Убирает старые трупы-рэгдоллы вместе с частями
+pub fn despawn_expired_ragdolls(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut ragdolls: Query<(Entity, &mut Ragdolled, &BlockBodyRig)>,
+) {
+    for (entity, mut ragdolled, rig) in &mut ragdolls {
+        let Some(timer) = ragdolled.despawn_timer.as_mut() else {
+            continue;
+        };
+        if !timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        for &part in rig.parts.values() {
+            if let Ok(mut part) = commands.get_entity(part) {
+                part.despawn();
+            }
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Сбитое тело встаёт: части снова дети корня в позе модели, без суставов.
+/// Отрубленных частей в rig уже нет, они не отрастают.
+pub fn recover_ragdolls(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut ragdolls: Query<(
+        Entity,
+        &mut Ragdolled,
+        &BlockBodyRig,
+        Option<&BlockBodySpawnOptions>,
+    )>,
+) {
+    for (root, mut ragdolled, rig, options) in &mut ragdolls {
+        let Some(timer) = ragdolled.recover_timer.as_mut() else {
+            continue;
+        };
+        if !timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        let options = options.cloned().unwrap_or_default();
+        for id in rig.body.topological_order() {
+            let Some(part) = rig.body.get_part(id) else {
+                continue;
+            };
+            let Some(entity) = rig.part(&part.name) else {
+                continue;
+            };
+            let parent = part
+                .parent
+                .as_ref()
+                .and_then(|name| rig.part(name))
+                .unwrap_or(root);
+
+            let mut part_commands = commands.entity(entity);
+            part_commands
+                .remove::<(
+                    RigidBody,
+                    ColliderMassProperties,
+                    Velocity,
+                    Damping,
+                    ExternalImpulse,
+                    ImpulseJoint,
+                )>()
+                .insert((
+                    Transform::from_translation(part.position).with_rotation(part.rotation),
+                    ChildOf(parent),
+                ));
+            if !options.colliders {
+                part_commands.remove::<Collider>();
+            }
+            if let Some(rigid_body) = options.rigid_body {
+                part_commands.insert(rigid_body);
+            }
+        }
+
+        // Анимация (BlockBodyClip) перезапустится сама
+        commands.entity(root).remove::<Ragdolled>();
+        info!("🧍 Block body {:?} got back up", root);
+    }
+}
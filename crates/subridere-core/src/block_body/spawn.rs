@@ -0,0 +1,294 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use block_bodies_core::model::DEFAULT_MATERIAL;
+use block_bodies_core::{Affine3A, BlockMaterial, Ragdoll, RagdollSettings};
+use std::collections::HashMap;
+
+use super::asset::BlockBodyAsset;
+use super::bake::{baked_material, baked_mesh, compound_collider};
+use super::components::BlockBodyRig;
+use super::physics::ragdoll_joint;
+use super::ragdoll::Ragdolled;
+
+/// Как спавнить части тела
+#[derive(Component, Clone, Debug, Default)]
+pub struct BlockBodySpawnOptions {
+    /// Коллайдер-кубоид на каждую часть
+    pub colliders: bool,
+    /// Тип тела Rapier для частей. Динамические части спавнятся отдельно в мире
+    /// и сцепляются суставами, остальные — дочерними сущностями корня.
+    pub rigid_body: Option<RigidBody>,
+    /// Один запечённый меш на всё тело вместо меша на часть (без анимации частей).
+    /// Коллайдер — составной, тип тела Rapier ставится на корень.
+    pub merged: bool,
+}
+
+impl BlockBodySpawnOptions {
+    /// Только меши, части — дети корня (для анимации)
+    pub fn visual() -> Self {
+        Self::default()
+    }
+
+    /// Динамические части с коллайдерами и суставами
+    pub fn physical() -> Self {
+        Self {
+            colliders: true,
+            rigid_body: Some(RigidBody::Dynamic),
+            merged: false,
+        }
+    }
+
+    /// Статичный предмет: один меш и составной коллайдер
+    pub fn merged() -> Self {
+        Self {
+            colliders: true,
+            rigid_body: Some(RigidBody::Fixed),
+            merged: true,
+        }
+    }
+
+    /// Части — самостоятельные динамические тела
+    pub fn is_dynamic(&self) -> bool {
+        !self.merged && matches!(self.rigid_body, Some(RigidBody::Dynamic))
+    }
+}
+
+/// Модель, из которой собрано тело
+#[derive(Component, Clone, Debug)]
+pub struct BlockBodyHandle(pub Handle<BlockBodyAsset>);
+
+/// Тело ждёт загрузки модели (или пересборки после горячей перезагрузки)
+#[derive(Component)]
+pub struct PendingBlockBody;
+
+/// Часть блочного тела
+#[derive(Component, Debug)]
+pub struct BlockBodyPart {
+    pub root: Entity,
+    pub name: String,
+}
+
+/// Спавнит корень тела. Части появятся, когда модель загрузится;
+/// после этого на корне будет `BlockBodyRig` с картой имя части → сущность.
+pub fn spawn_block_body(
+    commands: &mut Commands,
+    handle: Handle<BlockBodyAsset>,
+    transform: Transform,
+    options: BlockBodySpawnOptions,
+) -> Entity {
+    commands
+        .spawn((
+            BlockBodyHandle(handle),
+            options,
+            PendingBlockBody,
+            transform,
+            Visibility::Visible,
+        ))
+        .id()
+}
+
+/// Собирает части у тел, чья модель уже загружена
+pub fn build_pending_block_bodies(
+    mut commands: Commands,
+    bodies: Res<Assets<BlockBodyAsset>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    pending: Query<
+        (Entity, &BlockBodyHandle, &BlockBodySpawnOptions, &Transform),
+        With<PendingBlockBody>,
+    >,
+) {
+    for (root, handle, options, transform) in &pending {
+        let Some(asset) = bodies.get(&handle.0) else {
+            continue;
+        };
+
+        let parts = if options.merged {
+            spawn_merged(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                asset,
+                root,
+                options,
+            );
+            HashMap::new()
+        } else {
+            spawn_parts(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                asset,
+                root,
+                transform,
+                options,
+            )
+        };
+
+        let mut body = asset.body.clone();
+        body.update_world_transforms(Affine3A::IDENTITY);
+
+        info!("🧱 Block body built with {} parts", parts.len());
+        commands
+            .entity(root)
+            .remove::<PendingBlockBody>()
+            .insert(BlockBodyRig::new(body, parts));
+    }
+}
+
+/// Горячая перезагрузка: при изменении модели части пересоздаются
+pub fn reload_block_bodies(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<BlockBodyAsset>>,
+    rigs: Query<(Entity, &BlockBodyHandle, &BlockBodyRig), Without<Ragdolled>>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+
+        for (root, handle, rig) in &rigs {
+            if handle.0.id() != *id {
+                continue;
+            }
+            for &part in rig.parts.values() {
+                if let Ok(mut entity) = commands.get_entity(part) {
+                    entity.despawn();
+                }
+            }
+            commands
+                .entity(root)
+                .remove::<BlockBodyRig>()
+                .insert(PendingBlockBody);
+            info!("🔄 Reloading block body {:?}", root);
+        }
+    }
+}
+
+fn spawn_parts(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset: &BlockBodyAsset,
+    root: Entity,
+    root_transform: &Transform,
+    options: &BlockBodySpawnOptions,
+) -> HashMap<String, Entity> {
+    let mut body = asset.body.clone();
+    body.update_world_transforms(Affine3A::from_rotation_translation(
+        root_transform.rotation,
+        root_transform.translation,
+    ));
+
+    // Массы и суставы динамических частей — как у рэгдолла
+    let ragdoll = options
+        .is_dynamic()
+        .then(|| Ragdoll::from_body(&body, &RagdollSettings::default()));
+
+    let mut material_handles: HashMap<&str, Handle<StandardMaterial>> = HashMap::new();
+    let mut parts: HashMap<String, Entity> = HashMap::new();
+
+    for id in body.topological_order() {
+        let Some(part) = body.get_part(id) else {
+            continue;
+        };
+        let material_name = asset
+            .model
+            .get(&part.name)
+            .map_or(DEFAULT_MATERIAL, |p| p.material.as_str());
+        let material = material_handles
+            .entry(material_name)
+            .or_insert_with(|| {
+                materials.add(standard_material(&asset.palette.resolve(material_name)))
+            })
+            .clone();
+
+        let transform = if options.is_dynamic() {
+            let (_, rotation, translation) = part.world_transform.to_scale_rotation_translation();
+            Transform::from_translation(translation).with_rotation(rotation)
+        } else {
+            Transform::from_translation(part.position).with_rotation(part.rotation)
+        };
+
+        let mut entity = commands.spawn((
+            BlockBodyPart {
+                root,
+                name: part.name.clone(),
+            },
+            Mesh3d(meshes.add(Cuboid::new(part.size.x, part.size.y, part.size.z))),
+            MeshMaterial3d(material),
+            transform,
+            Visibility::Visible,
+            Name::new(part.name.clone()),
+        ));
+
+        if options.colliders {
+            let half = part.size * 0.5;
+            entity.insert(Collider::cuboid(half.x, half.y, half.z));
+        }
+        if let Some(rigid_body) = options.rigid_body {
+            entity.insert(rigid_body);
+        }
+
+        let parent = part
+            .parent
+            .as_ref()
+            .and_then(|name| parts.get(name))
+            .copied();
+        if let Some(ragdoll_part) = ragdoll.as_ref().and_then(|r| r.get(&part.name)) {
+            // Динамические части не наследуют трансформ — держатся суставами
+            entity.insert(ColliderMassProperties::Mass(ragdoll_part.mass));
+            if let (Some(parent), Some(joint)) = (parent, &ragdoll_part.joint) {
+                entity.insert(ImpulseJoint::new(parent, ragdoll_joint(joint)));
+            }
+        } else {
+            entity.insert(ChildOf(parent.unwrap_or(root)));
+        }
+
+        parts.insert(part.name.clone(), entity.id());
+    }
+
+    parts
+}
+
+/// Запекает модель в один меш прямо на корне
+fn spawn_merged(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset: &BlockBodyAsset,
+    root: Entity,
+    options: &BlockBodySpawnOptions,
+) {
+    let baked = match asset.model.bake(&asset.palette, None) {
+        Ok(baked) => baked,
+        Err(err) => {
+            warn!("⚠️ Failed to bake block body: {}", err);
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(root);
+    entity.insert((
+        Mesh3d(meshes.add(baked_mesh(&baked.mesh))),
+        MeshMaterial3d(materials.add(baked_material())),
+    ));
+    if options.colliders {
+        entity.insert(compound_collider(&baked.collider));
+    }
+    if let Some(rigid_body) = options.rigid_body {
+        entity.insert(rigid_body);
+    }
+}
+
+fn standard_material(material: &BlockMaterial) -> StandardMaterial {
+    let [r, g, b, a] = material.color;
+    let [er, eg, eb] = material.emissive;
+    StandardMaterial {
+        base_color: Color::srgba(r, g, b, a),
+        metallic: material.metallic,
+        perceptual_roughness: material.roughness,
+        emissive: LinearRgba::rgb(er, eg, eb),
+        ..default()
+    }
+}
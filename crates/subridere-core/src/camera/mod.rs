@@ -1,2 +1,3 @@
 pub mod flycam;
 pub mod plugin;
+pub mod shake;
@@ -10,21 +10,26 @@ pub struct CameraShakeEvent;
 #[derive(Component)]
 pub struct CameraShake {
     pub timer: Timer,
+    /// Исходная позиция камеры (восстанавливается после тряски)
+    pub origin: Vec3,
 }
 
-
 pub fn start_camera_shake(
     mut commands: Commands,
     mut evr: EventReader<CameraShakeEvent>,
-    query: Query<Entity, With<FlyCamera>>,
+    query: Query<(Entity, &Transform, Option<&CameraShake>), With<FlyCamera>>,
 ) {
-    for _ in evr.read() {
-        for entity in &query {
-            println!("inserting entity CameraShake");
-            commands.entity(entity).insert(CameraShake {
-                timer: Timer::from_seconds(0.3, TimerMode::Once),
-            });
-        }
+    if evr.read().count() == 0 {
+        return;
+    }
+
+    for (entity, transform, current) in &query {
+        // Если уже трясёт — не теряем исходную позицию
+        let origin = current.map(|s| s.origin).unwrap_or(transform.translation);
+        commands.entity(entity).insert(CameraShake {
+            timer: Timer::from_seconds(0.3, TimerMode::Once),
+            origin,
+        });
     }
 }
 
@@ -38,16 +43,16 @@ pub fn apply_camera_shake(
     for (entity, mut transform, mut shake) in &mut query {
         shake.timer.tick(time.delta());
 
+        if shake.timer.finished() {
+            transform.translation = shake.origin;
+            commands.entity(entity).remove::<CameraShake>();
+            continue;
+        }
+
         let strength = 0.1 * (1.0 - shake.timer.fraction()); // затухающая амплитуда
         let offset_x = rng.gen_range(-strength..=strength);
         let offset_y = rng.gen_range(-strength..=strength);
 
-        transform.translation.x += offset_x;
-        transform.translation.y += offset_y;
-
-        if shake.timer.finished() {
-            commands.entity(entity).remove::<CameraShake>();
-        }
-        println!("Shaking camera");
+        transform.translation = shake.origin + Vec3::new(offset_x, offset_y, 0.0);
     }
 }
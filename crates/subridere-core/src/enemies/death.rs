@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use super::components::Enemy;
+use super::worm::components::Worm;
+use crate::block_body::{BlockBodyRig, RagdollEvent};
+use crate::stats::{ExperienceGainEvent, ExperienceReward, health::Health};
+
+/// Начислить опыт убийце врага (если известен)
+pub fn award_kill_experience(
+    health: &Health,
+    reward: Option<&ExperienceReward>,
+    xp_events: &mut EventWriter<ExperienceGainEvent>,
+) {
+    let (Some(killer), Some(reward)) = (health.last_attacker, reward) else {
+        return;
+    };
+
+    if reward.amount == 0 {
+        return;
+    }
+
+    xp_events.write(ExperienceGainEvent {
+        entity: killer,
+        amount: reward.amount,
+    });
+}
+
+/// Сколько лежит труп врага с блочным телом
+const CORPSE_LIFETIME: f32 = 15.0;
+
+/// Общая смерть врагов без собственной логики смерти (черви обрабатываются отдельно)
+pub fn enemy_death_system(
+    mut commands: Commands,
+    enemies: Query<
+        (
+            Entity,
+            &Health,
+            Option<&ExperienceReward>,
+            Has<BlockBodyRig>,
+        ),
+        (With<Enemy>, Without<Worm>),
+    >,
+    mut xp_events: EventWriter<ExperienceGainEvent>,
+    mut ragdoll_events: EventWriter<RagdollEvent>,
+) {
+    for (entity, health, reward, has_body) in &enemies {
+        if health.is_alive() {
+            continue;
+        }
+
+        info!("💀 Enemy died!");
+        award_kill_experience(health, reward, &mut xp_events);
+
+        if has_body {
+            // Блочное тело падает рэгдоллом и исчезает позже
+            commands.entity(entity).remove::<(Enemy, Health)>();
+            ragdoll_events.write(RagdollEvent {
+                entity,
+                impulse: Vec3::ZERO,
+                despawn_after: Some(CORPSE_LIFETIME),
+                recover_after: None,
+            });
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -1,16 +1,18 @@
 use bevy::prelude::*;
 
 use crate::app::AppState; // ← ДОБАВИТЬ
+use crate::enemies::death::enemy_death_system;
 use crate::enemies::worm::{
     attack::{worm_execute_lunge_system, worm_prepare_visual_feedback},
-    damage::{worm_collision_damage_system, worm_damage_cooldown_system},
+    damage::{worm_collision_damage_system, worm_damage_cooldown_system, worm_melee_hit_reaction},
     death::{animate_blood_pool, fade_corpse_segments, spawn_blood_pool_visuals},
     detection::worm_detect_targets,
     health::worm_death_system,
     movement::worm_move_forward,
     particles::{spawn_dust_on_landing, update_blood_particles, update_dust_particles},
     rotation::worm_rotate_to_target,
-    state::worm_update_state,
+    spawn::finish_worm_bodies,
+    state::{worm_react_to_stagger, worm_update_state},
 };
 
 /// Plugin for all enemy-related systems
@@ -18,21 +20,26 @@ pub struct EnemiesPlugin;
 
 impl Plugin for EnemiesPlugin {
     fn build(&self, app: &mut App) {
+        // Черви собираются ещё при загрузке мира, до InGame
+        app.add_systems(Update, finish_worm_bodies);
         app.add_systems(
             Update,
             (
                 worm_detect_targets,
+                worm_react_to_stagger,
                 worm_update_state,
                 worm_execute_lunge_system,
                 worm_prepare_visual_feedback,
                 worm_damage_cooldown_system,
                 worm_collision_damage_system,
+                worm_melee_hit_reaction,
                 worm_rotate_to_target,
                 worm_move_forward,
                 spawn_dust_on_landing,
                 update_dust_particles,
                 update_blood_particles,
                 worm_death_system,
+                enemy_death_system,
                 spawn_blood_pool_visuals,
                 animate_blood_pool,
                 fade_corpse_segments,
@@ -12,7 +12,7 @@ pub struct Worm {
 impl Default for Worm {
     fn default() -> Self {
         Self {
-            segment_count: 4, // matches worm.ron model
+            segment_count: 4, // matches worm.model.ron
         }
     }
 }
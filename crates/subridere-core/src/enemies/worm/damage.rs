@@ -1,6 +1,9 @@
-use super::components::WormHead;
+use super::components::{Worm, WormHead};
+use super::particles::spawn_blood_splatter;
+use crate::audio::worm::events::WormHurtEvent;
+use crate::fighting::events::MeleeHitEvent;
 use crate::player::component::Player;
-use crate::stats::damage::component::{Damage, DamageType};
+use crate::stats::damage::component::{Damage, DamageType, Staggered};
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
@@ -21,7 +24,7 @@ impl Default for WormDamageCooldown {
 pub fn worm_collision_damage_system(
     mut commands: Commands,
     mut collision_events: EventReader<CollisionEvent>,
-    worm_heads: Query<(Entity, Option<&WormDamageCooldown>), With<WormHead>>,
+    worm_heads: Query<(Entity, Option<&WormDamageCooldown>, Has<Staggered>), With<WormHead>>,
     players: Query<Entity, With<Player>>,
 ) {
     for event in collision_events.read() {
@@ -35,15 +38,18 @@ pub fn worm_collision_damage_system(
                     continue;
                 };
 
-            if let Ok((worm_e, cooldown)) = worm_heads.get(worm_entity) {
-                if cooldown.is_some() {
+            if let Ok((worm_e, cooldown, staggered)) = worm_heads.get(worm_entity) {
+                // Оглушённый червь не кусает
+                if cooldown.is_some() || staggered {
                     continue;
                 }
 
-                commands.entity(player_entity).insert(Damage {
+                Damage {
                     amount: 15.0,
                     damage_type: DamageType::Physical,
-                });
+                    source: Some(worm_e),
+                }
+                .add_to(&mut commands.entity(player_entity));
 
                 commands
                     .entity(worm_e)
@@ -68,3 +74,34 @@ pub fn worm_damage_cooldown_system(
         }
     }
 }
+
+/// Worm reaction to player melee hits: hurt sound and blood splatter
+pub fn worm_melee_hit_reaction(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut hit_events: EventReader<MeleeHitEvent>,
+    worms: Query<(), With<Worm>>,
+    transforms: Query<&GlobalTransform>,
+    mut hurt_events: EventWriter<WormHurtEvent>,
+) {
+    for event in hit_events.read() {
+        if worms.get(event.target).is_err() {
+            continue;
+        }
+
+        hurt_events.write(WormHurtEvent);
+
+        if let Ok(hit_transform) = transforms.get(event.hit_entity) {
+            spawn_blood_splatter(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                hit_transform.translation(),
+                -event.direction,
+            );
+        }
+
+        info!("🩸 Worm took {:.1} melee damage", event.damage);
+    }
+}
@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
+use block_bodies_core::{Affine3A, BlockBody, Ragdoll, RagdollSettings};
+use std::collections::HashMap;
+
+use crate::block_body::spawn_ragdoll;
 
 /// Marker for corpse segment
 #[derive(Component)]
@@ -13,52 +17,51 @@ pub struct BloodPool {
     pub fade_timer: Timer,
 }
 
-/// Spawns corpse on worm death
+/// Corpse mass per unit of segment volume
+const CORPSE_DENSITY: f32 = 2.0;
+
+/// Spawns a jointed ragdoll corpse on worm death, starting from the segments' last pose
 pub fn spawn_corpse_on_death(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
-    segment_data: Vec<(Vec3, Quat, Vec3, Color)>, // ✅ position, rotation, size, color
+    body: &BlockBody,
+    segment_transforms: &HashMap<String, Affine3A>,
+    segment_colors: &HashMap<String, Color>, // ✅ Original colors
 ) {
     use rand::Rng;
     let mut rng = rand::thread_rng();
 
-    let mut death_position = Vec3::ZERO;
-
-    for (position, rotation, size, color) in &segment_data {
-        death_position += *position;
-
-        // Use original segment size
-        let mesh = meshes.add(Cuboid::new(size.x, size.y, size.z));
-        let material = materials.add(StandardMaterial {
-            base_color: *color, // ✅ Original color
+    let settings = RagdollSettings {
+        density: CORPSE_DENSITY,
+        ..default()
+    };
+    let ragdoll = Ragdoll::from_body_posed(body, &settings, segment_transforms);
+
+    // Each segment gets its own material so it can fade independently
+    let segments = spawn_ragdoll(commands, meshes, &ragdoll, |part| {
+        materials.add(StandardMaterial {
+            base_color: segment_colors
+                .get(&part.name)
+                .copied()
+                .unwrap_or(Color::WHITE),
             ..default()
-        });
+        })
+    });
 
+    for &segment in segments.values() {
+        // Small twitch; joints keep the body together
         let impulse = Vec3::new(
-            rng.gen_range(-5.0..5.0),
-            rng.gen_range(4.0..10.0),
-            rng.gen_range(-5.0..5.0),
+            rng.gen_range(-0.5..0.5),
+            rng.gen_range(0.5..1.5),
+            rng.gen_range(-0.5..0.5),
         );
 
-        commands.spawn((
-            Mesh3d(mesh),
-            MeshMaterial3d(material),
-            Transform::from_translation(*position).with_rotation(*rotation),
-            GlobalTransform::default(),
-            Visibility::Visible,
-            RigidBody::Dynamic,
-            Collider::cuboid(size.x * 0.5, size.y * 0.5, size.z * 0.5),
-            Velocity::default(),
+        commands.entity(segment).insert((
             ExternalImpulse {
                 impulse,
                 torque_impulse: Vec3::ZERO,
             },
-            GravityScale(1.0),
-            Damping {
-                linear_damping: 1.0,
-                angular_damping: 1.0,
-            },
             CorpseSegment {
                 fade_timer: Timer::from_seconds(3.0, TimerMode::Once),
             },
@@ -66,12 +69,11 @@ pub fn spawn_corpse_on_death(
         ));
     }
 
-    if !segment_data.is_empty() {
-        death_position /= segment_data.len() as f32;
-        spawn_blood_pool(commands, death_position);
+    if !segments.is_empty() {
+        spawn_blood_pool(commands, ragdoll.center_of_mass());
     }
 
-    info!("💀 Spawned corpse with {} segments", segment_data.len());
+    info!("💀 Spawned ragdoll corpse with {} segments", segments.len());
 }
 
 /// Spawns blood pool
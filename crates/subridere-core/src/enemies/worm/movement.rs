@@ -41,7 +41,7 @@ pub fn worm_move_forward(
 
                         let elapsed = time.elapsed_secs() as i32;
                         if elapsed % 2 == 0 && time.delta_secs() < 0.02 {
-                            let forward = Vec3::NEG_X;
+                            let forward = Vec3::NEG_Z;
                             let current_forward = head_transform.rotation * forward;
                             let _fwd_xz = Vec3::new(current_forward.x, 0.0, current_forward.z)
                                 .normalize_or_zero();
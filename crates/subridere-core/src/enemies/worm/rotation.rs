@@ -14,7 +14,7 @@ pub fn is_facing_target(head_transform: &Transform, target_pos: Vec3) -> bool {
         return true;
     }
 
-    let forward = Vec3::NEG_X;
+    let forward = Vec3::NEG_Z;
     let current_rotation = head_transform.rotation;
     let current_forward = current_rotation * forward;
     let current_dir = Vec3::new(current_forward.x, 0.0, current_forward.z).normalize_or_zero();
@@ -63,7 +63,7 @@ pub fn worm_rotate_to_target(
                     continue;
                 }
 
-                let forward = Vec3::NEG_X;
+                let forward = Vec3::NEG_Z;
                 let current_rotation = head_transform.rotation;
                 let current_forward = current_rotation * forward;
                 let current_dir =
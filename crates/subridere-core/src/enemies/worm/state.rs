@@ -1,14 +1,34 @@
 use super::components::{WormAI, WormHead, WormState};
+use crate::block_body::Dismemberment;
 use crate::player::component::Player;
+use crate::stats::damage::Staggered;
 use bevy::prelude::*;
 
+/// Парированный червь бросает атаку и отлёживается, пока оглушён
+pub fn worm_react_to_stagger(
+    mut heads: Query<(&mut WormState, &Staggered), (With<WormHead>, Added<Staggered>)>,
+) {
+    for (mut state, staggered) in &mut heads {
+        info!("🔄 Staggered -> Recovering");
+        *state = WormState::Recovering {
+            recovery_timer: staggered.timer.remaining_secs(),
+        };
+    }
+}
+
 /// Updates worm state machine with attack logic
 pub fn worm_update_state(
-    mut heads: Query<(&Transform, &mut WormState, &WormAI), With<WormHead>>,
+    mut heads: Query<(&Transform, &mut WormState, &WormAI, &WormHead)>,
     targets: Query<&Transform, (With<Player>, Without<WormHead>)>,
+    bodies: Query<&Dismemberment>,
     time: Res<Time>,
 ) {
-    for (head_transform, mut state, ai) in &mut heads {
+    for (head_transform, mut state, ai, head) in &mut heads {
+        // Без хвоста не от чего оттолкнуться — только ползёт и кусает
+        let can_lunge = bodies
+            .get(head.worm_root)
+            .map_or(true, |body| body.has_capability("lunge"));
+
         let new_state = match &*state {
             // ============================================
             // IDLE - ищем цель
@@ -39,7 +59,7 @@ pub fn worm_update_state(
                             .distance(target_transform.translation);
 
                         // Достаточно близко для прыжка?
-                        if distance <= ai.jump_range {
+                        if can_lunge && distance <= ai.jump_range {
                             info!("🔄 Chase -> PrepareAttack (distance: {:.1}m)", distance);
                             WormState::PrepareAttack {
                                 target: target_entity,
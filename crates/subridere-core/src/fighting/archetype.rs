@@ -0,0 +1,188 @@
+// fighting/archetype.rs
+//
+// Архетипы оружия: тайминги, позы рук и хитбокс для каждого WeaponKind.
+// Всё читается из assets/weapons/*.weapons.ron — новое оружие того же
+// типа добавляется только файлом предмета.
+
+use std::collections::HashMap;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::player::arm::ArmPose;
+
+use super::components::{AttackTimings, AttackType, WeaponKind};
+
+/// Путь к архетипам оружия по умолчанию
+pub const WEAPON_ARCHETYPES_PATH: &str = "weapons/default.weapons.ron";
+
+/// Ключевые позы одной атаки (для правой руки)
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct AttackPoses {
+    /// Замах — конец фазы windup
+    pub windup: ArmPose,
+    /// Удар — конец фазы active
+    pub strike: ArmPose,
+}
+
+/// Позы руки для оружия (для правой руки, левая зеркалится)
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct WeaponPoses {
+    pub idle: ArmPose,
+    /// Куда рука уходит, пока зажата кнопка тяжёлой атаки
+    pub charging: ArmPose,
+    pub light: AttackPoses,
+    pub heavy: AttackPoses,
+}
+
+impl Default for WeaponPoses {
+    fn default() -> Self {
+        Self {
+            idle: ArmPose::idle_right(),
+            charging: ArmPose::fists_heavy_charging_right(),
+            light: AttackPoses {
+                windup: ArmPose::fists_windup_right(),
+                strike: ArmPose::fists_punch_right(),
+            },
+            heavy: AttackPoses {
+                windup: ArmPose::fists_heavy_windup_right(),
+                strike: ArmPose::fists_heavy_active_right(),
+            },
+        }
+    }
+}
+
+/// Блок оружием (удержание кнопки руки)
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct BlockConfig {
+    /// Поза руки с поднятым блоком
+    pub pose: ArmPose,
+    /// Доля урона, снимаемая блоком (0.0..=1.0)
+    pub damage_reduction: f32,
+    /// Стамина за каждую единицу заблокированного урона
+    pub stamina_per_damage: f32,
+    /// Полуугол защищаемого сектора от взгляда камеры, градусы
+    pub angle: f32,
+    /// Первые секунды блока — парирование: урон не проходит, атакующий оглушён
+    pub parry_window: f32,
+    /// Длительность оглушения атакующего при парировании
+    pub stagger_duration: f32,
+}
+
+/// Архетип оружия
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponArchetype {
+    pub light: AttackTimings,
+    pub heavy: AttackTimings,
+    pub poses: WeaponPoses,
+    /// Чем можно блокировать; None — рука только атакует
+    #[serde(default)]
+    pub block: Option<BlockConfig>,
+    /// Смещение центра хитбокса вдоль оружия от хвата (0 — хитбокс на кисти)
+    #[serde(default)]
+    pub reach: f32,
+    /// Полуразмеры хитбокса в осях оружия
+    pub hitbox: [f32; 3],
+}
+
+impl Default for WeaponArchetype {
+    /// Кулаки — на случай, если ассет не загрузился
+    fn default() -> Self {
+        Self {
+            light: AttackTimings::default(),
+            heavy: AttackTimings::fists_heavy(),
+            poses: WeaponPoses::default(),
+            block: None,
+            reach: 0.0,
+            hitbox: [0.30, 0.24, 0.30],
+        }
+    }
+}
+
+impl WeaponArchetype {
+    pub fn attack_poses(&self, attack_type: AttackType) -> &AttackPoses {
+        match attack_type {
+            AttackType::Light => &self.poses.light,
+            AttackType::Heavy => &self.poses.heavy,
+        }
+    }
+
+    /// С какой позы начинается замах: тяжёлая атака стартует из зарядки
+    pub fn attack_start(&self, attack_type: AttackType) -> &ArmPose {
+        match attack_type {
+            AttackType::Light => &self.poses.idle,
+            AttackType::Heavy => &self.poses.charging,
+        }
+    }
+
+    pub fn hitbox_half_extents(&self) -> Vec3 {
+        Vec3::from_array(self.hitbox)
+    }
+}
+
+/// Все архетипы (assets/weapons/*.weapons.ron).
+/// Загружается как ассет, на время забега копируется в ресурс.
+#[derive(Asset, Resource, TypePath, Debug, Clone, Default, Deserialize)]
+pub struct WeaponArchetypes {
+    pub archetypes: HashMap<WeaponKind, WeaponArchetype>,
+
+    #[serde(skip)]
+    fallback: WeaponArchetype,
+}
+
+impl WeaponArchetypes {
+    /// Архетип оружия; неописанные типы дерутся как кулаки
+    pub fn get(&self, kind: WeaponKind) -> &WeaponArchetype {
+        self.archetypes
+            .get(&kind)
+            .or_else(|| self.archetypes.get(&WeaponKind::Fists))
+            .unwrap_or(&self.fallback)
+    }
+}
+
+/// Хэндл загружаемых архетипов
+#[derive(Resource)]
+pub struct WeaponArchetypesHandle(pub Handle<WeaponArchetypes>);
+
+#[derive(Default)]
+pub struct WeaponArchetypesLoader;
+
+impl AssetLoader for WeaponArchetypesLoader {
+    type Asset = WeaponArchetypes;
+    type Settings = ();
+    type Error = ron::error::SpannedError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.unwrap();
+        let archetypes: WeaponArchetypes = ron::de::from_bytes(&bytes)?;
+        Ok(archetypes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["weapons.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_archetypes_cover_every_weapon() {
+        let archetypes: WeaponArchetypes =
+            ron::de::from_str(include_str!("../../assets/weapons/default.weapons.ron")).unwrap();
+
+        for kind in WeaponKind::ALL {
+            assert!(archetypes.archetypes.contains_key(&kind), "{:?}", kind);
+        }
+        assert!(archetypes.get(WeaponKind::Shield).block.is_some());
+    }
+}
@@ -0,0 +1,147 @@
+// fighting/block.rs
+//
+// Блок и парирование: чем поднятый щит встречает входящий удар
+
+use bevy::prelude::*;
+
+use super::archetype::{BlockConfig, WeaponArchetypes};
+use super::components::{ArmCombatState, PlayerCombatState};
+
+/// Чем закончился удар по блокирующему
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockOutcome {
+    /// Блок не поднят или удар пришёл сбоку/сзади
+    Unblocked,
+    /// Удар в окне парирования: урон не проходит, атакующий оглушён
+    Parried { stagger_duration: f32 },
+    /// Часть урона снята блоком, за неё платится стамина
+    Blocked { damage: f32, stamina_cost: f32 },
+}
+
+/// Поднятый блок (любой рукой) и сколько он уже держится
+pub fn active_block(
+    combat: &PlayerCombatState,
+    archetypes: &WeaponArchetypes,
+) -> Option<(BlockConfig, f32)> {
+    [&combat.left, &combat.right]
+        .into_iter()
+        .find_map(|arm| match arm {
+            ArmCombatState::Blocking {
+                block_timer,
+                weapon_kind,
+            } => archetypes
+                .get(*weapon_kind)
+                .block
+                .map(|block| (block, *block_timer)),
+            _ => None,
+        })
+}
+
+/// Разрешает удар по блоку.
+/// `facing` — взгляд камеры, `to_attacker` — направление от цели к атакующему.
+pub fn resolve_block(
+    block: &BlockConfig,
+    block_timer: f32,
+    facing: Vec3,
+    to_attacker: Vec3,
+    damage: f32,
+) -> BlockOutcome {
+    // Сектор считается в горизонтальной плоскости: укус сверху — тоже спереди
+    let facing = facing.with_y(0.0).normalize_or_zero();
+    let to_attacker = to_attacker.with_y(0.0).normalize_or_zero();
+    if facing == Vec3::ZERO || to_attacker == Vec3::ZERO {
+        return BlockOutcome::Unblocked;
+    }
+    if facing.angle_between(to_attacker).to_degrees() > block.angle {
+        return BlockOutcome::Unblocked;
+    }
+
+    if block_timer <= block.parry_window {
+        return BlockOutcome::Parried {
+            stagger_duration: block.stagger_duration,
+        };
+    }
+
+    let absorbed = damage * block.damage_reduction.clamp(0.0, 1.0);
+    BlockOutcome::Blocked {
+        damage: damage - absorbed,
+        stamina_cost: absorbed * block.stamina_per_damage,
+    }
+}
+
+/// Блок пробит (не хватило стамины) — щит опускается
+pub fn break_block(combat: &mut PlayerCombatState) {
+    for arm in [&mut combat.left, &mut combat.right] {
+        if matches!(arm, ArmCombatState::Blocking { .. }) {
+            *arm = ArmCombatState::Lowering { timer: 0.0 };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fighting::components::WeaponKind;
+    use crate::player::arm::ArmPose;
+
+    fn shield() -> BlockConfig {
+        BlockConfig {
+            pose: ArmPose::idle_right(),
+            damage_reduction: 0.75,
+            stamina_per_damage: 2.0,
+            angle: 60.0,
+            parry_window: 0.2,
+            stagger_duration: 1.0,
+        }
+    }
+
+    #[test]
+    fn blocks_hits_from_the_front() {
+        let outcome = resolve_block(&shield(), 1.0, Vec3::NEG_Z, Vec3::new(0.3, 0.5, -1.0), 20.0);
+        assert_eq!(
+            outcome,
+            BlockOutcome::Blocked {
+                damage: 5.0,
+                stamina_cost: 30.0
+            }
+        );
+    }
+
+    #[test]
+    fn misses_hits_from_behind_and_the_side() {
+        for to_attacker in [Vec3::Z, Vec3::X, Vec3::new(-1.0, 0.0, -0.3)] {
+            assert_eq!(
+                resolve_block(&shield(), 1.0, Vec3::NEG_Z, to_attacker, 20.0),
+                BlockOutcome::Unblocked
+            );
+        }
+    }
+
+    #[test]
+    fn parries_at_block_start() {
+        assert_eq!(
+            resolve_block(&shield(), 0.1, Vec3::NEG_Z, Vec3::NEG_Z, 20.0),
+            BlockOutcome::Parried {
+                stagger_duration: 1.0
+            }
+        );
+    }
+
+    #[test]
+    fn break_lowers_only_blocking_arm() {
+        let mut combat = PlayerCombatState {
+            left: ArmCombatState::Blocking {
+                block_timer: 0.5,
+                weapon_kind: WeaponKind::Shield,
+            },
+            right: ArmCombatState::Charging {
+                charge_timer: 0.1,
+                weapon_kind: WeaponKind::Sword,
+            },
+        };
+        break_block(&mut combat);
+
+        assert_eq!(combat.left, ArmCombatState::Lowering { timer: 0.0 });
+        assert!(matches!(combat.right, ArmCombatState::Charging { .. }));
+    }
+}
@@ -1,6 +1,9 @@
 // fighting/components.rs
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::archetype::WeaponArchetype;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AttackPhase {
@@ -18,13 +21,29 @@ pub enum AttackType {
     Heavy,
 }
 
-/// Тип оружия — определяет анимации и тайминги
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Тип оружия — архетип из assets/weapons: анимации, тайминги, хитбокс
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
 pub enum WeaponKind {
     #[default]
     Fists,
     Sword,
-    // TODO: Staff, Hammer, Shield, Dagger...
+    Dagger,
+    Hammer,
+    Staff,
+    Shield,
+    Spear,
+}
+
+impl WeaponKind {
+    pub const ALL: [WeaponKind; 7] = [
+        WeaponKind::Fists,
+        WeaponKind::Sword,
+        WeaponKind::Dagger,
+        WeaponKind::Hammer,
+        WeaponKind::Staff,
+        WeaponKind::Shield,
+        WeaponKind::Spear,
+    ];
 }
 
 /// Состояние одной руки
@@ -48,6 +67,16 @@ pub enum ArmCombatState {
         charge_level: f32,
         weapon_kind: WeaponKind,
     },
+
+    /// Блок (кнопка удерживается, оружие умеет блокировать)
+    Blocking {
+        /// Сколько блок уже держится — в начале окно парирования
+        block_timer: f32,
+        weapon_kind: WeaponKind,
+    },
+
+    /// Щит опускается: пока не опустится, снова не поднять (иначе парирование спамится)
+    Lowering { timer: f32 },
 }
 
 /// Компонент боевого состояния игрока (обе руки независимо)
@@ -109,12 +138,14 @@ impl ChargeConfig {
     }
 }
 
-/// Тайминги атаки (можно менять для разного оружия)
-#[derive(Debug, Clone, Copy)]
+/// Тайминги атаки, задаются архетипом оружия
+#[derive(Debug, Clone, Copy, Deserialize)]
 pub struct AttackTimings {
     pub windup: f32,
     pub active: f32,
     pub recovery: f32,
+    /// Стоимость атаки в стамине
+    pub stamina_cost: f32,
 }
 
 impl Default for AttackTimings {
@@ -124,15 +155,14 @@ impl Default for AttackTimings {
 }
 
 impl AttackTimings {
-    // ─────────────────────────────────────────────────────────────
-    // FISTS (кулаки)
-    // ─────────────────────────────────────────────────────────────
+    // Кулаки — встроенный запасной вариант, остальное оружие в assets/weapons
 
     pub fn fists() -> Self {
         Self {
             windup: 0.10,
             active: 0.12,
             recovery: 0.20,
+            stamina_cost: 6.0,
         }
     }
 
@@ -141,55 +171,16 @@ impl AttackTimings {
             windup: 0.28,
             active: 0.22,
             recovery: 0.45,
+            stamina_cost: 12.0,
         }
     }
 
-    // ─────────────────────────────────────────────────────────────
-    // SWORD (меч)
-    // ─────────────────────────────────────────────────────────────
-
-    pub fn sword() -> Self {
-        Self {
-            windup: 0.12,
-            active: 0.15,
-            recovery: 0.25,
-        }
-    }
-
-    pub fn sword_heavy() -> Self {
-        Self {
-            windup: 0.25,
-            active: 0.20,
-            recovery: 0.40,
-        }
-    }
-
-    // ─────────────────────────────────────────────────────────────
-    // TODO: другие типы оружия
-    // ─────────────────────────────────────────────────────────────
-
-    pub fn hammer() -> Self {
-        Self {
-            windup: 0.25,
-            active: 0.20,
-            recovery: 0.45,
-        }
-    }
+    /// Тяжёлая атака стоит больше лёгкой с тем же оружием
+    pub const HEAVY_STAMINA_MULT: f32 = 2.0;
 
     pub fn total(&self) -> f32 {
         self.windup + self.active + self.recovery
     }
-
-    /// Получить тайминги по типу оружия и типу атаки
-    pub fn for_weapon(kind: WeaponKind, attack_type: AttackType) -> Self {
-        match (kind, attack_type) {
-            (WeaponKind::Fists, AttackType::Light) => Self::fists(),
-            (WeaponKind::Fists, AttackType::Heavy) => Self::fists_heavy(),
-            (WeaponKind::Sword, AttackType::Light) => Self::sword(),
-            (WeaponKind::Sword, AttackType::Heavy) => Self::sword_heavy(),
-            // TODO: другие комбинации
-        }
-    }
 }
 
 /// Resource: текущие тайминги атаки для каждой руки
@@ -217,19 +208,40 @@ impl Default for CurrentAttackTimings {
 }
 
 impl CurrentAttackTimings {
-    /// Обновить тайминги для руки
-    pub fn set_weapon(&mut self, side: crate::player::arm::ArmSide, kind: WeaponKind) {
+    /// Обновить тайминги для руки из архетипа оружия
+    pub fn set_weapon(
+        &mut self,
+        side: crate::player::arm::ArmSide,
+        kind: WeaponKind,
+        archetype: &WeaponArchetype,
+    ) {
         use crate::player::arm::ArmSide;
         match side {
             ArmSide::Right => {
                 self.right_weapon = kind;
-                self.right_light = AttackTimings::for_weapon(kind, AttackType::Light);
-                self.right_heavy = AttackTimings::for_weapon(kind, AttackType::Heavy);
+                self.right_light = archetype.light;
+                self.right_heavy = archetype.heavy;
             }
             ArmSide::Left => {
                 self.left_weapon = kind;
-                self.left_light = AttackTimings::for_weapon(kind, AttackType::Light);
-                self.left_heavy = AttackTimings::for_weapon(kind, AttackType::Heavy);
+                self.left_light = archetype.light;
+                self.left_heavy = archetype.heavy;
+            }
+        }
+    }
+
+    /// Переопределить стоимость атак для руки (из WeaponData)
+    pub fn set_stamina_cost(&mut self, side: crate::player::arm::ArmSide, light_cost: f32) {
+        use crate::player::arm::ArmSide;
+        let heavy_cost = light_cost * AttackTimings::HEAVY_STAMINA_MULT;
+        match side {
+            ArmSide::Right => {
+                self.right_light.stamina_cost = light_cost;
+                self.right_heavy.stamina_cost = heavy_cost;
+            }
+            ArmSide::Left => {
+                self.left_light.stamina_cost = light_cost;
+                self.left_heavy.stamina_cost = heavy_cost;
             }
         }
     }
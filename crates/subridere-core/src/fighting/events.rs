@@ -1,8 +1,20 @@
 use bevy::prelude::*;
 
+use crate::player::arm::ArmSide;
+
 /// Событие: melee удар попал по цели
 #[derive(Event)]
 pub struct MeleeHitEvent {
+    /// Корневая сущность цели (с Health)
     pub target: Entity,
+    /// Кто нанёс удар
+    pub attacker: Entity,
+    /// Сущность, в коллайдер которой пришёлся удар (голова/сегмент)
+    pub hit_entity: Entity,
     pub damage: f32,
+    /// Какая рука нанесла удар
+    pub side: ArmSide,
+    pub charge_level: f32,
+    /// Направление удара (forward камеры)
+    pub direction: Vec3,
 }
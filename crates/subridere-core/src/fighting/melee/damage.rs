@@ -1,12 +1,21 @@
 // crates/subridere-core/src/fighting/melee/damage.rs
 
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
+use crate::block_body::BlockBodyPart;
+use crate::camera::shake::CameraShakeEvent;
+use crate::enemies::Enemy;
 use crate::fighting::components::{ArmCombatState, AttackPhase, ChargeConfig, PlayerCombatState};
-use crate::items::WorldItem;
+use crate::fighting::events::MeleeHitEvent;
+use crate::fighting::weapon::arm_weapon_damage;
+use crate::inventory::Equipment;
+use crate::items::{ItemRegistry, WorldItem};
 use crate::player::arm::{ArmSide, MeleeHitbox};
 use crate::player::component::Player;
+use crate::stats::{ComputedStats, Damage, Health};
 
 /// Базовая скорость для "среднего" предмета (5kg)
 const BASE_VELOCITY: f32 = 5.0;
@@ -19,19 +28,33 @@ const MAX_LIGHT_BONUS: f32 = 2.0;
 /// Минимальный множитель для тяжёлых предметов
 const MIN_HEAVY_FACTOR: f32 = 0.5;
 
+/// Минимальный урон удара (даже с нулевой силой)
+const MIN_MELEE_DAMAGE: f32 = 1.0;
+/// Базовый импульс отброса врага
+const ENEMY_KNOCKBACK_IMPULSE: f32 = 6.0;
+/// Вертикальная составляющая отброса врага
+const ENEMY_LIFT_IMPULSE: f32 = 2.0;
+
 pub fn process_melee_collisions(
     mut commands: Commands,
     mut collision_events: EventReader<CollisionEvent>,
-    mut player_query: Query<&mut PlayerCombatState, With<Player>>,
+    mut player_query: Query<
+        (Entity, &mut PlayerCombatState, &ComputedStats, &Equipment),
+        With<Player>,
+    >,
     hitbox_query: Query<(Entity, &MeleeHitbox)>,
     camera_query: Query<&GlobalTransform, With<Camera>>,
     world_items: Query<Entity, With<WorldItem>>,
+    enemies: Query<(), (With<Enemy>, With<Health>)>,
+    body_parts: Query<&BlockBodyPart>,
     mass_query: Query<&AdditionalMassProperties>,
     parent_query: Query<&ChildOf>,
     names: Query<&Name>,
     charge_config: Res<ChargeConfig>,
+    registry: Res<ItemRegistry>,
+    mut hit_events: EventWriter<MeleeHitEvent>,
 ) {
-    let Ok(mut combat) = player_query.single_mut() else {
+    let Ok((player, mut combat, stats, equipment)) = player_query.single_mut() else {
         collision_events.clear();
         return;
     };
@@ -66,6 +89,8 @@ pub fn process_melee_collisions(
 
     // targets теперь хранит (Entity, ArmSide, charge_level)
     let mut targets: Vec<(Entity, ArmSide, f32)> = Vec::new();
+    // enemy_targets: (корень врага, задетая часть, ArmSide, charge_level)
+    let mut enemy_targets: Vec<(Entity, Entity, ArmSide, f32)> = Vec::new();
 
     for (i, event) in events.iter().enumerate() {
         let CollisionEvent::Started(e1, e2, _) = event else {
@@ -136,22 +161,66 @@ pub fn process_melee_collisions(
             } else {
                 info!("       ⏭️ already in targets");
             }
+            continue;
+        }
+
+        let enemy = find_enemy_root(root, &body_parts);
+        if enemies.get(enemy).is_ok() {
+            if !enemy_targets.iter().any(|(e, _, _, _)| *e == enemy) {
+                enemy_targets.push((enemy, target_entity, hitbox_side, charge_level));
+                info!("       ✓ added to enemy targets");
+            } else {
+                info!("       ⏭️ already in enemy targets");
+            }
         } else {
-            info!("       ❌ not a WorldItem");
+            info!("       ❌ not a WorldItem or Enemy");
         }
     }
 
-    if targets.is_empty() {
+    if targets.is_empty() && enemy_targets.is_empty() {
         return;
     }
 
-    info!("────────────────────────────────────────────────────");
-    info!("💥 HIT! Applying impulse to {} targets", targets.len());
-
     // Собираем какие руки попали
     let mut right_hit = false;
     let mut left_hit = false;
 
+    for (enemy, hit_entity, side, charge_level) in enemy_targets.iter() {
+        let damage = compute_melee_damage(
+            stats,
+            equipment,
+            &registry,
+            *side,
+            *charge_level,
+            &charge_config,
+        );
+
+        info!(
+            "🗡️ ENEMY HIT: '{}' takes {:.1} damage (charge: {:.0}%)",
+            names.get(*enemy).map(|n| n.as_str()).unwrap_or("?"),
+            damage,
+            charge_level * 100.0
+        );
+
+        hit_events.write(MeleeHitEvent {
+            target: *enemy,
+            attacker: player,
+            hit_entity: *hit_entity,
+            damage,
+            side: *side,
+            charge_level: *charge_level,
+            direction: punch_direction,
+        });
+
+        match side {
+            ArmSide::Right => right_hit = true,
+            ArmSide::Left => left_hit = true,
+        }
+    }
+
+    info!("────────────────────────────────────────────────────");
+    info!("💥 HIT! Applying impulse to {} targets", targets.len());
+
     for (root, side, charge_level) in targets.iter() {
         let name = names.get(*root).map(|n| n.as_str()).unwrap_or("?");
 
@@ -207,6 +276,70 @@ pub fn process_melee_collisions(
     }
 }
 
+/// Применяет попадания по врагам: урон, отброс с учётом сопротивления, тряска камеры
+pub fn apply_melee_hits(
+    mut commands: Commands,
+    mut hit_events: EventReader<MeleeHitEvent>,
+    targets: Query<Option<&ComputedStats>, With<Health>>,
+    charge_config: Res<ChargeConfig>,
+    mut shake_events: EventWriter<CameraShakeEvent>,
+) {
+    // Обе руки могут попасть в одну цель в одном кадре — суммируем урон
+    let mut damage_by_target: HashMap<Entity, (f32, Entity)> = HashMap::new();
+
+    for event in hit_events.read() {
+        let Ok(target_stats) = targets.get(event.target) else {
+            continue;
+        };
+
+        let knockback_resist = target_stats.map(|s| s.knockback_resist).unwrap_or(0.0);
+        let knockback = charge_config.knockback_mult(event.charge_level) * (1.0 - knockback_resist);
+
+        let impulse = event.direction * ENEMY_KNOCKBACK_IMPULSE * knockback
+            + Vec3::Y * ENEMY_LIFT_IMPULSE * knockback;
+
+        commands.entity(event.hit_entity).insert(ExternalImpulse {
+            impulse,
+            torque_impulse: Vec3::ZERO,
+        });
+
+        damage_by_target
+            .entry(event.target)
+            .or_insert((0.0, event.attacker))
+            .0 += event.damage;
+
+        shake_events.write(CameraShakeEvent);
+    }
+
+    for (target, (amount, attacker)) in damage_by_target {
+        Damage::physical(amount)
+            .with_source(attacker)
+            .add_to(&mut commands.entity(target));
+    }
+}
+
+/// Урон удара: melee_damage без оружия другой руки, умноженный на заряд
+fn compute_melee_damage(
+    stats: &ComputedStats,
+    equipment: &Equipment,
+    registry: &ItemRegistry,
+    side: ArmSide,
+    charge_level: f32,
+    charge_config: &ChargeConfig,
+) -> f32 {
+    // melee_damage уже включает урон всего экипированного оружия (через модификаторы),
+    // поэтому оставляем только оружие ударившей руки
+    let base = stats.melee_damage - equipment.total_damage(registry)
+        + arm_weapon_damage(side, equipment, registry);
+
+    base.max(MIN_MELEE_DAMAGE) * charge_config.damage_mult(charge_level)
+}
+
+/// Находит корень врага: части физических блочных тел (червь) ссылаются на корень через BlockBodyPart, а не через ChildOf
+fn find_enemy_root(entity: Entity, body_parts: &Query<&BlockBodyPart>) -> Entity {
+    body_parts.get(entity).map_or(entity, |part| part.root)
+}
+
 /// Проверяет может ли рука нанести урон (Active фаза, урон ещё не нанесён)
 fn can_arm_hit(arm: &ArmCombatState) -> bool {
     matches!(
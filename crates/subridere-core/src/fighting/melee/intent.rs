@@ -12,7 +12,8 @@ pub enum AttackInputState {
     Pressed,
     /// Кнопка только что отпущена — выпустить атаку/завершить
     Released,
-    // TODO: Held — для щита (пока кнопка удерживается)
+    /// Кнопка удерживается — каждый кадр после Pressed (блок щитом)
+    Held,
 }
 
 /// Intent: ввод правой руки (ЛКМ)
@@ -4,6 +4,6 @@ pub mod damage;
 pub mod intent;
 pub mod state;
 
-pub use damage::process_melee_collisions;
+pub use damage::{apply_melee_hits, process_melee_collisions};
 pub use intent::{AttackInputState, LeftAttackInput, RightAttackInput};
 pub use state::{is_any_arm_active, is_arm_in_active_phase, process_combat_state};
@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 
+use crate::fighting::archetype::WeaponArchetypes;
 use crate::fighting::components::{
     ArmCombatState, AttackPhase, AttackType, ChargeConfig, CurrentAttackTimings, PlayerCombatState,
     WeaponKind,
@@ -9,17 +10,23 @@ use crate::fighting::components::{
 use crate::fighting::melee::{AttackInputState, LeftAttackInput, RightAttackInput};
 use crate::player::arm::ArmSide;
 use crate::player::component::Player;
+use crate::stats::Stamina;
+
+/// Сколько опускается щит: до этого блок снова не поднять
+const BLOCK_LOWER_TIME: f32 = 0.5;
 
 /// Система обработки боевых состояний (обе руки независимо)
 pub fn process_combat_state(
     mut commands: Commands,
     time: Res<Time>,
     timings: Res<CurrentAttackTimings>,
+    archetypes: Res<WeaponArchetypes>,
     charge_config: Res<ChargeConfig>,
     mut query: Query<
         (
             Entity,
             &mut PlayerCombatState,
+            &mut Stamina,
             Option<&RightAttackInput>,
             Option<&LeftAttackInput>,
         ),
@@ -28,73 +35,76 @@ pub fn process_combat_state(
 ) {
     let dt = time.delta_secs();
 
-    for (entity, mut combat, right_input, left_input) in &mut query {
+    for (entity, mut combat, mut stamina, right_input, left_input) in &mut query {
         // Правая рука
-        let (right_pressed, right_released) = extract_right_input(right_input);
         if right_input.is_some() {
             commands.entity(entity).remove::<RightAttackInput>();
         }
         process_arm(
             &mut combat.right,
-            right_pressed,
-            right_released,
+            right_input.map(|input| input.0),
             "RIGHT",
             ArmSide::Right,
             dt,
+            &mut stamina,
             &timings,
+            &archetypes,
             &charge_config,
         );
 
         // Левая рука
-        let (left_pressed, left_released) = extract_left_input(left_input);
         if left_input.is_some() {
             commands.entity(entity).remove::<LeftAttackInput>();
         }
         process_arm(
             &mut combat.left,
-            left_pressed,
-            left_released,
+            left_input.map(|input| input.0),
             "LEFT",
             ArmSide::Left,
             dt,
+            &mut stamina,
             &timings,
+            &archetypes,
             &charge_config,
         );
     }
 }
 
-fn extract_right_input(input: Option<&RightAttackInput>) -> (bool, bool) {
-    match input {
-        Some(RightAttackInput(AttackInputState::Pressed)) => (true, false),
-        Some(RightAttackInput(AttackInputState::Released)) => (false, true),
-        None => (false, false),
-    }
-}
-
-fn extract_left_input(input: Option<&LeftAttackInput>) -> (bool, bool) {
-    match input {
-        Some(LeftAttackInput(AttackInputState::Pressed)) => (true, false),
-        Some(LeftAttackInput(AttackInputState::Released)) => (false, true),
-        None => (false, false),
-    }
-}
-
 fn process_arm(
     arm_state: &mut ArmCombatState,
-    pressed: bool,
-    released: bool,
+    input: Option<AttackInputState>,
     side_name: &str,
     side: ArmSide,
     dt: f32,
+    stamina: &mut Stamina,
     timings: &CurrentAttackTimings,
+    archetypes: &WeaponArchetypes,
     charge_config: &ChargeConfig,
 ) {
     // Получаем текущее оружие для этой руки
     let weapon_kind = timings.weapon(side);
+    let can_block = archetypes.get(weapon_kind).block.is_some();
+
+    let pressed = input == Some(AttackInputState::Pressed);
+    let released = input == Some(AttackInputState::Released);
+    let held = matches!(
+        input,
+        Some(AttackInputState::Pressed | AttackInputState::Held)
+    );
 
     match arm_state {
         ArmCombatState::Ready => {
-            if pressed {
+            // Блокирующее оружие поднимается вместо атаки; без стамины тоже —
+            // но такой блок пробьёт первый же удар
+            if pressed && can_block {
+                info!("🛡️ {} ARM: Ready → Blocking ({:?})", side_name, weapon_kind);
+                *arm_state = ArmCombatState::Blocking {
+                    block_timer: 0.0,
+                    weapon_kind,
+                };
+            } else if pressed && stamina.is_exhausted() {
+                info!("😮‍💨 {} ARM: too exhausted to attack", side_name);
+            } else if pressed {
                 info!("⚔️ {} ARM: Ready → Charging ({:?})", side_name, weapon_kind);
                 *arm_state = ArmCombatState::Charging {
                     charge_timer: 0.0,
@@ -111,14 +121,21 @@ fn process_arm(
 
             if released {
                 // Определяем тип атаки по времени заряда
-                let is_heavy = *charge_timer >= charge_config.heavy_threshold;
-                let attack_type = if is_heavy {
-                    AttackType::Heavy
-                } else {
-                    AttackType::Light
+                let wants_heavy = *charge_timer >= charge_config.heavy_threshold;
+
+                // Без стамины тяжёлая атака ослабляется до лёгкой, лёгкая — отменяется
+                let Some(attack_type) = pay_attack_cost(stamina, timings, side, wants_heavy) else {
+                    info!("😮‍💨 {} ARM: Charging → Ready (no stamina)", side_name);
+                    *arm_state = ArmCombatState::Ready;
+                    return;
                 };
 
-                let charge_level = charge_config.charge_level(*charge_timer);
+                let is_heavy = attack_type == AttackType::Heavy;
+                let charge_level = if is_heavy {
+                    charge_config.charge_level(*charge_timer)
+                } else {
+                    0.0
+                };
 
                 let type_name = if is_heavy { "Heavy" } else { "Light" };
                 info!(
@@ -178,6 +195,49 @@ fn process_arm(
                 }
             }
         }
+
+        ArmCombatState::Blocking {
+            block_timer,
+            weapon_kind: wk,
+        } => {
+            *block_timer += dt;
+
+            // Кнопку отпустили или щит сменили — блок опускается
+            if !held || *wk != weapon_kind || !can_block {
+                info!(
+                    "🛡️ {} ARM: Blocking ({:.2}s) → Lowering",
+                    side_name, block_timer
+                );
+                *arm_state = ArmCombatState::Lowering { timer: 0.0 };
+            }
+        }
+
+        ArmCombatState::Lowering { timer } => {
+            *timer += dt;
+
+            if *timer >= BLOCK_LOWER_TIME {
+                info!("🛡️ {} ARM: Lowering → Ready", side_name);
+                *arm_state = ArmCombatState::Ready;
+            }
+        }
+    }
+}
+
+/// Списать стамину за атаку. Возвращает фактический тип атаки или None если сил нет
+fn pay_attack_cost(
+    stamina: &mut Stamina,
+    timings: &CurrentAttackTimings,
+    side: ArmSide,
+    wants_heavy: bool,
+) -> Option<AttackType> {
+    if wants_heavy && stamina.spend(timings.get(side, AttackType::Heavy).stamina_cost) {
+        return Some(AttackType::Heavy);
+    }
+
+    if stamina.spend(timings.get(side, AttackType::Light).stamina_cost) {
+        Some(AttackType::Light)
+    } else {
+        None
     }
 }
 
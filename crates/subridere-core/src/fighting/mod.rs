@@ -1,11 +1,14 @@
 // fighting/mod.rs
 
+pub mod archetype;
+pub mod block;
 pub mod components;
 pub mod events;
 pub mod melee;
 pub mod plugin;
 pub mod weapon;
 
+pub use archetype::{WEAPON_ARCHETYPES_PATH, WeaponArchetypes, WeaponArchetypesHandle};
 pub use components::*;
 pub use plugin::CombatPlugin;
-pub use weapon::{arm_to_slot, get_weapon_kind};
+pub use weapon::{arm_to_slot, arm_weapon_damage, get_weapon_kind};
@@ -8,16 +8,21 @@ use crate::player::arm::{
     WeaponDebugState, apply_weapon_debug_transform, sync_equipped_weapon_visual, weapon_debug_input,
 };
 
+use super::archetype::{WeaponArchetypes, WeaponArchetypesLoader};
 use super::components::CurrentAttackTimings;
 use super::events::MeleeHitEvent;
-use super::melee::{process_combat_state, process_melee_collisions};
-use super::weapon::sync_weapon_timings;
+use super::melee::{apply_melee_hits, process_combat_state, process_melee_collisions};
+use super::weapon::{sync_weapon_hitboxes, sync_weapon_timings};
 
 pub struct CombatPlugin;
 
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CurrentAttackTimings>()
+        app.init_asset::<WeaponArchetypes>()
+            .init_asset_loader::<WeaponArchetypesLoader>()
+            // Кулаки для всего, пока ассет не загружен
+            .init_resource::<WeaponArchetypes>()
+            .init_resource::<CurrentAttackTimings>()
             .init_resource::<ChargeConfig>()
             .init_resource::<WeaponDebugState>()
             .add_event::<MeleeHitEvent>()
@@ -26,10 +31,12 @@ impl Plugin for CombatPlugin {
                 (
                     weapon_debug_input,
                     sync_weapon_timings,
+                    sync_weapon_hitboxes,
                     sync_equipped_weapon_visual,
                     apply_weapon_debug_transform,
                     process_combat_state,
                     process_melee_collisions,
+                    apply_melee_hits,
                 )
                     .chain()
                     .run_if(in_state(AppState::InGame)),
@@ -3,12 +3,15 @@
 // Хелперы для работы с оружием в боевой системе
 
 use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
 
 use crate::inventory::Equipment;
 use crate::items::{EquipmentSlot, ItemCategory, ItemRegistry};
-use crate::player::arm::ArmSide;
+use crate::player::arm::{ArmSide, MeleeHitbox, weapon_grip_transform};
+use crate::player::component::Player;
 
-use super::components::WeaponKind;
+use super::archetype::WeaponArchetypes;
+use super::components::{CurrentAttackTimings, WeaponKind};
 
 /// Конвертирует ArmSide в EquipmentSlot
 pub fn arm_to_slot(side: ArmSide) -> EquipmentSlot {
@@ -18,70 +21,102 @@ pub fn arm_to_slot(side: ArmSide) -> EquipmentSlot {
     }
 }
 
-/// Определяет тип оружия по экипировке
+/// Определяет тип оружия по экипировке (поле weapon_type предмета)
 pub fn get_weapon_kind(
     side: ArmSide,
     equipment: &Equipment,
     registry: &ItemRegistry,
 ) -> WeaponKind {
-    let slot = arm_to_slot(side);
-
-    let Some(item_id) = equipment.get(slot) else {
+    let Some(item_id) = equipment.get(arm_to_slot(side)) else {
         return WeaponKind::Fists;
     };
 
-    let def = registry.get(item_id);
+    match &registry.get(item_id).category {
+        ItemCategory::Weapon(weapon_data) => weapon_data.weapon_type,
+        _ => WeaponKind::Fists,
+    }
+}
 
-    match &def.category {
-        ItemCategory::Weapon(weapon_data) => {
-            // Магическое оружие (посох) — пока Fists
-            if weapon_data.mana_cost > 0.0 {
-                // TODO: WeaponKind::Staff
-                return WeaponKind::Fists;
-            }
+/// Урон оружия в руке (0 для кулаков)
+pub fn arm_weapon_damage(side: ArmSide, equipment: &Equipment, registry: &ItemRegistry) -> f32 {
+    equipment
+        .get(arm_to_slot(side))
+        .and_then(|item_id| registry.get(item_id).damage())
+        .unwrap_or(0.0)
+}
 
-            // Определяем по id или характеристикам
-            // TODO: добавить поле weapon_type в WeaponData
-            let item_name = def.id.as_str();
-
-            if item_name.contains("sword") || item_name.contains("blade") {
-                WeaponKind::Sword
-            } else if item_name.contains("hammer") || item_name.contains("mace") {
-                // TODO: WeaponKind::Hammer
-                WeaponKind::Fists
-            } else if item_name.contains("dagger") || item_name.contains("knife") {
-                // TODO: WeaponKind::Dagger
-                WeaponKind::Fists
-            } else {
-                // Неизвестное оружие — используем как кулаки
-                WeaponKind::Fists
-            }
-        }
-        // Щит в руке — пока Fists
-        // TODO: WeaponKind::Shield
-        _ => WeaponKind::Fists,
+/// Стоимость лёгкой атаки из определения оружия (если задана)
+pub fn arm_weapon_stamina_cost(
+    side: ArmSide,
+    equipment: &Equipment,
+    registry: &ItemRegistry,
+) -> Option<f32> {
+    let item_id = equipment.get(arm_to_slot(side))?;
+
+    match &registry.get(item_id).category {
+        ItemCategory::Weapon(weapon_data) => weapon_data.stamina_cost,
+        _ => None,
     }
 }
 
 /// Система синхронизации оружия с таймингами
 pub fn sync_weapon_timings(
-    player_query: Query<&Equipment, Changed<Equipment>>,
+    player_query: Query<Ref<Equipment>, With<Player>>,
     registry: Res<ItemRegistry>,
-    mut timings: ResMut<super::components::CurrentAttackTimings>,
+    archetypes: Res<WeaponArchetypes>,
+    mut timings: ResMut<CurrentAttackTimings>,
 ) {
     for equipment in &player_query {
-        // Правая рука
-        let right_kind = get_weapon_kind(ArmSide::Right, equipment, &registry);
-        if timings.right_weapon != right_kind {
-            timings.set_weapon(ArmSide::Right, right_kind);
-            info!("⚔️ Right hand weapon: {:?}", right_kind);
+        // Архетипы подменяются целиком, когда загрузится ассет
+        if !equipment.is_changed() && !archetypes.is_changed() {
+            continue;
         }
 
-        // Левая рука
-        let left_kind = get_weapon_kind(ArmSide::Left, equipment, &registry);
-        if timings.left_weapon != left_kind {
-            timings.set_weapon(ArmSide::Left, left_kind);
-            info!("⚔️ Left hand weapon: {:?}", left_kind);
+        for side in [ArmSide::Right, ArmSide::Left] {
+            let kind = get_weapon_kind(side, &equipment, &registry);
+            if timings.weapon(side) != kind {
+                info!("⚔️ {:?} hand weapon: {:?}", side, kind);
+            }
+
+            // Всегда пересобираем: оружие того же типа может иметь другую стоимость
+            timings.set_weapon(side, kind, archetypes.get(kind));
+            if let Some(cost) = arm_weapon_stamina_cost(side, &equipment, &registry) {
+                timings.set_stamina_cost(side, cost);
+            }
         }
     }
 }
+
+/// Система подгонки хитбокса руки под архетип оружия.
+/// Хитбокс задан в осях оружия и смещён на reach вдоль клинка от хвата.
+pub fn sync_weapon_hitboxes(
+    player_query: Query<Ref<Equipment>, With<Player>>,
+    registry: Res<ItemRegistry>,
+    archetypes: Res<WeaponArchetypes>,
+    mut hitbox_query: Query<(&MeleeHitbox, &mut Collider)>,
+) {
+    let Ok(equipment) = player_query.single() else {
+        return;
+    };
+    if !equipment.is_changed() && !archetypes.is_changed() {
+        return;
+    }
+
+    for (hitbox, mut collider) in &mut hitbox_query {
+        let archetype = archetypes.get(get_weapon_kind(hitbox.side, &equipment, &registry));
+
+        // Пустая рука — хитбокс на кисти, иначе по хвату оружия
+        let grip = if equipment.get(arm_to_slot(hitbox.side)).is_some() {
+            weapon_grip_transform(hitbox.side)
+        } else {
+            Transform::IDENTITY
+        };
+
+        let half = archetype.hitbox_half_extents();
+        *collider = Collider::compound(vec![(
+            grip.transform_point(Vec3::Y * archetype.reach),
+            grip.rotation,
+            Collider::cuboid(half.x, half.y, half.z),
+        )]);
+    }
+}
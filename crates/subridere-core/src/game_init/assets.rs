@@ -3,6 +3,12 @@ use bevy::prelude::*;
 // DISABLED - rebuilding items
 // use crate::items::{definition::ItemDefinition, visual::definition::VisualDefinition};
 
+use crate::enemies::worm::spawn::{WORM_MODEL_PATH, WormModel};
+use crate::fighting::{WEAPON_ARCHETYPES_PATH, WeaponArchetypes, WeaponArchetypesHandle};
+use crate::world::config::{DUNGEON_CONFIG_PATH, DungeonConfig, DungeonConfigHandle};
+use crate::world::room::template::{RoomTemplate, RoomTemplateHandles, RoomTemplates};
+use crate::world::room::types::RoomKind;
+
 use super::state::InitStage;
 
 /// Resource to store loaded asset handles (placeholder for now)
@@ -14,12 +20,23 @@ pub struct GameAssets {
 /// Load all game assets
 pub fn load_game_assets(
     mut commands: Commands,
-    _asset_server: Res<AssetServer>,
+    asset_server: Res<AssetServer>,
     mut next_state: ResMut<NextState<InitStage>>,
 ) {
     info!("📦 Loading game assets...");
 
     commands.insert_resource(GameAssets::default());
+    commands.insert_resource(DungeonConfigHandle(asset_server.load(DUNGEON_CONFIG_PATH)));
+    commands.insert_resource(RoomTemplateHandles(
+        RoomKind::ALL
+            .iter()
+            .map(|kind| (*kind, asset_server.load(kind.template_path())))
+            .collect(),
+    ));
+    commands.insert_resource(WormModel(asset_server.load(WORM_MODEL_PATH)));
+    commands.insert_resource(WeaponArchetypesHandle(
+        asset_server.load(WEAPON_ARCHETYPES_PATH),
+    ));
 
     info!("✅ Asset handles created");
     next_state.set(InitStage::AssetsLoading);
@@ -27,10 +44,66 @@ pub fn load_game_assets(
 
 /// Wait for assets to finish loading
 pub fn wait_for_assets(
+    mut commands: Commands,
     mut next_state: ResMut<NextState<InitStage>>,
-    // No assets to wait for currently
+    asset_server: Res<AssetServer>,
+    config_handle: Res<DungeonConfigHandle>,
+    configs: Res<Assets<DungeonConfig>>,
+    template_handles: Res<RoomTemplateHandles>,
+    room_templates: Res<Assets<RoomTemplate>>,
+    worm_model: Res<WormModel>,
+    archetypes_handle: Res<WeaponArchetypesHandle>,
+    weapon_archetypes: Res<Assets<WeaponArchetypes>>,
 ) {
-    // Skip straight to next stage
+    let pending = std::iter::once(config_handle.0.id().untyped())
+        .chain(template_handles.0.iter().map(|(_, h)| h.id().untyped()))
+        .chain(std::iter::once(worm_model.0.id().untyped()))
+        .chain(std::iter::once(archetypes_handle.0.id().untyped()))
+        .any(|id| {
+            let state = asset_server.load_state(id);
+            !state.is_loaded() && !state.is_failed()
+        });
+    if pending {
+        return;
+    }
+
+    let config = configs.get(&config_handle.0).cloned().unwrap_or_else(|| {
+        warn!(
+            "⚠️ Failed to load {}, using default dungeon config",
+            DUNGEON_CONFIG_PATH
+        );
+        DungeonConfig::default()
+    });
+
+    info!(
+        "🗺️ Dungeon config: {}x{} rooms, {} floors, {:?}, braid {}",
+        config.width, config.height, config.floors, config.algorithm, config.braid
+    );
+    commands.insert_resource(config);
+
+    let mut templates = RoomTemplates::default();
+    for (kind, handle) in &template_handles.0 {
+        match room_templates.get(handle) {
+            Some(template) => templates.insert(*kind, template.clone()),
+            None => warn!(
+                "⚠️ Failed to load {}, using default room template",
+                kind.template_path()
+            ),
+        }
+    }
+    commands.insert_resource(templates);
+
+    match weapon_archetypes.get(&archetypes_handle.0) {
+        Some(archetypes) => {
+            info!("⚔️ Weapon archetypes: {}", archetypes.archetypes.len());
+            commands.insert_resource(archetypes.clone());
+        }
+        None => warn!(
+            "⚠️ Failed to load {}, every weapon fights as fists",
+            WEAPON_ARCHETYPES_PATH
+        ),
+    }
+
     info!("✅ All assets loaded!");
     next_state.set(InitStage::MazeReady);
 }
@@ -1,65 +1,113 @@
-use crate::enemies::worm::spawn::spawn_worm;
+use crate::block_body::BlockBodyAsset;
+use crate::enemies::worm::spawn::{WORM_MODEL_PATH, WormModel, spawn_worm};
+use crate::game_init::loot::{
+    floor_levels, scaled_count, sorted_room_positions, spawnable_room_positions,
+};
+use crate::world::config::DungeonConfig;
+use crate::world::room::template::RoomTemplates;
 use crate::world::room::types::RoomMap;
+use crate::world::seed::{SeedStream, WorldSeed};
 use bevy::prelude::*;
 use rand::Rng;
 
 use super::state::InitStage;
 use crate::core::components::GameEntity;
 
-/// Spawns worms randomly across maze rooms
+/// Spawns worms randomly across maze rooms, scaled by floor difficulty
 pub fn spawn_test_enemies(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     mut next_state: ResMut<NextState<InitStage>>,
     room_map: Res<RoomMap>,
+    seed: Res<WorldSeed>,
+    config: Res<DungeonConfig>,
+    templates: Res<RoomTemplates>,
+    worm_model: Res<WormModel>,
+    block_bodies: Res<Assets<BlockBodyAsset>>,
 ) {
-    let mut rng = rand::thread_rng();
+    let Some(worm) = block_bodies.get(&worm_model.0) else {
+        warn!("⚠️ Failed to load {}, skipping enemies", WORM_MODEL_PATH);
+        next_state.set(InitStage::ItemsReady);
+        return;
+    };
 
-    let room_size = Vec3::new(12.0, 6.0, 12.0);
+    let mut rng = seed.rng(SeedStream::Enemies);
+
+    let room_size = config.room_size();
     let spawn_height = 2.0;
 
-    let room_positions: Vec<IVec3> = room_map.rooms.keys().copied().collect();
+    let floors = floor_levels(&room_map);
 
-    if room_positions.is_empty() {
+    if floors.is_empty() {
         warn!("No rooms found in RoomMap!");
         next_state.set(InitStage::ItemsReady);
         return;
     }
 
-    let worm_count = 0; //rng.gen_range(3..=5);
-
-    info!(
-        "🐛 Spawning {} worms across {} rooms",
-        worm_count,
-        room_positions.len()
-    );
+    let base_worm_count = 0; //rng.gen_range(3..=5);
 
-    for i in 0..worm_count {
-        let room_idx = rng.gen_range(0..room_positions.len());
-        let room_pos = room_positions[room_idx];
+    for y in floors {
+        let room_positions = spawnable_room_positions(&room_map, y);
+        // Ниже — больше червей, и они живучее
+        let difficulty = config.difficulty(y);
+        let worm_count = scaled_count(base_worm_count, difficulty);
 
-        let world_pos = Vec3::new(
-            room_pos.x as f32 * room_size.x,
-            spawn_height,
-            room_pos.z as f32 * room_size.z,
+        info!(
+            "🐛 Spawning {} worms across {} rooms on floor {} (x{:.2})",
+            worm_count,
+            room_positions.len(),
+            y,
+            difficulty
         );
 
-        let offset = Vec3::new(rng.gen_range(-3.0..3.0), 0.0, rng.gen_range(-3.0..3.0));
+        for i in 0..worm_count {
+            let room_idx = rng.gen_range(0..room_positions.len());
+            let room_pos = room_positions[room_idx];
 
-        let final_pos = world_pos + offset;
+            let world_pos = room_pos.as_vec3() * room_size + Vec3::Y * spawn_height;
 
-        let worm_entity = spawn_worm(&mut commands, &mut meshes, &mut materials, final_pos);
+            let offset = Vec3::new(rng.gen_range(-3.0..3.0), 0.0, rng.gen_range(-3.0..3.0));
 
-        // ← ДОБАВИТЬ: пометить worm как GameEntity
-        commands.entity(worm_entity).insert(GameEntity);
+            let final_pos = world_pos + offset;
 
-        info!(
-            "  🐛 Worm {} spawned at room {:?} (world: {:?})",
-            i + 1,
-            room_pos,
-            final_pos
-        );
+            let worm_entity = spawn_worm(
+                &mut commands,
+                worm_model.0.clone(),
+                worm,
+                final_pos,
+                difficulty,
+            );
+
+            // ← ДОБАВИТЬ: пометить worm как GameEntity
+            commands.entity(worm_entity).insert(GameEntity);
+
+            info!(
+                "  🐛 Worm {} spawned at room {:?} (world: {:?})",
+                i + 1,
+                room_pos,
+                final_pos
+            );
+        }
+    }
+
+    // Засады из шаблонов комнат (арены)
+    for room_pos in sorted_room_positions(&room_map) {
+        let kind = room_map.rooms[&room_pos].kind;
+        let difficulty = config.difficulty(room_pos.y);
+        let origin = config.room_origin(room_pos);
+
+        for &(x, y, z) in &templates.get(kind).spawn_points {
+            let position = origin + Vec3::new(x, y, z);
+            let worm_entity = spawn_worm(
+                &mut commands,
+                worm_model.0.clone(),
+                worm,
+                position,
+                difficulty,
+            );
+            commands.entity(worm_entity).insert(GameEntity);
+
+            info!("  🐛 {:?} worm spawned at room {:?}", kind, room_pos);
+        }
     }
 
     next_state.set(InitStage::ItemsReady);
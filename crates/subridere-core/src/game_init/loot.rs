@@ -2,11 +2,37 @@ use bevy::prelude::*;
 use rand::Rng;
 
 use crate::items::{ItemId, ItemRegistry, spawn_world_item};
+use crate::world::config::DungeonConfig;
+use crate::world::room::template::RoomTemplates;
 use crate::world::room::types::RoomMap;
+use crate::world::seed::{SeedStream, WorldSeed};
 
 use super::state::InitStage;
 
-/// Spawns random loot items across maze rooms
+/// Base loot per floor: (item, count on the top floor, log label)
+const LOOT_TABLE: &[(ItemId, usize, &str)] = &[
+    // === WEAPONS ===
+    (ItemId::WoodenStaff, 2, "🪄 Staff"),
+    (ItemId::IronSword, 2, "⚔️ Sword"),
+    (ItemId::WoodenShield, 1, "🛡️ Shield"),
+    // === ARMOR ===
+    (ItemId::IronHelmet, 2, "🪖 Helmet"),
+    (ItemId::ChainmailVest, 1, "🦺 Chainmail"),
+    (ItemId::LeatherBootLeft, 2, "👢 Boot L"),
+    (ItemId::LeatherBootRight, 2, "👢 Boot R"),
+    (ItemId::IronGauntletLeft, 2, "🧤 Gauntlet L"),
+    (ItemId::IronGauntletRight, 2, "🧤 Gauntlet R"),
+    (ItemId::VitalityHelm, 2, "💚 Vitality Helmet"),
+    // === ACCESSORIES ===
+    (ItemId::GoldRing, 3, "💍 Ring"),
+    // === CONSUMABLES ===
+    (ItemId::HealthPotion, 4, "❤️ Health Pot"),
+    (ItemId::ManaPotion, 3, "💙 Mana Pot"),
+    // === MISC ===
+    (ItemId::Torch, 5, "🔥 Torch"),
+];
+
+/// Spawns random loot items across maze rooms, floor by floor
 pub fn spawn_loot(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -14,217 +40,114 @@ pub fn spawn_loot(
     mut next_state: ResMut<NextState<InitStage>>,
     room_map: Res<RoomMap>,
     registry: Res<ItemRegistry>,
+    seed: Res<WorldSeed>,
+    config: Res<DungeonConfig>,
+    templates: Res<RoomTemplates>,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = seed.rng(SeedStream::Loot);
 
-    let room_size = Vec3::new(12.0, 6.0, 12.0);
+    let room_size = config.room_size();
     let spawn_height = 1.0;
 
-    let room_positions: Vec<IVec3> = room_map.rooms.keys().copied().collect();
+    let floors = floor_levels(&room_map);
 
-    if room_positions.is_empty() {
+    if floors.is_empty() {
         warn!("No rooms found in RoomMap!");
         next_state.set(InitStage::Done);
         return;
     }
 
-    info!("🎲 Spawning loot across {} rooms", room_positions.len());
-
-    // === WEAPONS ===
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::WoodenStaff,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "🪄 Staff",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::IronSword,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "⚔️ Sword",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::WoodenShield,
-        1,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "🛡️ Shield",
-    );
+    // Каждый этаж получает свою порцию лута, ниже — больше
+    for y in floors {
+        let room_positions = spawnable_room_positions(&room_map, y);
+        let difficulty = config.difficulty(y);
+
+        info!(
+            "🎲 Spawning loot across {} rooms on floor {} (x{:.2})",
+            room_positions.len(),
+            y,
+            difficulty
+        );
+
+        for &(item_id, base_count, label) in LOOT_TABLE {
+            spawn_items_with_marker(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &registry,
+                item_id,
+                scaled_count(base_count, difficulty),
+                &room_positions,
+                room_size,
+                spawn_height,
+                &mut rng,
+                label,
+            );
+        }
+    }
 
-    // === ARMOR ===
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::IronHelmet,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "🪖 Helmet",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::ChainmailVest,
-        1,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "🦺 Chainmail",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::LeatherBootLeft,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "👢 Boot L",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::LeatherBootRight,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "👢 Boot R",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::IronGauntletLeft,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "🧤 Gauntlet L",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::IronGauntletRight,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "🧤 Gauntlet R",
-    );
+    // Лут из шаблонов комнат (сокровищницы, выход)
+    for room_pos in sorted_room_positions(&room_map) {
+        let kind = room_map.rooms[&room_pos].kind;
+        for entry in &templates.get(kind).loot {
+            if !rng.gen_bool(entry.chance.clamp(0.0, 1.0) as f64) {
+                continue;
+            }
+            spawn_items_with_marker(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &registry,
+                entry.item,
+                entry.count,
+                &[room_pos],
+                room_size,
+                spawn_height,
+                &mut rng,
+                &format!("🎁 {:?}", kind),
+            );
+        }
+    }
 
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::VitalityHelm,
-        2,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "💚 Vitality Helmet",
-    );
+    info!("✅ Loot spawning complete!");
+    next_state.set(InitStage::Done);
+}
 
-    // === ACCESSORIES ===
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::GoldRing,
-        3,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "💍 Ring",
-    );
+/// Room positions in a stable order (HashMap order differs between runs)
+pub fn sorted_room_positions(room_map: &RoomMap) -> Vec<IVec3> {
+    let mut positions: Vec<IVec3> = room_map.rooms.keys().copied().collect();
+    positions.sort_by_key(|p| (p.y, p.x, p.z));
+    positions
+}
 
-    // === CONSUMABLES ===
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::HealthPotion,
-        4,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "❤️ Health Pot",
-    );
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::ManaPotion,
-        3,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "💙 Mana Pot",
-    );
+/// Floor levels present in the map, top to bottom
+pub fn floor_levels(room_map: &RoomMap) -> Vec<i32> {
+    let mut floors: Vec<i32> = room_map.rooms.keys().map(|p| p.y).collect();
+    floors.sort_unstable_by(|a, b| b.cmp(a));
+    floors.dedup();
+    floors
+}
 
-    // === MISC ===
-    spawn_items_with_marker(
-        &mut commands,
-        &mut meshes,
-        &mut materials,
-        &registry,
-        ItemId::Torch,
-        5,
-        &room_positions,
-        room_size,
-        spawn_height,
-        &mut rng,
-        "🔥 Torch",
-    );
+/// Rooms of one floor suitable for spawning things.
+/// Stairwell/ladder/shaft rooms are skipped unless the floor has nothing else.
+pub fn spawnable_room_positions(room_map: &RoomMap, y: i32) -> Vec<IVec3> {
+    let on_floor: Vec<IVec3> = sorted_room_positions(room_map)
+        .into_iter()
+        .filter(|p| p.y == y)
+        .collect();
+
+    let open: Vec<IVec3> = on_floor
+        .iter()
+        .copied()
+        .filter(|p| !room_map.rooms[p].vertical.is_connector())
+        .collect();
+
+    if open.is_empty() { on_floor } else { open }
+}
 
-    info!("✅ Loot spawning complete!");
-    next_state.set(InitStage::Done);
+/// Count scaled by floor difficulty
+pub fn scaled_count(base: usize, difficulty: f32) -> usize {
+    (base as f32 * difficulty).round() as usize
 }
 
 /// Helper function to spawn multiple items and mark them as GameEntity
@@ -259,11 +182,7 @@ fn calculate_spawn_position(
     spawn_height: f32,
     rng: &mut impl Rng,
 ) -> Vec3 {
-    let world_pos = Vec3::new(
-        room_pos.x as f32 * room_size.x,
-        spawn_height,
-        room_pos.z as f32 * room_size.z,
-    );
+    let world_pos = room_pos.as_vec3() * *room_size + Vec3::Y * spawn_height;
 
     let offset = Vec3::new(rng.gen_range(-2.0..2.0), 0.0, rng.gen_range(-2.0..2.0));
 
@@ -1,100 +1,167 @@
-use crate::world::builders::room::spawn_simple_room;
-use crate::world::generators::maze::generate_maze;
-use crate::world::room::types::{DoorFlags, RoomMap, RoomMetadata, WallFlags};
+use crate::game_init::loot::sorted_room_positions;
+use crate::world::builders::grid::register_vertical_link;
+use crate::world::builders::themed::spawn_room_of_kind;
+use crate::world::config::DungeonConfig;
+use crate::world::generators::{FloorParams, assign_room_kinds, generate_floors};
+use crate::world::room::template::RoomTemplates;
+use crate::world::room::types::{
+    DoorFlags, RoomKind, RoomMap, RoomMetadata, VerticalFlags, WallFlags,
+};
+use crate::world::seed::{SeedStream, WorldSeed};
 use bevy::prelude::*;
-use rand::{Rng, thread_rng};
+use rand::Rng;
 
 use super::state::InitStage;
 use crate::core::components::GameEntity;
 
-/// Спавним лабиринт из комнат в сетке width×height
+/// Спавним лабиринт из комнат по DungeonConfig
 pub fn spawn_maze_rooms(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut next_state: ResMut<NextState<InitStage>>,
     mut room_map: ResMut<RoomMap>,
+    seed: Res<WorldSeed>,
+    config: Res<DungeonConfig>,
+    templates: Res<RoomTemplates>,
 ) {
-    let mut rng = thread_rng();
-    let width = 5;
-    let height = 5;
-    let room_size = Vec3::new(12.0, 6.0, 12.0);
-    let wall_t = 0.1;
-
-    // 1) Получаем граф лабиринта
-    let graph = generate_maze(width, height);
-
-    // 2) Регистрируем все клетки
-    for x in 0..width {
-        for z in 0..height {
-            let pos = IVec3::new(x, 0, z);
-            let has_light = rng.gen_bool(0.3);
-            let meta = RoomMetadata {
-                wall_flags: WallFlags::default(),
-                door_flags: DoorFlags::default(),
-                has_light,
-                entity: None,
-            };
-            room_map.rooms.insert(pos, meta);
-        }
-    }
+    let mut maze_rng = seed.rng(SeedStream::Maze);
+    let mut light_rng = seed.rng(SeedStream::Lights);
+    let width = config.width;
+    let height = config.height;
 
-    // 3) Устанавливаем флаги на основании графа
-    for x in 0..width {
-        for z in 0..height {
-            let cell = IVec2::new(x, z);
-            let pos3 = IVec3::new(x, 0, z);
-            let meta = room_map.rooms.get_mut(&pos3).unwrap();
-
-            let neighbors = graph.get(&cell).cloned().unwrap_or_default();
-
-            // back (z-1)
-            if neighbors.contains(&(cell + IVec2::new(0, -1))) {
-                meta.door_flags.back = true;
-                meta.wall_flags.back = false;
-            } else {
-                meta.wall_flags.back = true;
-            }
-            // front (z+1)
-            if neighbors.contains(&(cell + IVec2::new(0, 1))) {
-                meta.door_flags.front = true;
-                meta.wall_flags.front = false;
-            } else {
-                meta.wall_flags.front = true;
-            }
-            // left (x-1)
-            if neighbors.contains(&(cell + IVec2::new(-1, 0))) {
-                meta.door_flags.left = true;
-                meta.wall_flags.left = false;
-            } else {
-                meta.wall_flags.left = true;
+    // 1) Получаем графы этажей выбранным алгоритмом
+    let params = FloorParams {
+        width,
+        height,
+        floors: config.floors,
+        braid: config.braid,
+        links_per_floor: config.links_per_floor,
+    };
+    let generator = config.algorithm.generator();
+    let layout = generate_floors(generator.as_ref(), &params, &mut maze_rng);
+
+    // 2) Для каждого этажа регистрируем клетки и ставим флаги стен/дверей
+    for (y, graph) in &layout.floors {
+        // Регистрируем клетки графа (некоторые алгоритмы оставляют пустоты)
+        for x in 0..width {
+            for z in 0..height {
+                if !graph.contains_key(&IVec2::new(x, z)) {
+                    continue;
+                }
+                let pos = IVec3::new(x, *y, z);
+                let meta = RoomMetadata {
+                    kind: RoomKind::Normal,
+                    wall_flags: WallFlags::default(),
+                    door_flags: DoorFlags::default(),
+                    vertical: VerticalFlags::default(),
+                    has_light: false,
+                    entity: None,
+                };
+                room_map.rooms.insert(pos, meta);
             }
-            // right (x+1)
-            if neighbors.contains(&(cell + IVec2::new(1, 0))) {
-                meta.door_flags.right = true;
-                meta.wall_flags.right = false;
-            } else {
-                meta.wall_flags.right = true;
+        }
+
+        // Устанавливаем флаги на основании графа
+        for x in 0..width {
+            for z in 0..height {
+                let cell = IVec2::new(x, z);
+                let pos3 = IVec3::new(x, *y, z);
+                let Some(meta) = room_map.rooms.get_mut(&pos3) else {
+                    continue;
+                };
+
+                let neighbors = graph.get(&cell).cloned().unwrap_or_default();
+
+                // back (z-1)
+                if neighbors.contains(&(cell + IVec2::new(0, -1))) {
+                    meta.door_flags.back = true;
+                    meta.wall_flags.back = false;
+                } else {
+                    meta.wall_flags.back = true;
+                }
+                // front (z+1)
+                if neighbors.contains(&(cell + IVec2::new(0, 1))) {
+                    meta.door_flags.front = true;
+                    meta.wall_flags.front = false;
+                } else {
+                    meta.wall_flags.front = true;
+                }
+                // left (x-1)
+                if neighbors.contains(&(cell + IVec2::new(-1, 0))) {
+                    meta.door_flags.left = true;
+                    meta.wall_flags.left = false;
+                } else {
+                    meta.wall_flags.left = true;
+                }
+                // right (x+1)
+                if neighbors.contains(&(cell + IVec2::new(1, 0))) {
+                    meta.door_flags.right = true;
+                    meta.wall_flags.right = false;
+                } else {
+                    meta.wall_flags.right = true;
+                }
             }
         }
     }
 
-    // 4) Спавним все комнаты
+    // 3) Переходы между этажами
+    for link in &layout.links {
+        let lower = IVec3::new(link.cell.x, link.lower, link.cell.y);
+        register_vertical_link(&mut room_map, lower, link.kind);
+    }
+
+    // 4) Типы комнат и свет по их шаблонам
+    let mut room_rng = seed.rng(SeedStream::Rooms);
+    let kinds = assign_room_kinds(&room_map, &mut room_rng);
+    for pos in sorted_room_positions(&room_map) {
+        let kind = kinds.get(&pos).copied().unwrap_or_default();
+        let light_chance = templates.get(kind).light_chance.clamp(0.0, 1.0);
+        let meta = room_map.rooms.get_mut(&pos).unwrap();
+        meta.kind = kind;
+        meta.has_light = light_rng.gen_bool(light_chance as f64);
+    }
+
+    // 5) Спавним все комнаты
+    spawn_rooms_from_map(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut room_map,
+        &config,
+        &templates,
+    );
+
+    info!(
+        "✅ Maze spawned: {} rooms on {}x{}x{} grid, {} floor links, {:?} (seed {})",
+        room_map.rooms.len(),
+        width,
+        height,
+        layout.floors.len(),
+        layout.links.len(),
+        config.algorithm,
+        seed.0
+    );
+}
+
+/// Спавним комнаты по уже заполненному RoomMap (генерация или загрузка сохранения)
+pub fn spawn_rooms_from_map(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    room_map: &mut RoomMap,
+    config: &DungeonConfig,
+    templates: &RoomTemplates,
+) {
     for (&pos3, meta) in room_map.rooms.iter_mut() {
-        let origin = Vec3::new(
-            pos3.x as f32 * room_size.x,
-            0.0,
-            pos3.z as f32 * room_size.z,
-        );
-        let ent = spawn_simple_room(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            origin,
-            room_size,
-            wall_t,
-            meta.wall_flags.clone(),
-            meta.door_flags.clone(),
+        let ent = spawn_room_of_kind(
+            commands,
+            meshes,
+            materials,
+            config.room_origin(pos3),
+            config.room_size(),
+            config.wall_thickness,
+            meta,
+            templates.get(meta.kind),
         );
 
         // ← ДОБАВИТЬ: пометить комнату как GameEntity
@@ -102,14 +169,12 @@ pub fn spawn_maze_rooms(
 
         meta.entity = Some(ent);
     }
-
-    info!("✅ Maze spawned: {}x{} rooms", width, height);
-    next_state.set(InitStage::MazeReady);
 }
 
 pub fn spawn_room_lights(
     mut commands: Commands,
     room_map: Res<RoomMap>,
+    templates: Res<RoomTemplates>,
     mut next_state: ResMut<NextState<InitStage>>,
 ) {
     for (_, room) in room_map.rooms.iter() {
@@ -118,17 +183,12 @@ pub fn spawn_room_lights(
         }
 
         let room_entity = room.entity.unwrap();
-        let light_pos = Vec3::new(0.0, 5.0, 0.0);
+        let light = &templates.get(room.kind).light;
+        let light_pos = Vec3::new(0.0, light.height, 0.0);
 
         commands.entity(room_entity).with_children(|child| {
             child.spawn((
-                PointLight {
-                    color: Color::srgb(0.0, 0.7, 0.0),
-                    intensity: 1_000_000.0,
-                    range: 200.0,
-                    shadows_enabled: true,
-                    ..default()
-                },
+                light.point_light(),
                 Transform::from_translation(light_pos),
                 Name::new("RoomLight"),
                 // Не нужен GameEntity - удалится вместе с родителем
@@ -8,7 +8,7 @@ use crate::{
     core::components::GameEntity,
     fighting::PlayerCombatState,
     input::component::PlayerControlled,
-    inventory::{Equipment, Inventory},
+    inventory::{Encumbrance, Equipment, Inventory},
     player::{
         arm::spawn_player_arms,
         body::{VisibleBodyConfig, spawn_visible_body},
@@ -64,6 +64,7 @@ pub fn spawn_player(
         .insert(StatsBundle::default())
         .insert(Inventory::default())
         .insert(Equipment::default())
+        .insert(Encumbrance::default())
         .insert(PlayerCombatState::default())
         .insert(GameEntity)
         .id();
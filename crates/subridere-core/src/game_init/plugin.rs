@@ -4,6 +4,8 @@ use crate::app::AppState;
 use crate::core::components::GameEntity;
 use crate::game_init::{lighting::spawn_lighting, player::spawn_player};
 use crate::items::registry::registry_loaded;
+use crate::save::systems::{restore_player, restore_rooms, restore_world_items};
+use crate::save::{has_pending_load, no_pending_load};
 use crate::ui::game_menu::state::GameMenuState; // ← ДОБАВИТЬ
 use crate::world::room::types::RoomMap;
 
@@ -36,18 +38,36 @@ impl Plugin for GameInitPlugin {
             // Init stages
             .add_systems(
                 OnEnter(InitStage::Setup),
-                (load_game_assets, spawn_maze_rooms, spawn_lighting),
+                (load_game_assets, spawn_lighting),
             )
             .add_systems(
                 Update,
                 wait_for_assets.run_if(in_state(InitStage::AssetsLoading)),
             )
-            .add_systems(OnEnter(InitStage::MazeReady), spawn_room_lights)
+            // Лабиринт строится после загрузки DungeonConfig
+            .add_systems(
+                OnEnter(InitStage::MazeReady),
+                (
+                    spawn_maze_rooms.run_if(no_pending_load),
+                    restore_rooms.run_if(has_pending_load),
+                    spawn_room_lights,
+                )
+                    .chain(),
+            )
             .add_systems(OnEnter(InitStage::LightsReady), spawn_player)
-            .add_systems(OnEnter(InitStage::EnemiesReady), spawn_test_enemies)
+            .add_systems(
+                OnEnter(InitStage::EnemiesReady),
+                (restore_player.run_if(has_pending_load), spawn_test_enemies).chain(),
+            )
+            // При загрузке сохранения предметы восстанавливаются вместо случайного лута
             .add_systems(
                 OnEnter(InitStage::ItemsReady),
-                spawn_loot.run_if(registry_loaded),
+                (
+                    spawn_loot.run_if(no_pending_load),
+                    restore_world_items.run_if(has_pending_load),
+                )
+                    .chain()
+                    .run_if(registry_loaded),
             )
             // Win condition check
             .add_systems(
@@ -1,6 +1,8 @@
 // src/game_init/rooms.rs
 
 use crate::world::builders::grid::{register_room, spawn_registered_rooms};
+use crate::world::config::DungeonConfig;
+use crate::world::room::template::RoomTemplates;
 use crate::world::room::types::RoomMap;
 use bevy::prelude::*;
 
@@ -9,10 +11,9 @@ pub fn spawn_initial_rooms(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut room_map: ResMut<RoomMap>,
+    config: Res<DungeonConfig>,
+    templates: Res<RoomTemplates>,
 ) {
-    let room_size = Vec3::new(12.0, 6.0, 12.0);
-    let wall_t = 0.1;
-
     for i in 0..3 {
         let pos = IVec3::new(0, 0, -i);
         register_room(&mut room_map, pos);
@@ -22,8 +23,9 @@ pub fn spawn_initial_rooms(
         &mut commands,
         &mut meshes,
         &mut materials,
-        room_size,
-        wall_t,
+        config.room_size(),
+        config.wall_thickness,
+        &templates,
         &mut room_map,
     );
 }
@@ -5,6 +5,8 @@ use crate::input::resources::InputSettings;
 use crate::ui::game_menu::game_menu_closed;
 use crate::ui::system_menu::system_menu_closed;
 
+use crate::fighting::melee::process_combat_state;
+
 use super::cursor::update_cursor_state;
 use super::systems::keyboard::{handle_keyboard_input, handle_melee_input};
 
@@ -19,6 +21,8 @@ impl Plugin for InputPlugin {
             .add_systems(
                 Update,
                 (handle_keyboard_input, handle_melee_input)
+                    // Held должен дойти до боевой системы в том же кадре
+                    .before(process_combat_state)
                     .run_if(in_state(AppState::InGame))
                     .run_if(game_menu_closed)
                     .run_if(system_menu_closed),
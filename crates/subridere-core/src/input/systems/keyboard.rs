@@ -53,7 +53,7 @@ pub fn handle_keyboard_input(
     }
 }
 
-/// Processes mouse input for melee attacks (pressed/released for charge system)
+/// Processes mouse input for melee attacks (pressed/released for charge system, held for blocking)
 pub fn handle_melee_input(
     buttons: Res<ButtonInput<MouseButton>>,
     mut commands: Commands,
@@ -68,6 +68,10 @@ pub fn handle_melee_input(
         commands
             .entity(player_entity)
             .insert(RightAttackInput(AttackInputState::Pressed));
+    } else if buttons.pressed(MouseButton::Left) {
+        commands
+            .entity(player_entity)
+            .insert(RightAttackInput(AttackInputState::Held));
     }
     if buttons.just_released(MouseButton::Left) {
         commands
@@ -80,6 +84,10 @@ pub fn handle_melee_input(
         commands
             .entity(player_entity)
             .insert(LeftAttackInput(AttackInputState::Pressed));
+    } else if buttons.pressed(MouseButton::Right) {
+        commands
+            .entity(player_entity)
+            .insert(LeftAttackInput(AttackInputState::Held));
     }
     if buttons.just_released(MouseButton::Right) {
         commands
@@ -207,6 +207,13 @@ impl Inventory {
             *slot = None;
         }
     }
+
+    /// Total weight of all carried stacks
+    pub fn total_weight(&self, registry: &ItemRegistry) -> f32 {
+        self.iter_items()
+            .map(|(_, stack)| registry.get(stack.id).weight * stack.quantity as f32)
+            .sum()
+    }
 }
 
 /// Equipment worn by character
@@ -261,6 +268,11 @@ impl Equipment {
             .sum()
     }
 
+    /// Total weight of equipped items
+    pub fn total_weight(&self, registry: &ItemRegistry) -> f32 {
+        self.slots.values().map(|&id| registry.get(id).weight).sum()
+    }
+
     /// Calculate total damage bonus from weapons
     pub fn total_damage(&self, registry: &ItemRegistry) -> f32 {
         self.slots
@@ -270,6 +282,61 @@ impl Equipment {
     }
 }
 
+/// Encumbrance tier — how heavily loaded the character is
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EncumbranceTier {
+    #[default]
+    Normal,
+    Burdened,
+    Overloaded,
+}
+
+impl EncumbranceTier {
+    /// Load ratio above which the character is burdened
+    pub const BURDENED_RATIO: f32 = 0.75;
+
+    pub fn from_load(weight: f32, capacity: f32) -> Self {
+        if capacity <= 0.0 {
+            return if weight > 0.0 {
+                Self::Overloaded
+            } else {
+                Self::Normal
+            };
+        }
+
+        let ratio = weight / capacity;
+        if ratio > 1.0 {
+            Self::Overloaded
+        } else if ratio > Self::BURDENED_RATIO {
+            Self::Burdened
+        } else {
+            Self::Normal
+        }
+    }
+
+    /// Movement acceleration multiplier
+    pub fn move_mult(&self) -> f32 {
+        match self {
+            Self::Normal => 1.0,
+            Self::Burdened => 0.7,
+            Self::Overloaded => 0.35,
+        }
+    }
+
+    /// Can the character dash?
+    pub fn can_dash(&self) -> bool {
+        *self != Self::Overloaded
+    }
+}
+
+/// Carried weight versus carry capacity (Inventory + Equipment)
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Encumbrance {
+    pub weight: f32,
+    pub capacity: f32,
+    pub tier: EncumbranceTier,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,4 +358,25 @@ mod tests {
         assert_eq!(inv.free_slots(), 10);
         assert!(!inv.is_full());
     }
+
+    #[test]
+    fn encumbrance_tiers() {
+        assert_eq!(
+            EncumbranceTier::from_load(10.0, 50.0),
+            EncumbranceTier::Normal
+        );
+        assert_eq!(
+            EncumbranceTier::from_load(40.0, 50.0),
+            EncumbranceTier::Burdened
+        );
+        assert_eq!(
+            EncumbranceTier::from_load(50.0, 50.0),
+            EncumbranceTier::Burdened
+        );
+        assert_eq!(
+            EncumbranceTier::from_load(51.0, 50.0),
+            EncumbranceTier::Overloaded
+        );
+        assert!(!EncumbranceTier::Overloaded.can_dash());
+    }
 }
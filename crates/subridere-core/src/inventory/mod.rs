@@ -4,5 +4,5 @@ pub mod component;
 pub mod plugin;
 pub mod systems;
 
-pub use component::{Equipment, Inventory};
+pub use component::{Encumbrance, EncumbranceTier, Equipment, Inventory};
 pub use plugin::InventoryPlugin;
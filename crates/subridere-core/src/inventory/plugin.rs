@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 
 use super::systems::drop::{DropToWorldEvent, handle_drop_to_world};
+use super::systems::encumbrance::update_encumbrance;
 use super::systems::equipment_stats::sync_equipment_modifiers;
 use super::systems::pickup::{
     TargetedItem, detect_pickupable_items, handle_pickup_input, process_pickup_intent,
@@ -28,6 +29,7 @@ impl Plugin for InventoryPlugin {
                     process_pickup_intent,
                     handle_drop_to_world,
                     sync_equipment_modifiers.before(recalculate_stats),
+                    update_encumbrance.after(recalculate_stats),
                 )
                     .run_if(registry_loaded),
             );
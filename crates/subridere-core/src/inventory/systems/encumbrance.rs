@@ -0,0 +1,38 @@
+// inventory/systems/encumbrance.rs
+
+use crate::inventory::component::{Encumbrance, EncumbranceTier, Equipment, Inventory};
+use crate::items::ItemRegistry;
+use crate::stats::ComputedStats;
+use bevy::prelude::*;
+
+/// Recalculate carried weight and encumbrance tier
+pub fn update_encumbrance(
+    mut query: Query<
+        (&Inventory, &Equipment, &ComputedStats, &mut Encumbrance),
+        Or<(
+            Changed<Inventory>,
+            Changed<Equipment>,
+            Changed<ComputedStats>,
+        )>,
+    >,
+    registry: Res<ItemRegistry>,
+) {
+    for (inventory, equipment, stats, mut encumbrance) in &mut query {
+        let weight = inventory.total_weight(&registry) + equipment.total_weight(&registry);
+        let capacity = stats.carry_capacity;
+        let tier = EncumbranceTier::from_load(weight, capacity);
+
+        if tier != encumbrance.tier {
+            info!(
+                "🎒 Encumbrance: {:?} → {:?} ({:.1}/{:.1})",
+                encumbrance.tier, tier, weight, capacity
+            );
+        }
+
+        *encumbrance = Encumbrance {
+            weight,
+            capacity,
+            tier,
+        };
+    }
+}
@@ -1,9 +1,11 @@
 // inventory/systems/mod.rs
 
 pub mod drop;
+pub mod encumbrance;
 pub mod equipment_stats;
 pub mod pickup;
 
 pub use drop::*;
+pub use encumbrance::update_encumbrance;
 pub use equipment_stats::sync_equipment_modifiers;
 pub use pickup::*;
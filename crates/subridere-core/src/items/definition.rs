@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::fighting::WeaponKind;
 use crate::stats::{ModifierOp, ModifierTarget};
 
 use super::flags::ItemFlags;
@@ -145,9 +146,18 @@ pub struct WeaponData {
     /// Equipment slot type
     pub slot: WeaponSlot,
 
+    /// Archetype (timings, arm poses, hitbox) from assets/weapons
+    #[serde(default)]
+    pub weapon_type: WeaponKind,
+
     /// Mana cost per attack (for magic weapons)
     #[serde(default)]
     pub mana_cost: f32,
+
+    /// Stamina cost of a light attack (heavy attacks cost more).
+    /// `None` uses the default for the weapon kind.
+    #[serde(default)]
+    pub stamina_cost: Option<f32>,
 }
 
 fn default_speed() -> f32 {
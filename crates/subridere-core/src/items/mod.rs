@@ -24,5 +24,5 @@ pub use plugin::ItemsPlugin;
 pub use registry::{ItemRegistry, registry_loaded};
 pub use slots::EquipmentSlot;
 pub use stack::ItemStack;
-pub use visual::{ItemVisual, VisualPart, VisualShape};
+pub use visual::{ItemVisual, VisualPart, VisualShape, spawn_item_visual_with_colliders};
 pub use world::{Pickupable, Targeted, WorldItem, spawn_world_item};
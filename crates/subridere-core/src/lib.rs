@@ -1,5 +1,6 @@
 pub mod app;
 pub mod audio;
+pub mod block_body;
 pub mod camera;
 pub mod core;
 pub mod enemies;
@@ -9,6 +10,7 @@ pub mod input;
 pub mod inventory;
 pub mod items;
 pub mod player;
+pub mod save;
 pub mod stats;
 pub mod ui;
 pub mod unit;
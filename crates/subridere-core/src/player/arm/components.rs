@@ -1,6 +1,10 @@
 // crates/subridere-core/src/player/arm/components.rs
 
 use bevy::prelude::*;
+use block_bodies_core::{BlockPose, ClipPlayer, MirrorAxis, PartPose};
+use serde::Deserialize;
+
+use crate::fighting::components::{AttackType, WeaponKind};
 
 // ═══════════════════════════════════════════════════════════════════
 // ARM SIDE
@@ -80,6 +84,23 @@ impl IkTarget {
     }
 }
 
+/// Клипы руки: при смене состояния боя запускается клип нового
+/// состояния, ClipPlayer плавно переводит в него из текущего
+#[derive(Component, Default)]
+pub struct ArmAnimator {
+    pub key: Option<ArmPoseKey>,
+    pub player: ClipPlayer,
+}
+
+/// Какой клип сейчас играет рука
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmPoseKey {
+    Ready(WeaponKind),
+    Charging(WeaponKind),
+    Attacking(WeaponKind, AttackType),
+    Blocking(WeaponKind),
+}
+
 // ═══════════════════════════════════════════════════════════════════
 // ARM CONFIG
 // ═══════════════════════════════════════════════════════════════════
@@ -115,13 +136,38 @@ impl Default for ArmConfig {
 // ARM POSES
 // ═══════════════════════════════════════════════════════════════════
 
-#[derive(Clone, Copy)]
+/// Части позы руки внутри BlockPose клипа
+const HAND_PART: &str = "Hand";
+const ELBOW_PART: &str = "Elbow";
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(from = "ArmPoseDef")]
 pub struct ArmPose {
     pub hand_offset: Vec3,
     pub elbow_hint: Vec3,
     pub hand_rotation: Quat,
 }
 
+/// Поза в RON архетипов: ротация кисти в градусах (pitch, yaw, roll)
+#[derive(Deserialize)]
+struct ArmPoseDef {
+    hand_offset: [f32; 3],
+    elbow_hint: [f32; 3],
+    #[serde(default)]
+    hand_rotation: [f32; 3],
+}
+
+impl From<ArmPoseDef> for ArmPose {
+    fn from(def: ArmPoseDef) -> Self {
+        let [pitch, yaw, roll] = def.hand_rotation;
+        Self {
+            hand_offset: Vec3::from_array(def.hand_offset),
+            elbow_hint: Vec3::from_array(def.elbow_hint),
+            hand_rotation: Self::rot(pitch, yaw, roll),
+        }
+    }
+}
+
 impl ArmPose {
     // ───────────────────────────────────────────────────────────────
     // ХЕЛПЕР ДЛЯ СОЗДАНИЯ РОТАЦИИ
@@ -151,7 +197,8 @@ impl ArmPose {
     }
 
     // ═══════════════════════════════════════════════════════════════
-    // FISTS (кулаки)
+    // FISTS (кулаки) — встроенный запасной вариант,
+    // позы остального оружия в assets/weapons
     // ═══════════════════════════════════════════════════════════════
 
     // ───────────────────────────────────────────────────────────────
@@ -207,84 +254,6 @@ impl ArmPose {
         }
     }
 
-    // ═══════════════════════════════════════════════════════════════
-    // SWORD (меч) — ГОРИЗОНТАЛЬНЫЙ СЛЭШ
-    // ═══════════════════════════════════════════════════════════════
-
-    // ───────────────────────────────────────────────────────────────
-    // SWORD IDLE
-    // ───────────────────────────────────────────────────────────────
-
-    /// Sword Idle - меч наготове, направлен вперёд-вверх
-    pub fn sword_idle_right() -> Self {
-        Self {
-            hand_offset: Vec3::new(0.25, -0.30, -0.55),
-            elbow_hint: Vec3::new(0.4, -0.4, 0.1),
-            // Кисть нейтральная, меч чуть наклонён
-            hand_rotation: Self::rot(0.0, 0.0, 0.0),
-        }
-    }
-
-    // ───────────────────────────────────────────────────────────────
-    // SWORD LIGHT (горизонтальный слэш справа налево)
-    // ───────────────────────────────────────────────────────────────
-
-    /// Sword Light Windup - меч отведён вправо, кисть развёрнута
-    pub fn sword_windup_right() -> Self {
-        Self {
-            hand_offset: Vec3::new(0.55, -0.15, -0.35),
-            elbow_hint: Vec3::new(0.60, -0.30, 0.30),
-            hand_rotation: Self::rot(0.0, 15.0, -30.0),
-        }
-    }
-
-    /// Sword Light Active - слэш влево, кисть вращается
-    pub fn sword_slash_right() -> Self {
-        Self {
-            hand_offset: Vec3::new(0.10, -0.25, -0.85),
-            elbow_hint: Vec3::new(0.10, -0.40, -0.40),
-            hand_rotation: Self::rot(75.0, -61.5, -11.1),
-        }
-    }
-
-    // ───────────────────────────────────────────────────────────────
-    // SWORD HEAVY (вертикальный удар сверху)
-    // ───────────────────────────────────────────────────────────────
-
-    /// Sword Heavy Charging - меч поднимается над головой (сдвинут вправо)
-    pub fn sword_heavy_charging_right() -> Self {
-        Self {
-            hand_offset: Vec3::new(0.40, 0.05, -0.30),
-            elbow_hint: Vec3::new(0.35, 0.1, 0.2),
-            // Кисть начинает подниматься
-            hand_rotation: Self::rot(-20.0, 0.0, 0.0),
-        }
-    }
-
-    /// Sword Heavy Windup - меч занесён над головой (сдвинут вправо)
-    pub fn sword_heavy_windup_right() -> Self {
-        Self {
-            hand_offset: Vec3::new(0.35, 0.25, -0.15),
-            elbow_hint: Vec3::new(0.30, 0.3, 0.35),
-            // Кисть отогнута назад, меч над головой
-            hand_rotation: Self::rot(-45.0, 0.0, 0.0),
-        }
-    }
-
-    /// Sword Heavy Active - вертикальный удар вниз-вперёд
-    pub fn sword_heavy_slash_right() -> Self {
-        Self {
-            hand_offset: Vec3::new(0.05, -0.45, -1.00),
-            elbow_hint: Vec3::new(0.15, -0.5, -0.5),
-            // Кисть резко вниз, завершение удара
-            hand_rotation: Self::rot(30.0, 0.0, 0.0),
-        }
-    }
-
-    // ═══════════════════════════════════════════════════════════════
-    // TODO: STAFF, HAMMER, SHIELD...
-    // ═══════════════════════════════════════════════════════════════
-
     // ───────────────────────────────────────────────────────────────
     // СПЕЦИАЛЬНЫЕ ПОЗЫ
     // ───────────────────────────────────────────────────────────────
@@ -313,21 +282,36 @@ impl ArmPose {
 
     /// Зеркальная версия для левой руки
     pub fn mirror(&self) -> ArmPose {
-        // Для зеркалирования Quat: инвертируем Y и Z компоненты
-        let mirrored_rotation = Quat::from_xyzw(
-            self.hand_rotation.x,
-            -self.hand_rotation.y,
-            -self.hand_rotation.z,
-            self.hand_rotation.w,
-        );
-
+        // Отражение через плоскость YZ, как у блочных тел
+        let axis = MirrorAxis::X;
         ArmPose {
-            hand_offset: Vec3::new(-self.hand_offset.x, self.hand_offset.y, self.hand_offset.z),
-            elbow_hint: Vec3::new(-self.elbow_hint.x, self.elbow_hint.y, self.elbow_hint.z),
-            hand_rotation: mirrored_rotation,
+            hand_offset: axis.mirror_vec3(self.hand_offset),
+            elbow_hint: axis.mirror_vec3(self.elbow_hint),
+            hand_rotation: axis.mirror_rotation(self.hand_rotation),
         }
     }
 
+    /// Поза как кадр клипа: кисть — смещение и ротация, локоть — только смещение
+    pub fn to_block_pose(&self) -> BlockPose {
+        BlockPose {
+            parts: vec![
+                PartPose::new(HAND_PART, self.hand_rotation).with_offset(self.hand_offset),
+                PartPose::new(ELBOW_PART, Quat::IDENTITY).with_offset(self.elbow_hint),
+            ],
+        }
+    }
+
+    /// Обратно из сэмпла клипа (None, если в позе нет частей руки)
+    pub fn from_block_pose(pose: &BlockPose) -> Option<Self> {
+        let hand = pose.get(HAND_PART)?;
+        let elbow = pose.get(ELBOW_PART)?;
+        Some(Self {
+            hand_offset: hand.offset?,
+            elbow_hint: elbow.offset?,
+            hand_rotation: hand.rotation,
+        })
+    }
+
     /// Линейная интерполяция между позами
     pub fn lerp(&self, other: &ArmPose, t: f32) -> ArmPose {
         ArmPose {
@@ -3,11 +3,12 @@
 // IK системы для рук игрока
 
 use bevy::prelude::*;
-use block_bodies_core::solve_arm_ik;
+use block_bodies_core::{AnimationClip, Easing, Keyframe, solve_arm_ik};
 
 use super::components::*;
+use crate::fighting::archetype::{WeaponArchetype, WeaponArchetypes};
 use crate::fighting::components::{
-    ArmCombatState, AttackPhase, AttackTimings, AttackType, ChargeConfig, CurrentAttackTimings,
+    ArmCombatState, AttackTimings, AttackType, ChargeConfig, CurrentAttackTimings,
     PlayerCombatState, WeaponKind,
 };
 use crate::player::component::Player;
@@ -16,31 +17,50 @@ use crate::player::component::Player;
 // IK TARGET UPDATE
 // ═══════════════════════════════════════════════════════════════════
 
+/// Длительность кросс-фейда между клипами при смене состояния руки
+const ARM_CLIP_FADE: f32 = 0.12;
+
 pub fn update_ik_target_from_combat(
+    time: Res<Time>,
     player_query: Query<&PlayerCombatState, With<Player>>,
     timings: Res<CurrentAttackTimings>,
+    archetypes: Res<WeaponArchetypes>,
     charge_config: Res<ChargeConfig>,
-    mut ik_targets: Query<&mut IkTarget>,
+    mut ik_targets: Query<(&mut IkTarget, &mut ArmAnimator)>,
 ) {
     let Ok(combat) = player_query.single() else {
         return;
     };
 
-    for mut ik_target in &mut ik_targets {
+    for (mut ik_target, mut animator) in &mut ik_targets {
         // Получаем состояние нужной руки
         let (arm_state, weapon_kind) = match ik_target.side {
             ArmSide::Right => (&combat.right, timings.right_weapon),
             ArmSide::Left => (&combat.left, timings.left_weapon),
         };
 
-        // Вычисляем позу (всегда как для правой)
-        let pose = compute_arm_pose(
-            arm_state,
-            weapon_kind,
-            &timings,
-            &charge_config,
-            ik_target.side,
-        );
+        // Новое состояние — новый клип, кросс-фейд из того, что сейчас на экране
+        let key = arm_pose_key(arm_state, weapon_kind);
+        if animator.key != Some(key) {
+            let clip = arm_clip(key, &archetypes, &timings, &charge_config, ik_target.side);
+            let fade = if animator.key.is_some() {
+                ARM_CLIP_FADE
+            } else {
+                0.0
+            };
+            animator.player.play(clip, fade);
+            animator.key = Some(key);
+        }
+        animator.player.advance(time.delta_secs());
+
+        // Клипы — для правой руки
+        let Some(pose) = animator
+            .player
+            .sample()
+            .and_then(|pose| ArmPose::from_block_pose(&pose))
+        else {
+            continue;
+        };
 
         // Зеркалим если левая рука
         let pose = match ik_target.side {
@@ -48,204 +68,101 @@ pub fn update_ik_target_from_combat(
             ArmSide::Left => pose.mirror(),
         };
 
-        // Интерполяция позиции и elbow_hint
-        ik_target.position = ik_target.position.lerp(pose.hand_offset, 0.3);
-        ik_target.elbow_hint = ik_target.elbow_hint.lerp(pose.elbow_hint, 0.3);
-
-        // Интерполяция ротации кисти (slerp для плавности)
-        ik_target.hand_rotation = ik_target.hand_rotation.slerp(pose.hand_rotation, 0.3);
+        ik_target.position = pose.hand_offset;
+        ik_target.elbow_hint = pose.elbow_hint;
+        ik_target.hand_rotation = pose.hand_rotation;
     }
 }
 
-// ═══════════════════════════════════════════════════════════════════
-// POSE COMPUTATION
-// ═══════════════════════════════════════════════════════════════════
-
-fn compute_arm_pose(
-    state: &ArmCombatState,
-    default_weapon: WeaponKind,
-    timings: &CurrentAttackTimings,
-    charge_config: &ChargeConfig,
-    side: ArmSide,
-) -> ArmPose {
+fn arm_pose_key(state: &ArmCombatState, default_weapon: WeaponKind) -> ArmPoseKey {
     match state {
-        ArmCombatState::Ready => idle_pose(default_weapon),
-
-        ArmCombatState::Charging {
-            charge_timer,
-            weapon_kind,
-        } => {
-            let weapon = *weapon_kind;
-            let t = (*charge_timer / charge_config.heavy_threshold).clamp(0.0, 1.0);
-            idle_pose(weapon).lerp(&heavy_charging_pose(weapon), ease_out_quad(t))
+        ArmCombatState::Ready | ArmCombatState::Lowering { .. } => {
+            ArmPoseKey::Ready(default_weapon)
         }
-
+        ArmCombatState::Charging { weapon_kind, .. } => ArmPoseKey::Charging(*weapon_kind),
         ArmCombatState::Attacking {
             attack_type,
-            phase,
-            phase_timer,
             weapon_kind,
             ..
-        } => {
-            let weapon = *weapon_kind;
-            let attack_timings = timings.get(side, *attack_type);
-
-            match attack_type {
-                AttackType::Light => {
-                    compute_light_pose(weapon, *phase, *phase_timer, attack_timings)
-                }
-                AttackType::Heavy => {
-                    compute_heavy_pose(weapon, *phase, *phase_timer, attack_timings)
-                }
-            }
-        }
+        } => ArmPoseKey::Attacking(*weapon_kind, *attack_type),
+        ArmCombatState::Blocking { weapon_kind, .. } => ArmPoseKey::Blocking(*weapon_kind),
     }
 }
 
 // ═══════════════════════════════════════════════════════════════════
-// POSE HELPERS BY WEAPON
+// ARM CLIPS
 // ═══════════════════════════════════════════════════════════════════
 
-fn idle_pose(weapon: WeaponKind) -> ArmPose {
-    match weapon {
-        WeaponKind::Fists => ArmPose::idle_right(),
-        WeaponKind::Sword => ArmPose::sword_idle_right(),
-    }
-}
-
-fn heavy_charging_pose(weapon: WeaponKind) -> ArmPose {
-    match weapon {
-        WeaponKind::Fists => ArmPose::fists_heavy_charging_right(),
-        WeaponKind::Sword => ArmPose::sword_heavy_charging_right(),
-    }
-}
-
-// ═══════════════════════════════════════════════════════════════════
-// LIGHT ATTACK POSES
-// ═══════════════════════════════════════════════════════════════════
-
-fn compute_light_pose(
-    weapon: WeaponKind,
-    phase: AttackPhase,
-    phase_timer: f32,
-    timings: &AttackTimings,
-) -> ArmPose {
-    match weapon {
-        WeaponKind::Fists => compute_fists_light_pose(phase, phase_timer, timings),
-        WeaponKind::Sword => compute_sword_light_pose(phase, phase_timer, timings),
-    }
-}
-
-fn compute_fists_light_pose(
-    phase: AttackPhase,
-    phase_timer: f32,
-    timings: &AttackTimings,
-) -> ArmPose {
-    match phase {
-        AttackPhase::Windup => {
-            let progress = (phase_timer / timings.windup).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::idle_right().lerp(&ArmPose::fists_windup_right(), eased)
-        }
-        AttackPhase::Active => {
-            let progress = (phase_timer / timings.active).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::fists_windup_right().lerp(&ArmPose::fists_punch_right(), eased)
-        }
-        AttackPhase::Recovery => {
-            let progress = (phase_timer / timings.recovery).clamp(0.0, 1.0);
-            let eased = ease_in_out_quad(progress);
-            ArmPose::fists_punch_right().lerp(&ArmPose::idle_right(), eased)
+/// Клип состояния руки из поз архетипа. Время клипа идёт вместе
+/// с таймерами состояния, т.к. клип стартует при входе в состояние.
+fn arm_clip(
+    key: ArmPoseKey,
+    archetypes: &WeaponArchetypes,
+    timings: &CurrentAttackTimings,
+    charge_config: &ChargeConfig,
+    side: ArmSide,
+) -> AnimationClip {
+    match key {
+        ArmPoseKey::Ready(weapon_kind) => still_clip(&archetypes.get(weapon_kind).poses.idle),
+
+        ArmPoseKey::Charging(weapon_kind) => {
+            let poses = &archetypes.get(weapon_kind).poses;
+            AnimationClip::new(
+                vec![
+                    arm_keyframe(0.0, &poses.idle),
+                    arm_keyframe(charge_config.heavy_threshold, &poses.charging)
+                        .with_easing(Easing::EaseOut),
+                ],
+                false,
+            )
         }
-    }
-}
 
-fn compute_sword_light_pose(
-    phase: AttackPhase,
-    phase_timer: f32,
-    timings: &AttackTimings,
-) -> ArmPose {
-    match phase {
-        AttackPhase::Windup => {
-            let progress = (phase_timer / timings.windup).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::sword_idle_right().lerp(&ArmPose::sword_windup_right(), eased)
-        }
-        AttackPhase::Active => {
-            let progress = (phase_timer / timings.active).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::sword_windup_right().lerp(&ArmPose::sword_slash_right(), eased)
-        }
-        AttackPhase::Recovery => {
-            let progress = (phase_timer / timings.recovery).clamp(0.0, 1.0);
-            let eased = ease_in_out_quad(progress);
-            ArmPose::sword_slash_right().lerp(&ArmPose::sword_idle_right(), eased)
+        ArmPoseKey::Attacking(weapon_kind, attack_type) => attack_clip(
+            archetypes.get(weapon_kind),
+            attack_type,
+            timings.get(side, attack_type),
+        ),
+
+        // Подъём щита сглаживает кросс-фейд
+        ArmPoseKey::Blocking(weapon_kind) => {
+            let archetype = archetypes.get(weapon_kind);
+            still_clip(
+                &archetype
+                    .block
+                    .map_or(archetype.poses.idle, |block| block.pose),
+            )
         }
     }
 }
 
-// ═══════════════════════════════════════════════════════════════════
-// HEAVY ATTACK POSES
-// ═══════════════════════════════════════════════════════════════════
-
-fn compute_heavy_pose(
-    weapon: WeaponKind,
-    phase: AttackPhase,
-    phase_timer: f32,
+/// Windup: старт → замах, Active: замах → удар, Recovery: удар → idle
+fn attack_clip(
+    archetype: &WeaponArchetype,
+    attack_type: AttackType,
     timings: &AttackTimings,
-) -> ArmPose {
-    match weapon {
-        WeaponKind::Fists => compute_fists_heavy_pose(phase, phase_timer, timings),
-        WeaponKind::Sword => compute_sword_heavy_pose(phase, phase_timer, timings),
-    }
+) -> AnimationClip {
+    let poses = archetype.attack_poses(attack_type);
+    let strike_time = timings.windup + timings.active;
+
+    AnimationClip::new(
+        vec![
+            arm_keyframe(0.0, archetype.attack_start(attack_type)),
+            arm_keyframe(timings.windup, &poses.windup).with_easing(Easing::EaseOut),
+            arm_keyframe(strike_time, &poses.strike).with_easing(Easing::EaseOut),
+            arm_keyframe(strike_time + timings.recovery, &archetype.poses.idle)
+                .with_easing(Easing::EaseInOut),
+        ],
+        false,
+    )
 }
 
-fn compute_fists_heavy_pose(
-    phase: AttackPhase,
-    phase_timer: f32,
-    timings: &AttackTimings,
-) -> ArmPose {
-    match phase {
-        AttackPhase::Windup => {
-            let progress = (phase_timer / timings.windup).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::fists_heavy_charging_right().lerp(&ArmPose::fists_heavy_windup_right(), eased)
-        }
-        AttackPhase::Active => {
-            let progress = (phase_timer / timings.active).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::fists_heavy_windup_right().lerp(&ArmPose::fists_heavy_active_right(), eased)
-        }
-        AttackPhase::Recovery => {
-            let progress = (phase_timer / timings.recovery).clamp(0.0, 1.0);
-            let eased = ease_in_out_quad(progress);
-            ArmPose::fists_heavy_active_right().lerp(&ArmPose::idle_right(), eased)
-        }
-    }
+/// Одна поза без движения
+fn still_clip(pose: &ArmPose) -> AnimationClip {
+    AnimationClip::new(vec![arm_keyframe(0.0, pose)], false)
 }
 
-fn compute_sword_heavy_pose(
-    phase: AttackPhase,
-    phase_timer: f32,
-    timings: &AttackTimings,
-) -> ArmPose {
-    match phase {
-        AttackPhase::Windup => {
-            let progress = (phase_timer / timings.windup).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::sword_heavy_charging_right().lerp(&ArmPose::sword_heavy_windup_right(), eased)
-        }
-        AttackPhase::Active => {
-            let progress = (phase_timer / timings.active).clamp(0.0, 1.0);
-            let eased = ease_out_quad(progress);
-            ArmPose::sword_heavy_windup_right().lerp(&ArmPose::sword_heavy_slash_right(), eased)
-        }
-        AttackPhase::Recovery => {
-            let progress = (phase_timer / timings.recovery).clamp(0.0, 1.0);
-            let eased = ease_in_out_quad(progress);
-            ArmPose::sword_heavy_slash_right().lerp(&ArmPose::sword_idle_right(), eased)
-        }
-    }
+fn arm_keyframe(time: f32, pose: &ArmPose) -> Keyframe {
+    Keyframe::new(time, pose.to_block_pose())
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -328,19 +245,3 @@ pub fn apply_arm_ik(
         }
     }
 }
-
-// ═══════════════════════════════════════════════════════════════════
-// EASING
-// ═══════════════════════════════════════════════════════════════════
-
-fn ease_out_quad(t: f32) -> f32 {
-    1.0 - (1.0 - t) * (1.0 - t)
-}
-
-fn ease_in_out_quad(t: f32) -> f32 {
-    if t < 0.5 {
-        2.0 * t * t
-    } else {
-        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
-    }
-}
@@ -18,5 +18,5 @@ pub use pose_debug::{
 pub use spawn::spawn_player_arms;
 pub use weapon_visual::{
     EquippedWeaponVisual, WeaponDebugState, apply_weapon_debug_transform,
-    sync_equipped_weapon_visual, weapon_debug_input,
+    sync_equipped_weapon_visual, weapon_debug_input, weapon_grip_transform,
 };
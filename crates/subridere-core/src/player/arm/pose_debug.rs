@@ -80,20 +80,21 @@ impl DebugPose {
         }
     }
 
-    pub fn fn_name(self) -> &'static str {
+    /// Где поза лежит в assets/weapons/default.weapons.ron
+    pub fn ron_path(self) -> &'static str {
         match self {
-            Self::SwordIdle => "sword_idle_right",
-            Self::SwordWindup => "sword_windup_right",
-            Self::SwordSlash => "sword_slash_right",
-            Self::SwordHeavyCharging => "sword_heavy_charging_right",
-            Self::SwordHeavyWindup => "sword_heavy_windup_right",
-            Self::SwordHeavySlash => "sword_heavy_slash_right",
-            Self::FistsIdle => "idle_right",
-            Self::FistsWindup => "fists_windup_right",
-            Self::FistsPunch => "fists_punch_right",
-            Self::FistsHeavyCharging => "fists_heavy_charging_right",
-            Self::FistsHeavyWindup => "fists_heavy_windup_right",
-            Self::FistsHeavyActive => "fists_heavy_active_right",
+            Self::SwordIdle => "Sword.poses.idle",
+            Self::SwordWindup => "Sword.poses.light.windup",
+            Self::SwordSlash => "Sword.poses.light.strike",
+            Self::SwordHeavyCharging => "Sword.poses.charging",
+            Self::SwordHeavyWindup => "Sword.poses.heavy.windup",
+            Self::SwordHeavySlash => "Sword.poses.heavy.strike",
+            Self::FistsIdle => "Fists.poses.idle",
+            Self::FistsWindup => "Fists.poses.light.windup",
+            Self::FistsPunch => "Fists.poses.light.strike",
+            Self::FistsHeavyCharging => "Fists.poses.charging",
+            Self::FistsHeavyWindup => "Fists.poses.heavy.windup",
+            Self::FistsHeavyActive => "Fists.poses.heavy.strike",
         }
     }
 }
@@ -205,7 +206,7 @@ pub struct PoseDebugData {
 }
 
 impl PoseDebugData {
-    /// Загружаем текущие значения из ArmPose
+    /// Начальные значения — как в assets/weapons/default.weapons.ron
     pub fn from_defaults() -> Self {
         Self {
             // Sword
@@ -476,31 +477,30 @@ fn print_current_state(pose_debug: &PoseDebugState) {
     );
 }
 
-fn print_all_poses(poses: &PoseDebugData) {
+pub fn print_all_poses(poses: &PoseDebugData) {
     info!("");
     info!("════════════════════════════════════════════════════════════════");
-    info!("🎨 ALL POSES (copy to components.rs):");
+    info!("🎨 ALL POSES (copy to assets/weapons/default.weapons.ron):");
     info!("════════════════════════════════════════════════════════════════");
 
     for &pose_type in DebugPose::ALL {
         let pose = poses.get(pose_type);
         info!("");
-        info!("pub fn {}() -> Self {{", pose_type.fn_name());
-        info!("    Self {{");
+        info!("// {}", pose_type.ron_path());
+        info!("(");
         info!(
-            "        hand_offset: Vec3::new({:.2}, {:.2}, {:.2}),",
+            "    hand_offset: ({:.2}, {:.2}, {:.2}),",
             pose.hand_offset.x, pose.hand_offset.y, pose.hand_offset.z
         );
         info!(
-            "        elbow_hint: Vec3::new({:.2}, {:.2}, {:.2}),",
+            "    elbow_hint: ({:.2}, {:.2}, {:.2}),",
             pose.elbow_hint.x, pose.elbow_hint.y, pose.elbow_hint.z
         );
         info!(
-            "        hand_rotation: Self::rot({:.1}, {:.1}, {:.1}),",
+            "    hand_rotation: ({:.1}, {:.1}, {:.1}),",
             pose.rotation_degrees.x, pose.rotation_degrees.y, pose.rotation_degrees.z
         );
-        info!("    }}");
-        info!("}}");
+        info!("),");
     }
 
     info!("");
@@ -137,6 +137,7 @@ pub fn spawn_player_arm(
     let ik_target_entity = commands
         .spawn((
             ik_target,
+            ArmAnimator::default(),
             Transform::default(),
             GlobalTransform::default(),
             Visibility::Hidden,
@@ -313,7 +313,7 @@ fn create_mesh_for_shape(
 
 /// Трансформ для правильного хвата оружия
 /// Найдено через дебаг (F7/F9)
-fn weapon_grip_transform(_side: ArmSide) -> Transform {
+pub fn weapon_grip_transform(_side: ArmSide) -> Transform {
     let rotation = Quat::from_euler(
         EulerRot::XYZ,
         (-150.0_f32).to_radians(),
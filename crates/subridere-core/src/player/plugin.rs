@@ -3,8 +3,12 @@ use bevy::prelude::*;
 use crate::{
     player::component::{PLAYER_START_POS, Player},
     unit::component::Velocity,
+    world::config::DungeonConfig,
 };
 
+/// Насколько ниже самого нижнего этажа игрок считается упавшим из мира
+const KILL_DEPTH: f32 = 50.0;
+
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
@@ -13,9 +17,13 @@ impl Plugin for PlayerPlugin {
     }
 }
 
-fn kill_plane_system(mut query: Query<(&mut Transform, &mut Velocity), With<Player>>) {
+fn kill_plane_system(
+    config: Res<DungeonConfig>,
+    mut query: Query<(&mut Transform, &mut Velocity), With<Player>>,
+) {
+    let lowest_floor = -(config.floors.max(1) - 1) as f32 * config.room_size().y;
     for (mut transform, mut velocity) in &mut query {
-        if transform.translation.y < -50.0 {
+        if transform.translation.y < lowest_floor - KILL_DEPTH {
             println!("💀 Player died. Respawning...");
             transform.translation = PLAYER_START_POS;
             velocity.0 = Vec3::ZERO;
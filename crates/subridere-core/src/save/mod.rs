@@ -0,0 +1,9 @@
+// save/mod.rs — Run persistence (RON snapshots)
+
+pub mod plugin;
+pub mod snapshot;
+pub mod systems;
+
+pub use plugin::SavePlugin;
+pub use snapshot::{RunSnapshot, SAVE_PATH, SAVE_VERSION, SaveError};
+pub use systems::{LoadGameEvent, PendingLoad, SaveGameEvent, has_pending_load, no_pending_load};
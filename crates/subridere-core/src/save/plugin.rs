@@ -0,0 +1,34 @@
+// save/plugin.rs — Save/load plugin
+
+use bevy::prelude::*;
+
+use crate::app::AppState;
+
+use super::systems::{
+    LoadGameEvent, PendingLoad, SaveGameEvent, discard_pending_load, finish_loading,
+    handle_load_request, save_game,
+};
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Events
+            .add_event::<SaveGameEvent>()
+            .add_event::<LoadGameEvent>()
+            // Resources
+            .init_resource::<PendingLoad>()
+            // Systems
+            .add_systems(Update, save_game.run_if(in_state(AppState::InGame)))
+            // Load works from main menu too ("Continue")
+            .add_systems(Update, handle_load_request)
+            .add_systems(OnEnter(AppState::Loading), finish_loading)
+            // New Game / Respawn начинают забег без снапшота
+            .add_systems(OnEnter(AppState::MainMenu), discard_pending_load)
+            .add_systems(OnExit(AppState::Dead), discard_pending_load)
+            .add_systems(OnExit(AppState::Victory), discard_pending_load);
+
+        info!("✅ Save plugin initialized");
+    }
+}
@@ -0,0 +1,216 @@
+// save/snapshot.rs — Versioned snapshot of a run
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::inventory::{Equipment, Inventory};
+use crate::items::ItemId;
+use crate::stats::{Attributes, Experience, Level};
+use crate::world::room::types::{DoorFlags, RoomKind, RoomMetadata, VerticalFlags, WallFlags};
+
+/// Default save file location (relative to working directory)
+pub const SAVE_PATH: &str = "saves/run.ron";
+
+/// Current snapshot format version. Bump when the layout changes
+/// and add a migration step in `RunSnapshot::from_ron`.
+pub const SAVE_VERSION: u32 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SaveError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to serialize snapshot: {0}")]
+    Serialize(#[from] ron::Error),
+
+    #[error("Failed to parse snapshot: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+
+    #[error("Unsupported save version {found} (expected {})", SAVE_VERSION)]
+    UnsupportedVersion { found: u32 },
+}
+
+/// Full run state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSnapshot {
+    pub version: u32,
+    /// WorldSeed of the run: enemies are regenerated from it on load.
+    /// Missing in saves made before seeds were stored
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub player: PlayerSnapshot,
+    pub rooms: Vec<RoomSnapshot>,
+    pub world_items: Vec<WorldItemSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub position: [f32; 3],
+    pub rotation: [f32; 4],
+    pub level: Level,
+    pub experience: Experience,
+    pub attributes: Attributes,
+    pub health: ResourceSnapshot,
+    pub mana: ResourceSnapshot,
+    pub stamina: ResourceSnapshot,
+    pub inventory: Inventory,
+    pub equipment: Equipment,
+}
+
+/// Current/max pair for Health, Mana and Stamina.
+/// Max is stored so that stat recalculation keeps the saved fill ratio.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ResourceSnapshot {
+    pub current: f32,
+    pub max: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomSnapshot {
+    pub position: [i32; 3],
+    /// Missing in saves made before room kinds
+    #[serde(default)]
+    pub kind: RoomKind,
+    pub wall_flags: WallFlags,
+    pub door_flags: DoorFlags,
+    /// Missing in saves made before multi-floor dungeons
+    #[serde(default)]
+    pub vertical: VerticalFlags,
+    pub has_light: bool,
+}
+
+impl RoomSnapshot {
+    pub fn from_room(pos: IVec3, meta: &RoomMetadata) -> Self {
+        Self {
+            position: pos.to_array(),
+            kind: meta.kind,
+            wall_flags: meta.wall_flags.clone(),
+            door_flags: meta.door_flags.clone(),
+            vertical: meta.vertical.clone(),
+            has_light: meta.has_light,
+        }
+    }
+
+    pub fn to_room(&self) -> (IVec3, RoomMetadata) {
+        (
+            IVec3::from_array(self.position),
+            RoomMetadata {
+                kind: self.kind,
+                wall_flags: self.wall_flags.clone(),
+                door_flags: self.door_flags.clone(),
+                vertical: self.vertical.clone(),
+                has_light: self.has_light,
+                entity: None,
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldItemSnapshot {
+    pub id: ItemId,
+    pub quantity: u32,
+    pub position: [f32; 3],
+}
+
+/// Only the version field, parsed first to pick a migration path
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    version: u32,
+}
+
+impl RunSnapshot {
+    pub fn to_ron(&self) -> Result<String, SaveError> {
+        let pretty = ron::ser::PrettyConfig::new().depth_limit(4);
+        Ok(ron::ser::to_string_pretty(self, pretty)?)
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, SaveError> {
+        let header: SnapshotHeader = ron::from_str(text)?;
+
+        match header.version {
+            SAVE_VERSION => Ok(ron::from_str(text)?),
+            // Older versions: migrate here, e.g. `1 => migrate_v1(ron::from_str(text)?)`
+            found => Err(SaveError::UnsupportedVersion { found }),
+        }
+    }
+
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), SaveError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_ron()?)?;
+        Ok(())
+    }
+
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, SaveError> {
+        let text = std::fs::read_to_string(path)?;
+        Self::from_ron(&text)
+    }
+
+    /// Is there a save file to continue from?
+    pub fn exists(path: impl AsRef<Path>) -> bool {
+        path.as_ref().is_file()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_snapshot(version: u32) -> RunSnapshot {
+        RunSnapshot {
+            version,
+            seed: Some(1234),
+            player: PlayerSnapshot {
+                position: [1.0, 2.0, 3.0],
+                rotation: [0.0, 0.0, 0.0, 1.0],
+                level: Level::new(3),
+                experience: Experience::default(),
+                attributes: Attributes::default(),
+                health: ResourceSnapshot {
+                    current: 40.0,
+                    max: 65.0,
+                },
+                mana: ResourceSnapshot {
+                    current: 10.0,
+                    max: 20.0,
+                },
+                stamina: ResourceSnapshot {
+                    current: 59.0,
+                    max: 59.0,
+                },
+                inventory: Inventory::default(),
+                equipment: Equipment::default(),
+            },
+            rooms: vec![RoomSnapshot::from_room(
+                IVec3::new(1, 0, 2),
+                &RoomMetadata::default(),
+            )],
+            world_items: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn snapshot_roundtrip() {
+        let text = empty_snapshot(SAVE_VERSION).to_ron().unwrap();
+        let loaded = RunSnapshot::from_ron(&text).unwrap();
+
+        assert_eq!(loaded.seed, Some(1234));
+        assert_eq!(loaded.player.level.current, 3);
+        assert_eq!(loaded.player.health.current, 40.0);
+        assert_eq!(loaded.rooms[0].to_room().0, IVec3::new(1, 0, 2));
+    }
+
+    #[test]
+    fn snapshot_rejects_unknown_version() {
+        let text = empty_snapshot(SAVE_VERSION + 1).to_ron().unwrap();
+
+        assert!(matches!(
+            RunSnapshot::from_ron(&text),
+            Err(SaveError::UnsupportedVersion { .. })
+        ));
+    }
+}
@@ -0,0 +1,289 @@
+// save/systems.rs — Capturing and restoring run snapshots
+
+use bevy::prelude::*;
+
+use crate::app::AppState;
+use crate::game_init::maze_rooms::spawn_rooms_from_map;
+use crate::game_init::state::InitStage;
+use crate::inventory::{Equipment, Inventory};
+use crate::items::{ItemRegistry, WorldItem, spawn_world_item};
+use crate::player::component::Player;
+use crate::stats::{Attributes, Experience, Health, Level, Mana, Stamina};
+use crate::ui::system_menu::SystemMenuState;
+use crate::world::config::DungeonConfig;
+use crate::world::room::template::RoomTemplates;
+use crate::world::room::types::RoomMap;
+use crate::world::seed::WorldSeed;
+
+use super::snapshot::{
+    PlayerSnapshot, ResourceSnapshot, RoomSnapshot, RunSnapshot, SAVE_PATH, SAVE_VERSION,
+    WorldItemSnapshot,
+};
+
+/// Request: write the current run to disk
+#[derive(Event)]
+pub struct SaveGameEvent;
+
+/// Request: load the run from disk and restart the game with it
+#[derive(Event)]
+pub struct LoadGameEvent;
+
+/// Snapshot waiting to be restored by the InitStage pipeline.
+/// While set, init stages restore from it instead of generating.
+#[derive(Resource, Default)]
+pub struct PendingLoad(pub Option<RunSnapshot>);
+
+pub fn has_pending_load(pending: Res<PendingLoad>) -> bool {
+    pending.0.is_some()
+}
+
+pub fn no_pending_load(pending: Res<PendingLoad>) -> bool {
+    pending.0.is_none()
+}
+
+// ============================================================
+// Save
+// ============================================================
+
+type PlayerSaveData<'a> = (
+    &'a Transform,
+    &'a Level,
+    &'a Experience,
+    &'a Attributes,
+    &'a Health,
+    &'a Mana,
+    &'a Stamina,
+    &'a Inventory,
+    &'a Equipment,
+);
+
+pub fn save_game(
+    mut events: EventReader<SaveGameEvent>,
+    player_query: Query<PlayerSaveData, With<Player>>,
+    room_map: Res<RoomMap>,
+    seed: Res<WorldSeed>,
+    world_items: Query<(&WorldItem, &GlobalTransform)>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    let Ok((transform, level, experience, attributes, health, mana, stamina, inventory, equipment)) =
+        player_query.single()
+    else {
+        warn!("💾 Cannot save: no player");
+        return;
+    };
+
+    let snapshot = RunSnapshot {
+        version: SAVE_VERSION,
+        seed: Some(seed.0),
+        player: PlayerSnapshot {
+            position: transform.translation.to_array(),
+            rotation: transform.rotation.to_array(),
+            level: level.clone(),
+            experience: experience.clone(),
+            attributes: *attributes,
+            health: ResourceSnapshot {
+                current: health.current,
+                max: health.max,
+            },
+            mana: ResourceSnapshot {
+                current: mana.current,
+                max: mana.max,
+            },
+            stamina: ResourceSnapshot {
+                current: stamina.current,
+                max: stamina.max,
+            },
+            inventory: inventory.clone(),
+            equipment: equipment.clone(),
+        },
+        rooms: room_map
+            .rooms
+            .iter()
+            .map(|(&pos, meta)| RoomSnapshot::from_room(pos, meta))
+            .collect(),
+        world_items: world_items
+            .iter()
+            .map(|(item, transform)| WorldItemSnapshot {
+                id: item.id,
+                quantity: item.quantity,
+                position: transform.translation().to_array(),
+            })
+            .collect(),
+    };
+
+    match snapshot.save_to_file(SAVE_PATH) {
+        Ok(()) => info!(
+            "💾 Game saved to {} ({} rooms, {} world items)",
+            SAVE_PATH,
+            snapshot.rooms.len(),
+            snapshot.world_items.len()
+        ),
+        Err(e) => error!("💾 Failed to save game: {}", e),
+    }
+}
+
+// ============================================================
+// Load
+// ============================================================
+
+/// Read the save file and (re)start the game from it
+pub fn handle_load_request(
+    mut events: EventReader<LoadGameEvent>,
+    mut pending: ResMut<PendingLoad>,
+    mut next_app: ResMut<NextState<AppState>>,
+    mut next_system_menu: ResMut<NextState<SystemMenuState>>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    match RunSnapshot::load_from_file(SAVE_PATH) {
+        Ok(snapshot) => {
+            info!("📂 Loaded save (version {})", snapshot.version);
+            pending.0 = Some(snapshot);
+            next_system_menu.set(SystemMenuState::Closed);
+            // InGame → InGame не вызывает OnExit/OnEnter, поэтому через Loading
+            next_app.set(AppState::Loading);
+        }
+        Err(e) => {
+            error!("📂 Failed to load save: {}", e);
+            pending.0 = None;
+        }
+    }
+}
+
+/// AppState::Loading — старый забег уже убран, запускаем init со снапшотом
+pub fn finish_loading(mut next_app: ResMut<NextState<AppState>>) {
+    next_app.set(AppState::InGame);
+}
+
+/// Новый забег не должен подхватить снапшот, оставшийся от прерванной загрузки
+pub fn discard_pending_load(mut pending: ResMut<PendingLoad>) {
+    if pending.0.take().is_some() {
+        info!("📂 Discarded unused save snapshot");
+    }
+}
+
+/// InitStage::MazeReady — rebuild the maze from the snapshot instead of generating it
+pub fn restore_rooms(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut room_map: ResMut<RoomMap>,
+    mut seed: ResMut<WorldSeed>,
+    pending: Res<PendingLoad>,
+    config: Res<DungeonConfig>,
+    templates: Res<RoomTemplates>,
+) {
+    let Some(snapshot) = &pending.0 else {
+        return;
+    };
+
+    // Враги генерируются из сида — без него загрузка даст другой забег
+    if let Some(saved_seed) = snapshot.seed {
+        *seed = WorldSeed(saved_seed);
+        info!("📂 World seed restored: {}", saved_seed);
+    }
+
+    room_map.rooms = snapshot.rooms.iter().map(RoomSnapshot::to_room).collect();
+    spawn_rooms_from_map(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut room_map,
+        &config,
+        &templates,
+    );
+
+    info!("📂 Maze restored: {} rooms", room_map.rooms.len());
+}
+
+/// InitStage::EnemiesReady — player is spawned, overwrite its state
+pub fn restore_player(
+    pending: Res<PendingLoad>,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut Level,
+            &mut Experience,
+            &mut Attributes,
+            &mut Health,
+            &mut Mana,
+            &mut Stamina,
+            &mut Inventory,
+            &mut Equipment,
+        ),
+        With<Player>,
+    >,
+) {
+    let Some(snapshot) = &pending.0 else {
+        return;
+    };
+    let Ok((
+        mut transform,
+        mut level,
+        mut experience,
+        mut attributes,
+        mut health,
+        mut mana,
+        mut stamina,
+        mut inventory,
+        mut equipment,
+    )) = player_query.single_mut()
+    else {
+        warn!("📂 Cannot restore player: not spawned");
+        return;
+    };
+
+    let saved = &snapshot.player;
+    transform.translation = Vec3::from_array(saved.position);
+    transform.rotation = Quat::from_array(saved.rotation);
+    *level = saved.level.clone();
+    *experience = saved.experience.clone();
+    *attributes = saved.attributes;
+    // max тоже восстанавливаем: пересчёт статов сохраняет процент заполнения
+    health.current = saved.health.current;
+    health.max = saved.health.max;
+    mana.current = saved.mana.current;
+    mana.max = saved.mana.max;
+    stamina.current = saved.stamina.current;
+    stamina.max = saved.stamina.max;
+    *inventory = saved.inventory.clone();
+    *equipment = saved.equipment.clone();
+
+    info!("📂 Player restored (level {})", level.current);
+}
+
+/// InitStage::ItemsReady — place saved world items instead of random loot
+pub fn restore_world_items(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut next_state: ResMut<NextState<InitStage>>,
+    mut pending: ResMut<PendingLoad>,
+    registry: Res<ItemRegistry>,
+) {
+    // Снапшот больше не нужен — следующая игра генерируется заново
+    let Some(snapshot) = pending.0.take() else {
+        return;
+    };
+
+    for item in &snapshot.world_items {
+        spawn_world_item(
+            &mut commands,
+            &registry,
+            item.id,
+            item.quantity,
+            Vec3::from_array(item.position),
+            None,
+            &mut meshes,
+            &mut materials,
+        );
+    }
+
+    info!("📂 Restored {} world items", snapshot.world_items.len());
+    next_state.set(InitStage::Done);
+}
@@ -1,8 +1,9 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Базовые атрибуты персонажа — игрок распределяет очки сюда.
 /// Диапазон каждого: 0-30
-#[derive(Component, Clone, Copy, Debug)]
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Attributes {
     pub might: u8,     // сила: ближний урон, оглушение, грузоподъёмность
     pub fortitude: u8, // стойкость: HP, физ.защита, сопротивление отбросу
@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 
 /// Компонент урона - добавляется на сущность чтобы нанести урон
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Clone, Copy)]
 pub struct Damage {
     pub amount: f32,
     pub damage_type: DamageType,
+    /// Источник урона (атакующая сущность)
+    pub source: Option<Entity>,
 }
 
 impl Damage {
@@ -12,6 +14,7 @@ impl Damage {
         Self {
             amount,
             damage_type: DamageType::Physical,
+            source: None,
         }
     }
 
@@ -19,8 +22,24 @@ impl Damage {
         Self {
             amount,
             damage_type: DamageType::Magical,
+            source: None,
         }
     }
+
+    /// Указать атакующего
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Нанести урон: если в этом кадре цель уже получила Damage, суммы складываются
+    /// (тип и атакующий остаются от первого удара)
+    pub fn add_to(self, target: &mut EntityCommands) {
+        target
+            .entry::<Damage>()
+            .and_modify(move |mut damage| damage.amount += self.amount)
+            .or_insert(self);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,6 +48,34 @@ pub enum DamageType {
     Magical,
 }
 
+/// Неуязвимость (i-frames), например во время рывка
+#[derive(Component, Debug)]
+pub struct Invulnerable {
+    pub timer: Timer,
+}
+
+impl Invulnerable {
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+/// Оглушение: атакующего парировали, он ненадолго выключен из боя
+#[derive(Component, Debug)]
+pub struct Staggered {
+    pub timer: Timer,
+}
+
+impl Staggered {
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
 /// Маркер что сущность уже нанесла урон в этом кадре
 #[derive(Component, Debug)]
 pub struct HasDealtDamage;
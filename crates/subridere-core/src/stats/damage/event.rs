@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+/// Удар принят на блок; `parried` — в окне парирования, урона нет
+#[derive(Event, Debug)]
+pub struct DamageBlockedEvent {
+    pub entity: Entity,
+    pub parried: bool,
+}
+
+/// Урон не прошёл — цель была неуязвима (увернулась)
+#[derive(Event, Debug)]
+pub struct DamageDodgedEvent {
+    pub entity: Entity,
+}
@@ -1,5 +1,7 @@
 pub mod component;
+pub mod event;
 pub mod system;
 
-pub use component::{Damage, DamageType, HasDealtDamage};
-pub use system::apply_damage;
+pub use component::{Damage, DamageType, HasDealtDamage, Invulnerable, Staggered};
+pub use event::{DamageBlockedEvent, DamageDodgedEvent};
+pub use system::{apply_damage, tick_invulnerability, tick_stagger};
@@ -1,27 +1,140 @@
-use super::component::{Damage, DamageType};
+use super::component::{Damage, DamageType, Invulnerable, Staggered};
+use super::event::{DamageBlockedEvent, DamageDodgedEvent};
 use crate::{
     audio::player::events::PlayerDamageEvent,
+    camera::flycam::FlyCamera,
+    fighting::{
+        PlayerCombatState, WeaponArchetypes,
+        block::{BlockOutcome, active_block, break_block, resolve_block},
+    },
     player::component::Player,
-    stats::{computed::ComputedStats, health::Health},
+    stats::{Stamina, computed::ComputedStats, health::Health},
     ui::hud::hitflash::HitFlashEvent,
 };
 use bevy::prelude::*;
 
-/// Применить урон с учётом защиты
+/// Применить урон с учётом блока и защиты
 pub fn apply_damage(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Health, &Damage, Option<&ComputedStats>)>,
+    mut query: Query<(
+        Entity,
+        &mut Health,
+        &Damage,
+        Option<&ComputedStats>,
+        Has<Invulnerable>,
+        Option<&mut PlayerCombatState>,
+        Option<&mut Stamina>,
+    )>,
     players: Query<&Transform, With<Player>>,
+    transforms: Query<&GlobalTransform>,
+    children: Query<&Children>,
+    cameras: Query<&GlobalTransform, With<FlyCamera>>,
+    archetypes: Res<WeaponArchetypes>,
     mut ev_flash: EventWriter<HitFlashEvent>,
     mut ev_audio: EventWriter<PlayerDamageEvent>,
+    mut ev_dodged: EventWriter<DamageDodgedEvent>,
+    mut ev_blocked: EventWriter<DamageBlockedEvent>,
 ) {
-    for (entity, mut health, damage, computed) in &mut query {
+    for (entity, mut health, damage, computed, invulnerable, mut combat, mut stamina) in &mut query
+    {
+        // Неуязвимость (i-frames) — урон сгорает
+        if invulnerable {
+            ev_dodged.write(DamageDodgedEvent { entity });
+            commands.entity(entity).remove::<Damage>();
+            continue;
+        }
+
+        // Блок: только физический урон от известного атакующего
+        let outcome = match (combat.as_deref(), damage.source, damage.damage_type) {
+            (Some(combat), Some(source), DamageType::Physical) => {
+                // Взгляд собственной камеры блокирующего (она — его дочерняя сущность)
+                let facing = children
+                    .get(entity)
+                    .ok()
+                    .and_then(|children| children.iter().find_map(|child| cameras.get(child).ok()))
+                    .map(|camera| *camera.forward());
+                let positions = transforms.get(entity).ok().zip(transforms.get(source).ok());
+
+                match (active_block(combat, &archetypes), facing, positions) {
+                    (Some((block, block_timer)), Some(facing), Some((target, attacker))) => {
+                        resolve_block(
+                            &block,
+                            block_timer,
+                            facing,
+                            attacker.translation() - target.translation(),
+                            damage.amount,
+                        )
+                    }
+                    _ => BlockOutcome::Unblocked,
+                }
+            }
+            _ => BlockOutcome::Unblocked,
+        };
+
+        let amount = match outcome {
+            BlockOutcome::Unblocked => damage.amount,
+
+            BlockOutcome::Parried { stagger_duration } => {
+                info!(
+                    "🛡️ Parried! Attacker staggered for {:.1}s",
+                    stagger_duration
+                );
+                let attacker = damage
+                    .source
+                    .and_then(|source| commands.get_entity(source).ok());
+                if let Some(mut attacker) = attacker {
+                    attacker.try_insert(Staggered::new(stagger_duration));
+                }
+                ev_blocked.write(DamageBlockedEvent {
+                    entity,
+                    parried: true,
+                });
+                commands.entity(entity).remove::<Damage>();
+                continue;
+            }
+
+            BlockOutcome::Blocked {
+                damage: rest,
+                stamina_cost,
+            } => match stamina.as_deref_mut().map(|s| s.spend(stamina_cost)) {
+                Some(true) => {
+                    info!(
+                        "🛡️ Blocked {:.1} of {:.1} damage (-{:.1} stamina)",
+                        damage.amount - rest,
+                        damage.amount,
+                        stamina_cost
+                    );
+                    ev_blocked.write(DamageBlockedEvent {
+                        entity,
+                        parried: false,
+                    });
+                    rest
+                }
+                // Не хватило стамины — блок пробит, удар проходит целиком
+                _ => {
+                    info!("💢 Guard broken!");
+                    if let Some(stamina) = stamina.as_deref_mut() {
+                        stamina.spend(stamina.current);
+                    }
+                    if let Some(combat) = combat.as_deref_mut() {
+                        break_block(combat);
+                    }
+                    damage.amount
+                }
+            },
+        };
+
         // Рассчитываем итоговый урон с учётом защиты
-        let final_damage = calculate_damage(damage, computed);
+        let final_damage = calculate_damage(amount, damage.damage_type, computed);
 
         // Наносим урон
         health.damage(final_damage);
 
+        // Запоминаем атакующего для начисления опыта
+        if damage.source.is_some() {
+            health.last_attacker = damage.source;
+        }
+
         // Эффекты для игрока
         if players.get(entity).is_ok() {
             ev_flash.write(HitFlashEvent);
@@ -33,18 +146,132 @@ pub fn apply_damage(
     }
 }
 
+/// Отсчёт оглушения
+pub fn tick_stagger(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Staggered)>,
+) {
+    for (entity, mut staggered) in &mut query {
+        staggered.timer.tick(time.delta());
+
+        if staggered.timer.finished() {
+            commands.entity(entity).remove::<Staggered>();
+        }
+    }
+}
+
+/// Отсчёт неуязвимости
+pub fn tick_invulnerability(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Invulnerable)>,
+) {
+    for (entity, mut invulnerable) in &mut query {
+        invulnerable.timer.tick(time.delta());
+
+        if invulnerable.timer.finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+}
+
 /// Рассчитать итоговый урон с учётом защиты
-fn calculate_damage(damage: &Damage, computed: Option<&ComputedStats>) -> f32 {
+fn calculate_damage(amount: f32, damage_type: DamageType, computed: Option<&ComputedStats>) -> f32 {
     let Some(stats) = computed else {
         // Нет статов - полный урон
-        return damage.amount.max(0.0);
+        return amount.max(0.0);
     };
 
-    let reduction = match damage.damage_type {
+    let reduction = match damage_type {
         DamageType::Physical => stats.physical_defense,
         DamageType::Magical => stats.magic_resist,
     };
 
     // Плоское снижение, минимум 0
-    (damage.amount - reduction).max(0.0)
+    (amount - reduction).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fighting::archetype::{BlockConfig, WeaponArchetype};
+    use crate::fighting::components::{ArmCombatState, WeaponKind};
+    use crate::player::arm::ArmPose;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// Игрок держит щит, смотрит в -Z; атакующий стоит перед ним
+    fn blocking_player(stamina: f32) -> (World, Entity) {
+        let mut world = World::new();
+
+        let mut archetypes = WeaponArchetypes::default();
+        archetypes.archetypes.insert(
+            WeaponKind::Shield,
+            WeaponArchetype {
+                block: Some(BlockConfig {
+                    pose: ArmPose::idle_right(),
+                    damage_reduction: 0.75,
+                    stamina_per_damage: 2.0,
+                    angle: 60.0,
+                    parry_window: 0.2,
+                    stagger_duration: 1.0,
+                }),
+                ..default()
+            },
+        );
+        world.insert_resource(archetypes);
+        world.init_resource::<Events<HitFlashEvent>>();
+        world.init_resource::<Events<PlayerDamageEvent>>();
+        world.init_resource::<Events<DamageDodgedEvent>>();
+        world.init_resource::<Events<DamageBlockedEvent>>();
+
+        let attacker = world
+            .spawn(GlobalTransform::from_translation(Vec3::new(0.0, 0.0, -2.0)))
+            .id();
+        let player = world
+            .spawn((
+                Health::full(100.0),
+                Stamina::full(stamina),
+                PlayerCombatState {
+                    left: ArmCombatState::Blocking {
+                        block_timer: 0.5,
+                        weapon_kind: WeaponKind::Shield,
+                    },
+                    right: ArmCombatState::Ready,
+                },
+                Damage::physical(20.0).with_source(attacker),
+                GlobalTransform::IDENTITY,
+            ))
+            .id();
+        world.spawn((
+            FlyCamera::default(),
+            GlobalTransform::IDENTITY,
+            ChildOf(player),
+        ));
+
+        (world, player)
+    }
+
+    #[test]
+    fn block_absorbs_damage_for_stamina() {
+        let (mut world, player) = blocking_player(50.0);
+        world.run_system_once(apply_damage).unwrap();
+
+        assert_eq!(world.get::<Health>(player).unwrap().current, 95.0);
+        assert_eq!(world.get::<Stamina>(player).unwrap().current, 20.0);
+        assert!(world.get::<Damage>(player).is_none());
+    }
+
+    #[test]
+    fn guard_breaks_without_stamina() {
+        let (mut world, player) = blocking_player(10.0);
+        world.run_system_once(apply_damage).unwrap();
+
+        assert_eq!(world.get::<Health>(player).unwrap().current, 80.0);
+        assert_eq!(world.get::<Stamina>(player).unwrap().current, 0.0);
+        assert!(matches!(
+            world.get::<PlayerCombatState>(player).unwrap().left,
+            ArmCombatState::Lowering { .. }
+        ));
+    }
 }
@@ -7,6 +7,8 @@ pub struct Health {
     pub current: f32,
     pub max: f32,
     pub regen: f32,
+    /// Кто нанёс последний урон (для начисления опыта за убийство)
+    pub last_attacker: Option<Entity>,
 }
 
 impl Default for Health {
@@ -16,6 +18,7 @@ impl Default for Health {
             current: 65.0,
             max: 65.0,
             regen: 0.3,
+            last_attacker: None,
         }
     }
 }
@@ -26,6 +29,7 @@ impl Health {
             current: max,
             max,
             regen,
+            last_attacker: None,
         }
     }
 
@@ -35,6 +39,7 @@ impl Health {
             current: max,
             max,
             regen: 0.0,
+            last_attacker: None,
         }
     }
 
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct Level {
     pub current: u32,
 }
@@ -17,7 +18,7 @@ impl Level {
     }
 }
 
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct Experience {
     pub current: u32,
     pub to_next_level: u32,
@@ -57,7 +58,7 @@ impl Experience {
 }
 
 /// Компонент для врагов — сколько опыта даёт
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct ExperienceReward {
     pub amount: u32,
 }
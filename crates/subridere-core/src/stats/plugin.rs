@@ -2,7 +2,9 @@ use bevy::prelude::*;
 
 use crate::app::AppState;
 
-use super::damage::apply_damage;
+use super::damage::{
+    DamageBlockedEvent, DamageDodgedEvent, apply_damage, tick_invulnerability, tick_stagger,
+};
 use super::health::{check_player_death, regenerate_health}; // ← ИЗМЕНИТЬ
 use super::level::plugin::LevelPlugin;
 use super::mana::regenerate_mana;
@@ -13,19 +15,24 @@ pub struct StatsPlugin;
 
 impl Plugin for StatsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(LevelPlugin).add_systems(
-            Update,
-            (
-                recalculate_stats,
-                regenerate_health,
-                regenerate_mana,
-                regenerate_stamina,
-                apply_damage,
-                check_player_death, // ← ДОБАВИТЬ
-            )
-                .chain()
-                .run_if(in_state(AppState::InGame)), // ← ДОБАВИТЬ
-        );
+        app.add_plugins(LevelPlugin)
+            .add_event::<DamageDodgedEvent>()
+            .add_event::<DamageBlockedEvent>()
+            .add_systems(
+                Update,
+                (
+                    recalculate_stats,
+                    regenerate_health,
+                    regenerate_mana,
+                    regenerate_stamina,
+                    tick_invulnerability,
+                    tick_stagger,
+                    apply_damage,
+                    check_player_death, // ← ДОБАВИТЬ
+                )
+                    .chain()
+                    .run_if(in_state(AppState::InGame)), // ← ДОБАВИТЬ
+            );
 
         info!("✅ Stats plugin initialized");
     }
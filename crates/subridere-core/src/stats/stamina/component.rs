@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+/// Задержка регенерации после траты стамины (секунды)
+pub const STAMINA_REGEN_DELAY: f32 = 0.8;
+
 /// Компонент стамины.
 /// `max` и `regen` синхронизируются из ComputedStats.
 #[derive(Component, Clone, Copy, Debug)]
@@ -7,6 +10,8 @@ pub struct Stamina {
     pub current: f32,
     pub max: f32,
     pub regen: f32,
+    /// Сколько ещё ждать до начала регенерации
+    pub regen_delay: f32,
 }
 
 impl Default for Stamina {
@@ -16,6 +21,7 @@ impl Default for Stamina {
             current: 59.0,
             max: 59.0,
             regen: 6.5,
+            regen_delay: 0.0,
         }
     }
 }
@@ -26,6 +32,7 @@ impl Stamina {
             current: max,
             max,
             regen,
+            regen_delay: 0.0,
         }
     }
 
@@ -35,6 +42,7 @@ impl Stamina {
             current: max,
             max,
             regen: 0.0,
+            regen_delay: 0.0,
         }
     }
 
@@ -42,6 +50,7 @@ impl Stamina {
     pub fn spend(&mut self, amount: f32) -> bool {
         if self.current >= amount {
             self.current -= amount;
+            self.regen_delay = STAMINA_REGEN_DELAY;
             true
         } else {
             false
@@ -1,9 +1,14 @@
 use super::component::Stamina;
 use bevy::prelude::*;
 
-/// Регенерация стамины
+/// Регенерация стамины (с задержкой после траты)
 pub fn regenerate_stamina(mut query: Query<&mut Stamina>, time: Res<Time>) {
     for mut stamina in &mut query {
+        if stamina.regen_delay > 0.0 {
+            stamina.regen_delay = (stamina.regen_delay - time.delta_secs()).max(0.0);
+            continue;
+        }
+
         if stamina.regen > 0.0 && stamina.current < stamina.max {
             stamina.current =
                 (stamina.current + stamina.regen * time.delta_secs()).min(stamina.max);
@@ -2,10 +2,15 @@ use bevy::prelude::*;
 use bevy_ui_actions::prelude::*;
 
 use crate::app::AppState;
+use crate::world::seed::WorldSeed;
 
 use super::components::DeathScreenRoot;
 
-pub fn spawn_death_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_death_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    seed: Res<WorldSeed>,
+) {
     let font = asset_server.load("fonts/dogica.ttf");
 
     commands
@@ -48,6 +53,17 @@ pub fn spawn_death_screen(mut commands: Commands, asset_server: Res<AssetServer>
                 TextColor(Color::srgb(0.6, 0.4, 0.4)),
             ));
 
+            // Seed — чтобы забег можно было воспроизвести
+            root.spawn((
+                Text::new(format!("Seed: {}", seed.0)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.35, 0.35)),
+            ));
+
             // Spacer
             root.spawn(Node {
                 height: Val::Px(30.0),
@@ -9,7 +9,9 @@ use super::state::{GameMenuActiveTab, GameMenuState, game_menu_open};
 use super::tabs::character::sync::{
     sync_attributes_display, sync_level_display, sync_stats_display,
 };
-use super::tabs::inventory::sync::{sync_description_panel, sync_drag_visual, sync_slots};
+use super::tabs::inventory::sync::{
+    sync_description_panel, sync_drag_visual, sync_slots, sync_weight_display,
+};
 use super::tabs::inventory::tooltip::{clear_tooltip_on_unhover, update_hovered_tooltip};
 
 pub struct GameMenuPlugin;
@@ -41,6 +43,7 @@ impl Plugin for GameMenuPlugin {
                     sync_slots,
                     sync_drag_visual,
                     sync_description_panel,
+                    sync_weight_display,
                     // Character tab
                     sync_level_display,
                     sync_attributes_display,
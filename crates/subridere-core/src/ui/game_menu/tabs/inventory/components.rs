@@ -54,6 +54,10 @@ pub struct SlotQuantity;
 #[derive(Component)]
 pub struct SlotLabel;
 
+/// Carried weight vs capacity text under the grid
+#[derive(Component)]
+pub struct WeightText;
+
 /// Description panel root
 #[derive(Component)]
 pub struct DescriptionPanel;
@@ -21,3 +21,7 @@ pub const EQUIP_BORDER: Color = Color::srgb(0.30, 0.28, 0.25);
 
 pub const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 pub const TEXT_DIM: Color = Color::srgb(0.6, 0.6, 0.6);
+
+// === Encumbrance ===
+pub const WEIGHT_BURDENED: Color = Color::srgb(0.95, 0.75, 0.3);
+pub const WEIGHT_OVERLOADED: Color = Color::srgb(0.95, 0.3, 0.3);
@@ -7,5 +7,5 @@ pub mod tooltip;
 
 pub use components::{SelectedSlot, SlotId, SlotUI};
 pub use spawn::spawn_inventory_content;
-pub use sync::{sync_description_panel, sync_drag_visual, sync_slots};
+pub use sync::{sync_description_panel, sync_drag_visual, sync_slots, sync_weight_display};
 pub use tooltip::{clear_tooltip_on_unhover, update_hovered_tooltip};
@@ -253,6 +253,18 @@ fn spawn_inventory_grid(parent: &mut ChildSpawnerCommands, font: &Handle<Font>)
                     spawn_inventory_slot(grid, font, i);
                 }
             });
+
+            // Weight / capacity
+            col.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(TEXT_DIM),
+                WeightText,
+            ));
         });
 }
 
@@ -1,13 +1,37 @@
 use bevy::prelude::*;
 use bevy_ui_actions::{DragPhase, DragState};
 
-use crate::inventory::component::{Equipment, Inventory};
+use crate::inventory::component::{Encumbrance, EncumbranceTier, Equipment, Inventory};
 use crate::items::{ConsumableEffect, ItemCategory, ItemRegistry};
 use crate::player::component::Player;
 
 use super::components::*;
 use super::layout::*;
 
+/// Sync carried weight text and its color by encumbrance tier
+pub fn sync_weight_display(
+    player_query: Query<&Encumbrance, With<Player>>,
+    mut text_query: Query<(&mut Text, &mut TextColor), With<WeightText>>,
+) {
+    let Ok(encumbrance) = player_query.single() else {
+        return;
+    };
+
+    let (label, color) = match encumbrance.tier {
+        EncumbranceTier::Normal => ("", TEXT_DIM),
+        EncumbranceTier::Burdened => (" (Burdened)", WEIGHT_BURDENED),
+        EncumbranceTier::Overloaded => (" (Overloaded)", WEIGHT_OVERLOADED),
+    };
+
+    for (mut text, mut text_color) in &mut text_query {
+        text.0 = format!(
+            "Weight: {:.1} / {:.1}{}",
+            encumbrance.weight, encumbrance.capacity, label
+        );
+        text_color.0 = color;
+    }
+}
+
 /// Sync all slot visuals (icons, quantities, backgrounds)
 pub fn sync_slots(
     inventory_query: Query<(&Inventory, &Equipment), With<Player>>,
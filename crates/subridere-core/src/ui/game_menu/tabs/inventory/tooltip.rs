@@ -197,6 +197,16 @@ fn add_weapon_stats(
         diff: eq_weapon.map(|eq| calc_diff(weapon.speed, eq.speed)),
     });
 
+    if let Some(stamina_cost) = weapon.stamina_cost {
+        sections.push(TooltipSection::Stat {
+            label: "Stamina Cost".to_string(),
+            value: format!("{:.0}", stamina_cost),
+            diff: eq_weapon
+                .and_then(|eq| eq.stamina_cost)
+                .map(|eq_cost| calc_diff(eq_cost, stamina_cost)),
+        });
+    }
+
     if weapon.mana_cost > 0.0 {
         sections.push(TooltipSection::Stat {
             label: "Mana Cost".to_string(),
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use crate::core::components::GameEntity;
+use crate::player::component::Player;
+use crate::stats::damage::{DamageBlockedEvent, DamageDodgedEvent};
+
+/// Всплывающая надпись у прицела: "Dodged!", "Blocked!" или "Parried!"
+#[derive(Component)]
+pub struct DodgeNotice {
+    pub timer: Timer,
+}
+
+pub fn spawn_dodge_notice(
+    mut commands: Commands,
+    mut evr: EventReader<DamageDodgedEvent>,
+    mut evr_blocked: EventReader<DamageBlockedEvent>,
+    players: Query<(), With<Player>>,
+    existing: Query<Entity, With<DodgeNotice>>,
+    asset_server: Res<AssetServer>,
+) {
+    // Несколько событий за кадр — одна надпись, парирование важнее блока
+    let dodged = evr.read().any(|e| players.get(e.entity).is_ok());
+    let blocked: Vec<bool> = evr_blocked
+        .read()
+        .filter(|e| players.get(e.entity).is_ok())
+        .map(|e| e.parried)
+        .collect();
+
+    let (text, color) = if blocked.contains(&true) {
+        ("Parried!", Color::srgb(1.0, 0.85, 0.3))
+    } else if !blocked.is_empty() {
+        ("Blocked!", Color::srgb(0.8, 0.8, 0.8))
+    } else if dodged {
+        ("Dodged!", Color::srgb(0.5, 0.8, 1.0))
+    } else {
+        return;
+    };
+
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    commands.spawn((
+        Text::new(text),
+        TextFont {
+            font: asset_server.load("fonts/dogica.ttf"),
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(color),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.0),
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+        DodgeNotice {
+            timer: Timer::from_seconds(0.6, TimerMode::Once),
+        },
+        Name::new("DodgeNotice"),
+        GameEntity,
+    ));
+}
+
+pub fn update_dodge_notice(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut DodgeNotice, &mut TextColor, &mut Node)>,
+) {
+    for (entity, mut notice, mut color, mut node) in &mut q {
+        notice.timer.tick(time.delta());
+
+        // Всплывает вверх и тает
+        let progress = notice.timer.fraction();
+        color.0.set_alpha(1.0 - progress);
+        node.top = Val::Percent(40.0 - progress * 5.0);
+
+        if notice.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+use crate::core::components::GameEntity;
+use crate::player::component::Player;
+use crate::stats::{Attributes, LevelUpEvent};
+
+/// Сколько держится уведомление о новом уровне
+const LEVEL_UP_DURATION: f32 = 3.0;
+
+/// Уведомление "LEVEL UP" поверх HUD
+#[derive(Component)]
+pub struct LevelUpNotice {
+    pub timer: Timer,
+}
+
+pub fn spawn_level_up_notice(
+    mut commands: Commands,
+    mut evr: EventReader<LevelUpEvent>,
+    players: Query<&Attributes, With<Player>>,
+    existing: Query<Entity, With<LevelUpNotice>>,
+    asset_server: Res<AssetServer>,
+) {
+    // При нескольких уровнях за кадр показываем только последний
+    let Some(event) = evr.read().filter(|e| players.get(e.entity).is_ok()).last() else {
+        return;
+    };
+
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    let unspent = players
+        .get(event.entity)
+        .map(|attrs| attrs.unspent_points)
+        .unwrap_or(0);
+
+    let font = asset_server.load("fonts/dogica.ttf");
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(120.0),
+                width: Val::Vw(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            LevelUpNotice {
+                timer: Timer::from_seconds(LEVEL_UP_DURATION, TimerMode::Once),
+            },
+            Name::new("LevelUpNotice"),
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("LEVEL {}", event.new_level)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+            ));
+            parent.spawn((
+                Text::new(format!("{} attribute points to spend [Tab]", unspent)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+pub fn update_level_up_notice(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut notices: Query<(Entity, &mut LevelUpNotice, &Children)>,
+    mut texts: Query<&mut TextColor>,
+) {
+    for (entity, mut notice, children) in &mut notices {
+        notice.timer.tick(time.delta());
+
+        // Затухание в последней трети
+        let alpha = (notice.timer.remaining_secs() / (LEVEL_UP_DURATION / 3.0)).min(1.0);
+        for child in children.iter() {
+            if let Ok(mut color) = texts.get_mut(child) {
+                color.0.set_alpha(alpha);
+            }
+        }
+
+        if notice.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -1,6 +1,8 @@
 pub mod crosshair;
+pub mod dodge;
 pub mod fps;
 pub mod hitflash;
+pub mod level_up;
 pub mod pickup_hint;
 pub mod plugin;
 pub mod stats;
@@ -4,8 +4,10 @@ use crate::app::AppState;
 
 use super::{
     crosshair::CrosshairPlugin,
+    dodge::{spawn_dodge_notice, update_dodge_notice},
     fps::UiFpsPlugin,
     hitflash::{HitFlashEvent, spawn_hit_overlay, update_hit_overlay},
+    level_up::{spawn_level_up_notice, update_level_up_notice},
     pickup_hint::{despawn_pickup_hint, spawn_pickup_hint, update_pickup_hint},
     stats::UiStatsPlugin,
 };
@@ -27,7 +29,15 @@ impl Plugin for HudUiPlugin {
             // Update systems - only in game
             .add_systems(
                 Update,
-                (spawn_hit_overlay, update_hit_overlay, update_pickup_hint)
+                (
+                    spawn_hit_overlay,
+                    update_hit_overlay,
+                    update_pickup_hint,
+                    spawn_level_up_notice,
+                    update_level_up_notice,
+                    spawn_dodge_notice,
+                    update_dodge_notice,
+                )
                     .run_if(in_state(AppState::InGame)),
             );
     }
@@ -2,3 +2,7 @@ use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct MainMenuRoot;
+
+/// Текст с текущим сидом мира
+#[derive(Component)]
+pub struct SeedText;
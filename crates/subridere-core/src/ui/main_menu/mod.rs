@@ -1,5 +1,6 @@
 pub mod components;
 pub mod plugin;
+pub mod seed;
 pub mod spawn;
 
 pub use plugin::MainMenuPlugin;
@@ -2,14 +2,22 @@ use bevy::prelude::*;
 
 use crate::app::AppState;
 
+use super::seed::{SeedInput, seed_text_input, sync_seed_text};
 use super::spawn::{despawn_main_menu, spawn_main_menu};
 
 pub struct MainMenuPlugin;
 
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::MainMenu), spawn_main_menu)
-            .add_systems(OnExit(AppState::MainMenu), despawn_main_menu);
+        app.init_resource::<SeedInput>()
+            .add_systems(OnEnter(AppState::MainMenu), spawn_main_menu)
+            .add_systems(OnExit(AppState::MainMenu), despawn_main_menu)
+            .add_systems(
+                Update,
+                (seed_text_input, sync_seed_text)
+                    .chain()
+                    .run_if(in_state(AppState::MainMenu)),
+            );
 
         info!("✅ Main Menu plugin initialized");
     }
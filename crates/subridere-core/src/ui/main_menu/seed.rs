@@ -0,0 +1,109 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy_ui_actions::prelude::*;
+
+use super::components::SeedText;
+use crate::world::seed::{SeedOrigin, WorldSeed};
+
+/// Максимальная длина вводимого сида (влезает в u64)
+const MAX_SEED_DIGITS: usize = 19;
+
+/// Режим ввода сида с клавиатуры
+#[derive(Resource, Default)]
+pub struct SeedInput {
+    pub editing: bool,
+    pub buffer: String,
+}
+
+/// Клик по полю сида — начать/закончить ввод
+pub struct EditSeedAction;
+
+impl UiAction for EditSeedAction {
+    fn execute(&self, world: &mut World) {
+        let current = world.resource::<WorldSeed>().0;
+        let mut input = world.resource_mut::<SeedInput>();
+
+        if input.editing {
+            input.editing = false;
+        } else {
+            input.editing = true;
+            input.buffer = current.to_string();
+        }
+    }
+}
+
+/// Новый случайный сид
+pub struct RerollSeedAction;
+
+impl UiAction for RerollSeedAction {
+    fn execute(&self, world: &mut World) {
+        let seed = WorldSeed::random();
+        info!("🌱 New seed: {}", seed.0);
+        world.insert_resource(seed);
+        world.insert_resource(SeedOrigin::Random);
+        world.resource_mut::<SeedInput>().editing = false;
+    }
+}
+
+/// Ввод цифр сида: Enter — применить, Escape — отменить
+pub fn seed_text_input(
+    mut events: EventReader<KeyboardInput>,
+    mut input: ResMut<SeedInput>,
+    mut seed: ResMut<WorldSeed>,
+    mut origin: ResMut<SeedOrigin>,
+) {
+    if !input.editing {
+        events.clear();
+        return;
+    }
+
+    for event in events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Character(chars) => {
+                for c in chars.chars().filter(char::is_ascii_digit) {
+                    if input.buffer.len() < MAX_SEED_DIGITS {
+                        input.buffer.push(c);
+                    }
+                }
+            }
+            Key::Backspace => {
+                input.buffer.pop();
+            }
+            Key::Enter => {
+                if let Ok(value) = input.buffer.parse() {
+                    seed.0 = value;
+                    *origin = SeedOrigin::Player;
+                    info!("🌱 Seed set: {}", value);
+                }
+                input.editing = false;
+            }
+            Key::Escape => {
+                input.editing = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn sync_seed_text(
+    input: Res<SeedInput>,
+    seed: Res<WorldSeed>,
+    mut query: Query<&mut Text, With<SeedText>>,
+) {
+    if !input.is_changed() && !seed.is_changed() {
+        return;
+    }
+
+    for mut text in &mut query {
+        text.0 = if input.editing {
+            format!("Seed: {}_", input.buffer)
+        } else {
+            format!("Seed: {}", seed.0)
+        };
+    }
+}
@@ -1,10 +1,17 @@
 use bevy::prelude::*;
 use bevy_ui_actions::prelude::*;
 
-use super::components::MainMenuRoot;
+use super::components::{MainMenuRoot, SeedText};
+use super::seed::{EditSeedAction, RerollSeedAction};
 use crate::app::AppState;
+use crate::save::{LoadGameEvent, RunSnapshot, SAVE_PATH};
+use crate::world::seed::WorldSeed;
 
-pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_main_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    seed: Res<WorldSeed>,
+) {
     let font = asset_server.load("fonts/dogica.ttf");
 
     commands
@@ -62,9 +69,52 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 });
 
+                // Continue button (только если есть сохранение)
+                if RunSnapshot::exists(SAVE_PATH) {
+                    spawn_menu_button(panel, &font, "Continue", ContinueAction);
+                }
+
                 // New Game button
                 spawn_menu_button(panel, &font, "New Game", NewGameAction);
 
+                // Seed: клик — ввести вручную, кнопка — случайный
+                panel
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Button,
+                            Node {
+                                min_width: Val::Px(220.0),
+                                height: Val::Px(36.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.1, 0.1, 0.12)),
+                            OnClick::new(EditSeedAction),
+                            InteractiveVisual,
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                Text::new(format!("Seed: {}", seed.0)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                                SeedText,
+                            ));
+                        });
+
+                        spawn_small_button(row, &font, "Reroll", RerollSeedAction);
+                    });
+
                 // Settings button (заглушка)
                 spawn_menu_button(panel, &font, "Settings", SettingsAction);
 
@@ -116,6 +166,38 @@ fn spawn_menu_button(
         });
 }
 
+fn spawn_small_button(
+    parent: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    label: &str,
+    action: impl UiAction,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.18)),
+            OnClick::new(action),
+            InteractiveVisual,
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+            ));
+        });
+}
+
 // === Actions ===
 
 struct NewGameAction;
@@ -129,6 +211,15 @@ impl UiAction for NewGameAction {
     }
 }
 
+struct ContinueAction;
+
+impl UiAction for ContinueAction {
+    fn execute(&self, world: &mut World) {
+        info!("📂 Continue clicked");
+        world.send_event(LoadGameEvent);
+    }
+}
+
 struct SettingsAction;
 
 impl UiAction for SettingsAction {
@@ -3,7 +3,7 @@
 use bevy::prelude::*;
 use bevy_ui_actions::prelude::*;
 
-use crate::player::arm::pose_debug::{DebugPose, EditTarget, PoseDebugState};
+use crate::player::arm::pose_debug::{EditTarget, PoseDebugState, print_all_poses};
 
 use super::components::*;
 
@@ -518,36 +518,7 @@ impl UiAction for AdjustValueAction {
 struct PrintAllPosesAction;
 impl UiAction for PrintAllPosesAction {
     fn execute(&self, world: &mut World) {
-        let state = world.resource::<PoseDebugState>();
-
-        info!("");
-        info!("════════════════════════════════════════════════════════════════");
-        info!("🎨 ALL POSES (copy to components.rs):");
-        info!("════════════════════════════════════════════════════════════════");
-
-        for &pose_type in DebugPose::ALL {
-            let pose = state.poses.get(pose_type);
-            info!("");
-            info!("pub fn {}() -> Self {{", pose_type.fn_name());
-            info!("    Self {{");
-            info!(
-                "        hand_offset: Vec3::new({:.2}, {:.2}, {:.2}),",
-                pose.hand_offset.x, pose.hand_offset.y, pose.hand_offset.z
-            );
-            info!(
-                "        elbow_hint: Vec3::new({:.2}, {:.2}, {:.2}),",
-                pose.elbow_hint.x, pose.elbow_hint.y, pose.elbow_hint.z
-            );
-            info!(
-                "        hand_rotation: Self::rot({:.1}, {:.1}, {:.1}),",
-                pose.rotation_degrees.x, pose.rotation_degrees.y, pose.rotation_degrees.z
-            );
-            info!("    }}");
-            info!("}}");
-        }
-
-        info!("");
-        info!("════════════════════════════════════════════════════════════════");
+        print_all_poses(&world.resource::<PoseDebugState>().poses);
     }
 }
 
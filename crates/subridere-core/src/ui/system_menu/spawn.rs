@@ -3,6 +3,7 @@ use bevy_ui_actions::prelude::*;
 
 use super::components::SystemMenuRoot;
 use crate::app::AppState;
+use crate::save::{LoadGameEvent, RunSnapshot, SAVE_PATH, SaveGameEvent};
 
 pub fn spawn_system_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
     let font = asset_server.load("fonts/dogica.ttf");
@@ -58,6 +59,10 @@ pub fn spawn_system_menu(mut commands: Commands, asset_server: Res<AssetServer>)
                 // Resume button
                 spawn_menu_button(panel, &font, "Resume", ResumeAction);
 
+                // Save / Load
+                spawn_menu_button(panel, &font, "Save Game", SaveGameAction);
+                spawn_menu_button(panel, &font, "Load Game", LoadGameAction);
+
                 // Settings button (заглушка)
                 spawn_menu_button(panel, &font, "Settings", SettingsAction);
 
@@ -124,6 +129,28 @@ impl UiAction for ResumeAction {
     }
 }
 
+struct SaveGameAction;
+
+impl UiAction for SaveGameAction {
+    fn execute(&self, world: &mut World) {
+        info!("💾 Save clicked");
+        world.send_event(SaveGameEvent);
+    }
+}
+
+struct LoadGameAction;
+
+impl UiAction for LoadGameAction {
+    fn execute(&self, world: &mut World) {
+        if !RunSnapshot::exists(SAVE_PATH) {
+            info!("📂 No save file to load");
+            return;
+        }
+        info!("📂 Load clicked");
+        world.send_event(LoadGameEvent);
+    }
+}
+
 struct SettingsAction;
 
 impl UiAction for SettingsAction {
@@ -2,10 +2,15 @@ use bevy::prelude::*;
 use bevy_ui_actions::prelude::*;
 
 use crate::app::AppState;
+use crate::world::seed::WorldSeed;
 
 use super::components::VictoryScreenRoot;
 
-pub fn spawn_victory_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_victory_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    seed: Res<WorldSeed>,
+) {
     let font = asset_server.load("fonts/dogica.ttf");
 
     commands
@@ -48,6 +53,17 @@ pub fn spawn_victory_screen(mut commands: Commands, asset_server: Res<AssetServe
                 TextColor(Color::srgb(0.7, 0.8, 0.9)),
             ));
 
+            // Seed — чтобы забег можно было воспроизвести
+            root.spawn((
+                Text::new(format!("Seed: {}", seed.0)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.6, 0.7)),
+            ));
+
             // Spacer
             root.spawn(Node {
                 height: Val::Px(40.0),
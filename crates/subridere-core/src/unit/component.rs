@@ -11,6 +11,10 @@ pub struct Grounded(pub bool);
 #[derive(Component, Default)]
 pub struct Velocity(pub Vec3);
 
+/// The entity is inside a ladder volume: gravity is off and forward input climbs.
+#[derive(Component)]
+pub struct Climbing;
+
 /// Signals the entity wants to jump. Added by input, consumed by logic.
 #[derive(Component)]
 pub struct JumpIntent;
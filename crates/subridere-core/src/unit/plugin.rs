@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use super::systems::intent::{
     apply_dash_intents, apply_jump_intents, apply_move_intents, apply_turn_intents, apply_velocity,
-    update_grounded_system,
+    update_climbing_system, update_grounded_system,
 };
 
 pub struct UnitPlugin;
@@ -13,6 +13,7 @@ impl Plugin for UnitPlugin {
             Update,
             (
                 update_grounded_system,
+                update_climbing_system,
                 apply_move_intents,
                 apply_jump_intents,
                 apply_dash_intents,
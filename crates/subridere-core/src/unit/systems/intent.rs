@@ -2,11 +2,16 @@ use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
 use crate::audio::weapons::events::{MagicBoltFireEvent, PhysicsCubeFireEvent};
+use crate::inventory::Encumbrance;
+use crate::stats::computed::ComputedStats;
+use crate::stats::damage::component::Invulnerable;
 use crate::stats::mana::component::Mana;
+use crate::stats::stamina::component::Stamina;
 use crate::unit::component::TurnIntent;
 use crate::unit::component::{
-    DashIntent, Grounded, JumpIntent, MoveIntent, ShootIntent, Unit, Velocity,
+    Climbing, DashIntent, Grounded, JumpIntent, MoveIntent, ShootIntent, Unit, Velocity,
 };
+use crate::world::room::types::Ladder;
 
 // Movement tuning constants
 const MOVE_ACCEL: f32 = 50.0;
@@ -14,6 +19,11 @@ const DASH_SPEED: f32 = 12.0;
 const JUMP_SPEED: f32 = 20.0;
 const GRAVITY: f32 = 20.0;
 const DAMPING: f32 = 3.0;
+const CLIMB_SPEED: f32 = 3.0;
+
+// Stamina costs (units without Stamina act for free)
+const DASH_STAMINA_COST: f32 = 15.0;
+const JUMP_STAMINA_COST: f32 = 8.0;
 
 /// Updates `Grounded` based on Rapier's KinematicCharacterController output.
 pub fn update_grounded_system(
@@ -24,34 +34,72 @@ pub fn update_grounded_system(
     }
 }
 
+/// Marks units standing inside a ladder volume as `Climbing`.
+pub fn update_climbing_system(
+    mut commands: Commands,
+    ladders: Query<(&Ladder, &GlobalTransform)>,
+    units: Query<(Entity, &Transform, Has<Climbing>), With<Unit>>,
+) {
+    for (entity, transform, climbing) in &units {
+        let inside = ladders.iter().any(|(ladder, ladder_transform)| {
+            let local = transform.translation - ladder_transform.translation();
+            local.abs().cmple(ladder.half_extents).all()
+        });
+
+        if inside && !climbing {
+            commands.entity(entity).insert(Climbing);
+        } else if !inside && climbing {
+            commands.entity(entity).remove::<Climbing>();
+        }
+    }
+}
+
 /// Applies movement intents, converting local XZ input to world-space acceleration.
+/// Acceleration is scaled down by encumbrance. Climbing units also move
+/// vertically: forward climbs up, backward climbs down.
 pub fn apply_move_intents(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &MoveIntent, &mut Velocity, &Transform), With<Unit>>,
+    mut query: Query<
+        (
+            Entity,
+            &MoveIntent,
+            &mut Velocity,
+            &Transform,
+            Option<&Encumbrance>,
+            Has<Climbing>,
+        ),
+        With<Unit>,
+    >,
 ) {
     let dt = time.delta_secs();
-    for (entity, intent, mut velocity, transform) in &mut query {
+    for (entity, intent, mut velocity, transform, encumbrance, climbing) in &mut query {
         let local = intent.0;
         if local.length_squared() > 0.0 {
             // In Bevy: forward is -Z, right is +X
             let forward = -transform.forward();
             let right = transform.right();
             let dir = (right * local.x + forward * local.z).normalize_or_zero();
-            velocity.0.x += dir.x * MOVE_ACCEL * dt;
-            velocity.0.z += dir.z * MOVE_ACCEL * dt;
+            let accel = MOVE_ACCEL * encumbrance.map_or(1.0, |e| e.tier.move_mult());
+            velocity.0.x += dir.x * accel * dt;
+            velocity.0.z += dir.z * accel * dt;
+
+            if climbing {
+                // Forward input is -Z
+                velocity.0.y = -local.z * CLIMB_SPEED;
+            }
         }
         commands.entity(entity).remove::<MoveIntent>();
     }
 }
 
-/// Applies jump if grounded, resetting vertical velocity.
+/// Applies jump if grounded and the unit can pay the stamina cost.
 pub fn apply_jump_intents(
     mut commands: Commands,
-    mut query: Query<(Entity, &Grounded, &mut Velocity), With<JumpIntent>>,
+    mut query: Query<(Entity, &Grounded, &mut Velocity, Option<&mut Stamina>), With<JumpIntent>>,
 ) {
-    for (entity, grounded, mut velocity) in &mut query {
-        if grounded.0 {
+    for (entity, grounded, mut velocity, stamina) in &mut query {
+        if grounded.0 && try_spend_stamina(stamina, JUMP_STAMINA_COST) {
             velocity.0.y = JUMP_SPEED;
             // Optional: play jump SFX here
         }
@@ -70,27 +118,65 @@ pub fn apply_turn_intents(
     }
 }
 
-/// Overrides horizontal velocity for dash intents.
+/// Overrides horizontal velocity for dash intents, if the unit can pay the stamina cost.
+/// Units with stats become invulnerable for `dodge_frames` seconds.
+/// Overloaded units cannot dash.
 pub fn apply_dash_intents(
     mut commands: Commands,
-    mut query: Query<(Entity, &DashIntent, &mut Velocity), With<Unit>>,
+    mut query: Query<
+        (
+            Entity,
+            &DashIntent,
+            &mut Velocity,
+            Option<&mut Stamina>,
+            Option<&ComputedStats>,
+            Option<&Encumbrance>,
+        ),
+        With<Unit>,
+    >,
 ) {
-    for (entity, intent, mut velocity) in &mut query {
-        let dash_vec = intent.0.normalize_or_zero() * DASH_SPEED;
-        velocity.0.x = dash_vec.x;
-        velocity.0.z = dash_vec.z;
+    for (entity, intent, mut velocity, stamina, stats, encumbrance) in &mut query {
+        let can_dash = encumbrance.is_none_or(|e| e.tier.can_dash());
+        if can_dash && try_spend_stamina(stamina, DASH_STAMINA_COST) {
+            let dash_vec = intent.0.normalize_or_zero() * DASH_SPEED;
+            velocity.0.x = dash_vec.x;
+            velocity.0.z = dash_vec.z;
+
+            if let Some(stats) = stats.filter(|s| s.dodge_frames > 0.0) {
+                commands
+                    .entity(entity)
+                    .insert(Invulnerable::new(stats.dodge_frames));
+            }
+        }
         commands.entity(entity).remove::<DashIntent>();
     }
 }
 
+/// Spends stamina if the unit has it. Units without `Stamina` always succeed.
+fn try_spend_stamina(stamina: Option<Mut<Stamina>>, cost: f32) -> bool {
+    match stamina {
+        Some(mut stamina) => stamina.spend(cost),
+        None => true,
+    }
+}
+
 /// Applies gravity, damping, and moves the character via KinematicCharacterController.
+/// Climbing units ignore gravity and slow down vertically instead.
 pub fn apply_velocity(
     time: Res<Time>,
-    mut query: Query<(&mut Velocity, &mut KinematicCharacterController, &Grounded), With<Unit>>,
+    mut query: Query<
+        (
+            &mut Velocity,
+            &mut KinematicCharacterController,
+            &Grounded,
+            Has<Climbing>,
+        ),
+        With<Unit>,
+    >,
 ) {
     let dt = time.delta_secs();
 
-    for (mut velocity, mut controller, grounded) in &mut query {
+    for (mut velocity, mut controller, grounded, climbing) in &mut query {
         if velocity.0.length_squared() < 0.0001 && grounded.0 {
             controller.translation = Some(Vec3::ZERO);
             continue;
@@ -99,7 +185,9 @@ pub fn apply_velocity(
         velocity.0.x = velocity.0.x.lerp(0.0, DAMPING * dt);
         velocity.0.z = velocity.0.z.lerp(0.0, DAMPING * dt);
 
-        if !grounded.0 {
+        if climbing {
+            velocity.0.y = velocity.0.y.lerp(0.0, DAMPING * dt);
+        } else if !grounded.0 {
             velocity.0.y -= GRAVITY * dt;
         } else if velocity.0.y < 0.0 {
             velocity.0.y = 0.0;
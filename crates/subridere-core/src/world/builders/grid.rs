@@ -1,7 +1,10 @@
 // src/world/builders/grid.rs
 
-use crate::world::builders::room::spawn_simple_room;
-use crate::world::room::types::{DoorFlags, RoomMap, RoomMetadata, WallFlags};
+use crate::world::builders::themed::spawn_room_of_kind;
+use crate::world::room::template::RoomTemplates;
+use crate::world::room::types::{
+    DoorFlags, RoomKind, RoomMap, RoomMetadata, VerticalFlags, VerticalKind, WallFlags,
+};
 use bevy::prelude::*;
 
 pub fn register_room(map: &mut RoomMap, pos: IVec3) {
@@ -49,33 +52,55 @@ pub fn register_room(map: &mut RoomMap, pos: IVec3) {
     map.rooms.insert(
         pos,
         RoomMetadata {
+            kind: RoomKind::Normal,
             wall_flags: wall,
             door_flags: door,
+            vertical: VerticalFlags::default(),
             has_light: true,
             entity: None,
         },
     );
 }
 
+/// Связать комнату `lower` с комнатой над ней (y + 1).
+/// Горизонтальные соседи соединяются в register_room автоматически,
+/// вертикальные — только явно, иначе проёмы были бы в каждой комнате.
+pub fn register_vertical_link(map: &mut RoomMap, lower: IVec3, kind: VerticalKind) {
+    let upper = lower + IVec3::Y;
+    if !map.rooms.contains_key(&lower) || !map.rooms.contains_key(&upper) {
+        warn!("Vertical link {:?} -> {:?}: room missing", lower, upper);
+        return;
+    }
+
+    if let Some(meta) = map.rooms.get_mut(&lower) {
+        meta.vertical.up = Some(kind);
+    }
+    if let Some(meta) = map.rooms.get_mut(&upper) {
+        meta.vertical.down = Some(kind);
+    }
+}
+
+/// Спавнит все зарегистрированные комнаты, выбирая строителя по RoomKind
 pub fn spawn_registered_rooms(
     commands: &mut Commands,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<StandardMaterial>,
     room_size: Vec3,
     wall_t: f32,
+    templates: &RoomTemplates,
     map: &mut RoomMap,
 ) {
     for (pos, metadata) in map.rooms.iter_mut() {
-        let origin = Vec3::new(pos.x as f32 * room_size.x, 0.0, pos.z as f32 * room_size.z);
-        let ent = spawn_simple_room(
+        let origin = pos.as_vec3() * room_size;
+        let ent = spawn_room_of_kind(
             commands,
             meshes,
             materials,
             origin,
             room_size,
             wall_t,
-            metadata.wall_flags.clone(),
-            metadata.door_flags.clone(),
+            metadata,
+            templates.get(metadata.kind),
         );
         metadata.entity = Some(ent);
     }
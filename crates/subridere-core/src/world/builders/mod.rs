@@ -1,4 +1,6 @@
 pub mod grid;
 pub mod panel;
 pub mod room;
+pub mod themed;
+pub mod vertical;
 pub mod wall;
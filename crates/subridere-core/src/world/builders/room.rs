@@ -2,9 +2,10 @@
 
 use crate::world::builders::{
     panel::spawn_panel,
+    vertical::{opening_size, spawn_floor_with_opening, spawn_vertical_connector},
     wall::{spawn_solid_wall, spawn_wall_with_door},
 };
-use crate::world::room::types::{DoorFlags, WallFlags};
+use crate::world::room::types::{DoorFlags, VerticalFlags, WallFlags};
 use bevy::prelude::*;
 
 pub fn spawn_simple_room(
@@ -14,10 +15,12 @@ pub fn spawn_simple_room(
     origin: Vec3,
     size: Vec3,
     wall_t: f32,
+    color: Color,
     walls: WallFlags,
     doors: DoorFlags,
+    vertical: VerticalFlags,
 ) -> Entity {
-    let material = materials.add(Color::srgb(0.75, 0.75, 0.8));
+    let material = materials.add(color);
     let room = commands
         .spawn((
             Transform::from_translation(origin),
@@ -27,15 +30,33 @@ pub fn spawn_simple_room(
         .id();
 
     commands.entity(room).with_children(|child| {
-        // Пол
-        spawn_panel(
-            child,
-            meshes,
-            material.clone(),
-            Vec3::new(size.x, wall_t, size.z),
-            Vec3::ZERO,
-            "Floor".into(),
-        );
+        // Пол (с проёмом, если есть переход вниз)
+        if let Some(kind) = vertical.down {
+            spawn_floor_with_opening(
+                child,
+                meshes,
+                material.clone(),
+                Vec3::new(size.x, wall_t, size.z),
+                opening_size(kind, size),
+                Vec3::ZERO,
+                "Floor",
+            );
+        } else {
+            spawn_panel(
+                child,
+                meshes,
+                material.clone(),
+                Vec3::new(size.x, wall_t, size.z),
+                Vec3::ZERO,
+                "Floor".into(),
+            );
+        }
+
+        // Потолка нет: его роль играет пол комнаты выше, проём в нём —
+        // проём в потолке. Здесь ставим только пандус/лестницу к нему.
+        if let Some(kind) = vertical.up {
+            spawn_vertical_connector(child, meshes, materials, kind, size, wall_t);
+        }
 
         // Стены
         if walls.back {
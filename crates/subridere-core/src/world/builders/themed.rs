@@ -0,0 +1,103 @@
+// src/world/builders/themed.rs
+
+use crate::block_body::{BlockBodyClip, BlockBodySpawnOptions, spawn_block_body};
+use crate::items::{VisualPart, spawn_item_visual_with_colliders};
+use crate::world::builders::room::spawn_simple_room;
+use crate::world::room::shrine::Shrine;
+use crate::world::room::template::RoomTemplate;
+use crate::world::room::types::{RoomKind, RoomMetadata};
+use bevy::prelude::*;
+
+/// Комната по шаблону её типа: коробка + декор + особенности типа
+pub fn spawn_room_of_kind(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    origin: Vec3,
+    size: Vec3,
+    wall_t: f32,
+    meta: &RoomMetadata,
+    template: &RoomTemplate,
+) -> Entity {
+    let room = spawn_simple_room(
+        commands,
+        meshes,
+        materials,
+        origin,
+        size,
+        wall_t,
+        template.color(),
+        meta.wall_flags.clone(),
+        meta.door_flags.clone(),
+        meta.vertical.clone(),
+    );
+
+    commands
+        .entity(room)
+        .insert(Name::new(format!("Room ({:?})", meta.kind)));
+
+    if !template.props.is_empty() {
+        commands.entity(room).with_children(|child| {
+            spawn_item_visual_with_colliders(child, &template.props, meshes, materials);
+        });
+    }
+
+    for body in &template.bodies {
+        let options = if body.merged {
+            BlockBodySpawnOptions::merged()
+        } else {
+            BlockBodySpawnOptions::visual()
+        };
+        let entity = spawn_block_body(
+            commands,
+            body.model_handle.clone(),
+            body.transform(),
+            options,
+        );
+        commands
+            .entity(entity)
+            .insert((ChildOf(room), Name::new(body.model.clone())));
+        // У запечённого тела нет частей — анимировать нечего
+        if let Some(clip) = body.animation_handle.as_ref().filter(|_| !body.merged) {
+            commands.entity(entity).insert(BlockBodyClip(clip.clone()));
+        }
+    }
+
+    match meta.kind {
+        RoomKind::Shrine => spawn_shrine_altar(commands, meshes, materials, room),
+        RoomKind::Normal
+        | RoomKind::Start
+        | RoomKind::Treasure
+        | RoomKind::Arena
+        | RoomKind::DeadEnd
+        | RoomKind::Exit => {}
+    }
+
+    room
+}
+
+/// Алтарь в центре комнаты
+fn spawn_shrine_altar(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    room: Entity,
+) {
+    let altar = [
+        VisualPart::cube((1.2, 0.9, 1.2), (0.55, 0.55, 0.6, 1.0)).with_offset((0.0, 0.45, 0.0)),
+        VisualPart::sphere(0.25, (0.4, 0.8, 1.0, 1.0)).with_offset((0.0, 1.2, 0.0)),
+    ];
+
+    commands.entity(room).with_children(|child| {
+        child
+            .spawn((
+                Shrine::default(),
+                Transform::default(),
+                Visibility::default(),
+                Name::new("ShrineAltar"),
+            ))
+            .with_children(|altar_child| {
+                spawn_item_visual_with_colliders(altar_child, &altar, meshes, materials);
+            });
+    });
+}
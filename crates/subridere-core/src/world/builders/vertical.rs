@@ -0,0 +1,159 @@
+// src/world/builders/vertical.rs
+
+use crate::world::builders::panel::spawn_panel;
+use crate::world::room::types::{Ladder, VerticalKind};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+/// Угол пандуса — ниже предельного угла подъёма KinematicCharacterController (45°)
+const STAIR_ANGLE: f32 = 35.0 * std::f32::consts::PI / 180.0;
+/// Ширина пандуса и его проёма
+const STAIR_WIDTH: f32 = 3.0;
+/// Проём под лестницу
+const LADDER_OPENING: f32 = 1.5;
+/// Проём шахты
+const SHAFT_OPENING: f32 = 3.0;
+/// Насколько объём лестницы выступает над верхним полом, чтобы можно было вылезти
+const LADDER_EXIT_HEIGHT: f32 = 1.0;
+
+/// Размер проёма в полу (x, z) под переход данного типа
+pub fn opening_size(kind: VerticalKind, room_size: Vec3) -> Vec2 {
+    match kind {
+        VerticalKind::Stairwell => Vec2::new(STAIR_WIDTH, stair_run(room_size)),
+        VerticalKind::Ladder => Vec2::splat(LADDER_OPENING),
+        VerticalKind::Shaft => Vec2::splat(SHAFT_OPENING),
+    }
+}
+
+/// Горизонтальная длина пандуса (ограничена комнатой, оставляя проход по краям)
+fn stair_run(room_size: Vec3) -> f32 {
+    (room_size.y / STAIR_ANGLE.tan()).min(room_size.z - 2.0)
+}
+
+/// Пол с прямоугольным проёмом по центру
+pub fn spawn_floor_with_opening(
+    spawner: &mut ChildSpawnerCommands,
+    meshes: &mut Assets<Mesh>,
+    material: Handle<StandardMaterial>,
+    floor: Vec3,   // размеры полного пола (width, thickness, depth)
+    opening: Vec2, // размеры проёма (x, z)
+    offset: Vec3,  // центр пола
+    name: &str,
+) {
+    let t = floor.y;
+    let side_w = (floor.x - opening.x) * 0.5;
+    let side_off = opening.x * 0.5 + side_w * 0.5;
+    let end_d = (floor.z - opening.y) * 0.5;
+    let end_off = opening.y * 0.5 + end_d * 0.5;
+
+    // Боковые полосы на всю глубину
+    spawn_panel(
+        spawner,
+        meshes,
+        material.clone(),
+        Vec3::new(side_w, t, floor.z),
+        offset + Vec3::new(-side_off, 0.0, 0.0),
+        format!("{name}_Left"),
+    );
+    spawn_panel(
+        spawner,
+        meshes,
+        material.clone(),
+        Vec3::new(side_w, t, floor.z),
+        offset + Vec3::new(side_off, 0.0, 0.0),
+        format!("{name}_Right"),
+    );
+
+    // Полосы спереди и сзади проёма
+    if end_d > 0.0 {
+        spawn_panel(
+            spawner,
+            meshes,
+            material.clone(),
+            Vec3::new(opening.x, t, end_d),
+            offset + Vec3::new(0.0, 0.0, -end_off),
+            format!("{name}_Back"),
+        );
+        spawn_panel(
+            spawner,
+            meshes,
+            material,
+            Vec3::new(opening.x, t, end_d),
+            offset + Vec3::new(0.0, 0.0, end_off),
+            format!("{name}_Front"),
+        );
+    }
+}
+
+/// Подъём в комнату выше: геометрия под проёмом в её полу
+pub fn spawn_vertical_connector(
+    spawner: &mut ChildSpawnerCommands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    kind: VerticalKind,
+    room_size: Vec3,
+    wall_t: f32,
+) {
+    match kind {
+        VerticalKind::Stairwell => spawn_stair_ramp(spawner, meshes, materials, room_size, wall_t),
+        VerticalKind::Ladder => spawn_ladder(spawner, meshes, materials, room_size),
+        // Шахта — просто дыра, подняться нельзя
+        VerticalKind::Shaft => {}
+    }
+}
+
+/// Наклонный пандус вдоль +Z от пола до проёма в потолке
+fn spawn_stair_ramp(
+    spawner: &mut ChildSpawnerCommands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    room_size: Vec3,
+    wall_t: f32,
+) {
+    let run = stair_run(room_size);
+    let rise = room_size.y;
+    let length = (run * run + rise * rise).sqrt();
+    let angle = (rise / run).atan();
+
+    let size = Vec3::new(STAIR_WIDTH, wall_t, length);
+    let mesh = meshes.add(Mesh::from(Cuboid::new(size.x, size.y, size.z)));
+    let material = materials.add(Color::srgb(0.55, 0.5, 0.45));
+
+    spawner.spawn((
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        // Отрицательный угол поднимает +Z конец
+        Transform::from_translation(Vec3::new(0.0, rise * 0.5, 0.0))
+            .with_rotation(Quat::from_rotation_x(-angle)),
+        RigidBody::Fixed,
+        Collider::cuboid(size.x * 0.5, size.y * 0.5, size.z * 0.5),
+        Name::new("Stairwell"),
+    ));
+}
+
+/// Лестница: видимая доска у края проёма и объём, в котором можно карабкаться
+fn spawn_ladder(
+    spawner: &mut ChildSpawnerCommands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    room_size: Vec3,
+) {
+    let height = room_size.y + LADDER_EXIT_HEIGHT;
+    let half_extents = Vec3::new(LADDER_OPENING, height, LADDER_OPENING) * 0.5;
+    let material = materials.add(Color::srgb(0.45, 0.3, 0.15));
+    let mesh = meshes.add(Mesh::from(Cuboid::new(LADDER_OPENING, room_size.y, 0.1)));
+
+    // Доска без коллайдера у дальнего края проёма
+    spawner.spawn((
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        Transform::from_translation(Vec3::new(0.0, room_size.y * 0.5, -LADDER_OPENING * 0.5)),
+        Name::new("LadderBoard"),
+    ));
+
+    spawner.spawn((
+        Ladder { half_extents },
+        Transform::from_translation(Vec3::new(0.0, height * 0.5, 0.0)),
+        Name::new("LadderVolume"),
+    ));
+}
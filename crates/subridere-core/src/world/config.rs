@@ -0,0 +1,99 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::world::generators::MazeAlgorithm;
+
+/// Путь к конфигу подземелья по умолчанию
+pub const DUNGEON_CONFIG_PATH: &str = "dungeons/default.dungeon.ron";
+
+/// Параметры генерации подземелья (assets/dungeons/*.dungeon.ron).
+/// Загружается как ассет, на время забега копируется в ресурс.
+#[derive(Asset, Resource, TypePath, Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DungeonConfig {
+    /// Размер сетки в комнатах
+    pub width: i32,
+    pub height: i32,
+    /// Размер одной комнаты (x, y, z)
+    pub room_size: [f32; 3],
+    /// Толщина стен
+    pub wall_thickness: f32,
+    /// Доля тупиков, превращаемых в петли (0.0..=1.0)
+    pub braid: f32,
+    /// Алгоритм генерации графа
+    pub algorithm: MazeAlgorithm,
+    /// Количество этажей; этаж 0 — верхний, следующие уходят вниз (y = -1, -2, ...)
+    pub floors: i32,
+    /// Сколько переходов между соседними этажами
+    pub links_per_floor: u32,
+    /// Прирост сложности (лут и враги) за каждый этаж вниз
+    pub difficulty_per_floor: f32,
+}
+
+impl Default for DungeonConfig {
+    fn default() -> Self {
+        Self {
+            width: 5,
+            height: 5,
+            room_size: [12.0, 6.0, 12.0],
+            wall_thickness: 0.1,
+            braid: 0.0,
+            algorithm: MazeAlgorithm::Backtracker,
+            floors: 1,
+            links_per_floor: 1,
+            difficulty_per_floor: 0.25,
+        }
+    }
+}
+
+impl DungeonConfig {
+    pub fn room_size(&self) -> Vec3 {
+        Vec3::from_array(self.room_size)
+    }
+
+    /// Мировая позиция начала комнаты по её координате в сетке
+    pub fn room_origin(&self, pos: IVec3) -> Vec3 {
+        pos.as_vec3() * self.room_size()
+    }
+
+    /// Множитель сложности этажа `y` (1.0 на верхнем этаже)
+    pub fn difficulty(&self, y: i32) -> f32 {
+        1.0 + (-y).max(0) as f32 * self.difficulty_per_floor
+    }
+
+    /// Координаты y всех этажей сверху вниз
+    pub fn floor_levels(&self) -> impl Iterator<Item = i32> {
+        (0..self.floors.max(1)).map(|i| -i)
+    }
+}
+
+/// Хэндл загружаемого конфига
+#[derive(Resource)]
+pub struct DungeonConfigHandle(pub Handle<DungeonConfig>);
+
+#[derive(Default)]
+pub struct DungeonConfigLoader;
+
+impl AssetLoader for DungeonConfigLoader {
+    type Asset = DungeonConfig;
+    type Settings = ();
+    type Error = ron::error::SpannedError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.unwrap();
+        let config: DungeonConfig = ron::de::from_bytes(&bytes)?;
+        Ok(config)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["dungeon.ron"]
+    }
+}
@@ -0,0 +1,100 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use std::collections::HashSet;
+
+use super::{Cell, MazeGenerator, MazeGraph, braid};
+use crate::world::room::types::VerticalKind;
+
+/// Переход между этажами: клетка `cell` на этаже `lower` связана с этажом `lower + 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloorLink {
+    pub cell: Cell,
+    pub lower: i32,
+    pub kind: VerticalKind,
+}
+
+/// Многоэтажное подземелье: граф каждого этажа и переходы между ними
+#[derive(Debug, Default)]
+pub struct DungeonLayout {
+    /// (y этажа, граф этажа), сверху вниз
+    pub floors: Vec<(i32, MazeGraph)>,
+    pub links: Vec<FloorLink>,
+}
+
+/// Параметры генерации этажей
+pub struct FloorParams {
+    pub width: i32,
+    pub height: i32,
+    pub floors: i32,
+    pub braid: f32,
+    pub links_per_floor: u32,
+}
+
+/// Генерирует этажи y = 0, -1, -2, ... одним генератором и соединяет соседние.
+/// Первый переход каждой пары этажей всегда проходим в обе стороны,
+/// поэтому с любого этажа можно вернуться наверх.
+pub fn generate_floors(
+    generator: &dyn MazeGenerator,
+    params: &FloorParams,
+    rng: &mut dyn RngCore,
+) -> DungeonLayout {
+    let mut layout = DungeonLayout::default();
+
+    for i in 0..params.floors.max(1) {
+        let mut graph = generator.generate(params.width, params.height, rng);
+        braid(&mut graph, params.braid, rng);
+        layout.floors.push((-i, graph));
+    }
+
+    // Клетки, уже занятые переходами, на каждом этаже
+    let mut used: HashSet<(i32, Cell)> = HashSet::new();
+    // Игрок появляется над (0, 0) верхнего этажа — там не должно быть дыры
+    used.insert((0, Cell::ZERO));
+
+    for pair in layout.floors.windows(2) {
+        let (upper_y, upper) = &pair[0];
+        let (lower_y, lower) = &pair[1];
+
+        let mut candidates: Vec<Cell> = upper
+            .keys()
+            .filter(|c| lower.contains_key(c))
+            .filter(|c| !used.contains(&(*upper_y, **c)) && !used.contains(&(*lower_y, **c)))
+            .copied()
+            .collect();
+        // Порядок HashMap не детерминирован — сортируем ради воспроизводимости
+        candidates.sort_by_key(|c| (c.x, c.y));
+        candidates.shuffle(rng);
+
+        for (n, &cell) in candidates
+            .iter()
+            .take(params.links_per_floor.max(1) as usize)
+            .enumerate()
+        {
+            let kind = if n == 0 {
+                if rng.gen_bool(0.5) {
+                    VerticalKind::Stairwell
+                } else {
+                    VerticalKind::Ladder
+                }
+            } else {
+                *[
+                    VerticalKind::Stairwell,
+                    VerticalKind::Ladder,
+                    VerticalKind::Shaft,
+                ]
+                .choose(rng)
+                .unwrap()
+            };
+
+            used.insert((*upper_y, cell));
+            used.insert((*lower_y, cell));
+            layout.links.push(FloorLink {
+                cell,
+                lower: *lower_y,
+                kind,
+            });
+        }
+    }
+
+    layout
+}
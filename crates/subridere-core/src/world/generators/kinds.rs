@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use rand::{Rng, RngCore};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::world::room::types::{RoomKind, RoomMap};
+
+/// Шанс, что тупик станет сокровищницей
+const TREASURE_CHANCE: f64 = 0.35;
+/// Шанс, что тупик станет алтарём
+const SHRINE_CHANCE: f64 = 0.2;
+/// Шанс, что развилка станет ареной
+const ARENA_CHANCE: f64 = 0.3;
+
+/// Назначает типы комнатам уже связанной карты.
+/// Start — (0, 0, 0) или первая комната, Exit — самая дальняя от неё,
+/// тупики становятся сокровищницами, алтарями или пустыми тупиками,
+/// развилки — иногда аренами. Комнаты с вертикальными переходами остаются Normal.
+pub fn assign_room_kinds(room_map: &RoomMap, rng: &mut dyn RngCore) -> HashMap<IVec3, RoomKind> {
+    let mut positions: Vec<IVec3> = room_map.rooms.keys().copied().collect();
+    positions.sort_by_key(|p| (std::cmp::Reverse(p.y), p.x, p.z));
+
+    let mut kinds = HashMap::new();
+    let Some(&first) = positions.first() else {
+        return kinds;
+    };
+
+    let start = if room_map.rooms.contains_key(&IVec3::ZERO) {
+        IVec3::ZERO
+    } else {
+        first
+    };
+    kinds.insert(start, RoomKind::Start);
+
+    // Самая дальняя достижимая комната (первая в порядке сортировки при равенстве)
+    let distances = distances_from(room_map, start);
+    let exit = positions
+        .iter()
+        .filter(|p| **p != start && !room_map.rooms[*p].vertical.is_connector())
+        .filter_map(|p| distances.get(p).map(|d| (*d, *p)))
+        .fold(None, |best: Option<(usize, IVec3)>, (d, p)| match best {
+            Some((best_d, _)) if best_d >= d => best,
+            _ => Some((d, p)),
+        });
+    if let Some((_, exit)) = exit {
+        kinds.insert(exit, RoomKind::Exit);
+    }
+
+    for pos in positions {
+        if kinds.contains_key(&pos) {
+            continue;
+        }
+        let meta = &room_map.rooms[&pos];
+        if meta.vertical.is_connector() {
+            kinds.insert(pos, RoomKind::Normal);
+            continue;
+        }
+
+        let kind = match meta.exits(pos).len() {
+            1 => {
+                let roll: f64 = rng.gen_range(0.0..1.0);
+                if roll < TREASURE_CHANCE {
+                    RoomKind::Treasure
+                } else if roll < TREASURE_CHANCE + SHRINE_CHANCE {
+                    RoomKind::Shrine
+                } else {
+                    RoomKind::DeadEnd
+                }
+            }
+            n if n >= 3 && rng.gen_bool(ARENA_CHANCE) => RoomKind::Arena,
+            _ => RoomKind::Normal,
+        };
+        kinds.insert(pos, kind);
+    }
+
+    kinds
+}
+
+/// BFS по дверям и вертикальным переходам
+fn distances_from(room_map: &RoomMap, start: IVec3) -> HashMap<IVec3, usize> {
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(pos) = queue.pop_front() {
+        let d = distances[&pos];
+        for next in room_map.rooms[&pos].exits(pos) {
+            if room_map.rooms.contains_key(&next) && seen.insert(next) {
+                distances.insert(next, d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::builders::grid::register_room;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn corridor_gets_start_and_exit_at_the_ends() {
+        let mut room_map = RoomMap::default();
+        for i in 0..4 {
+            register_room(&mut room_map, IVec3::new(0, 0, -i));
+        }
+
+        let kinds = assign_room_kinds(&room_map, &mut StdRng::seed_from_u64(1));
+
+        assert_eq!(kinds[&IVec3::ZERO], RoomKind::Start);
+        assert_eq!(kinds[&IVec3::new(0, 0, -3)], RoomKind::Exit);
+        assert_eq!(kinds[&IVec3::new(0, 0, -1)], RoomKind::Normal);
+        assert_eq!(kinds.len(), 4);
+    }
+}
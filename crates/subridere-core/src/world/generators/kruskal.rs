@@ -0,0 +1,81 @@
+use rand::RngCore;
+use rand::seq::SliceRandom;
+
+use super::{MazeGenerator, MazeGraph, connect};
+use crate::world::generators::Cell;
+
+/// Randomized Kruskal: перебираем все стены в случайном порядке
+/// и ломаем те, что разделяют разные компоненты.
+pub struct Kruskal;
+
+impl MazeGenerator for Kruskal {
+    fn generate(&self, width: i32, height: i32, rng: &mut dyn RngCore) -> MazeGraph {
+        let mut graph = MazeGraph::new();
+        if width <= 0 || height <= 0 {
+            return graph;
+        }
+
+        let index = |c: Cell| (c.y * width + c.x) as usize;
+        let mut sets = DisjointSet::new((width * height) as usize);
+
+        let mut edges = Vec::new();
+        for z in 0..height {
+            for x in 0..width {
+                let cell = Cell::new(x, z);
+                graph.entry(cell).or_default();
+                if x + 1 < width {
+                    edges.push((cell, Cell::new(x + 1, z)));
+                }
+                if z + 1 < height {
+                    edges.push((cell, Cell::new(x, z + 1)));
+                }
+            }
+        }
+        edges.shuffle(rng);
+
+        for (a, b) in edges {
+            if sets.union(index(a), index(b)) {
+                connect(&mut graph, a, b);
+            }
+        }
+
+        graph
+    }
+}
+
+/// Union-find с сжатием путей
+struct DisjointSet {
+    parent: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut cur = i;
+        while self.parent[cur] != root {
+            let next = self.parent[cur];
+            self.parent[cur] = root;
+            cur = next;
+        }
+        root
+    }
+
+    /// true, если множества были разными и объединены
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        self.parent[ra] = rb;
+        true
+    }
+}
@@ -1 +1,203 @@
+pub mod floors;
+pub mod kinds;
+pub mod kruskal;
 pub mod maze;
+pub mod prim;
+pub mod rooms_corridors;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub use floors::{DungeonLayout, FloorLink, FloorParams, generate_floors};
+pub use kinds::assign_room_kinds;
+pub use kruskal::Kruskal;
+pub use maze::{Backtracker, Cell, generate_maze};
+pub use prim::Prim;
+pub use rooms_corridors::RoomsAndCorridors;
+
+/// Карта смежности лабиринта: для каждой клетки — соседи, с которыми есть проход.
+/// Ключи — все клетки, которые надо построить (включая тупики).
+pub type MazeGraph = HashMap<Cell, Vec<Cell>>;
+
+/// Соседи по четырём направлениям (x, z)
+pub(crate) const DIRS: [Cell; 4] = [
+    Cell::new(1, 0),  // право
+    Cell::new(-1, 0), // лево
+    Cell::new(0, 1),  // вперед (+z)
+    Cell::new(0, -1), // назад (-z)
+];
+
+/// Генератор графа лабиринта на сетке width×height
+pub trait MazeGenerator {
+    fn generate(&self, width: i32, height: i32, rng: &mut dyn RngCore) -> MazeGraph;
+}
+
+/// Выбор алгоритма в DungeonConfig
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MazeAlgorithm {
+    /// Recursive backtracker — длинные извилистые коридоры
+    #[default]
+    Backtracker,
+    /// Randomized Prim — много коротких тупиков
+    Prim,
+    /// Randomized Kruskal — равномерная "ветвистость"
+    Kruskal,
+    /// Прямоугольные залы, соединённые коридорами
+    RoomsAndCorridors,
+}
+
+impl MazeAlgorithm {
+    pub fn generator(self) -> Box<dyn MazeGenerator> {
+        match self {
+            MazeAlgorithm::Backtracker => Box::new(Backtracker),
+            MazeAlgorithm::Prim => Box::new(Prim),
+            MazeAlgorithm::Kruskal => Box::new(Kruskal),
+            MazeAlgorithm::RoomsAndCorridors => Box::new(RoomsAndCorridors::default()),
+        }
+    }
+}
+
+/// Соединить две клетки проходом (в обе стороны, без дублей)
+pub(crate) fn connect(graph: &mut MazeGraph, a: Cell, b: Cell) {
+    let a_neighbors = graph.entry(a).or_default();
+    if !a_neighbors.contains(&b) {
+        a_neighbors.push(b);
+    }
+    let b_neighbors = graph.entry(b).or_default();
+    if !b_neighbors.contains(&a) {
+        b_neighbors.push(a);
+    }
+}
+
+/// Убрать часть тупиков, добавляя петли.
+/// `factor` 0.0 — идеальный лабиринт, 1.0 — тупиков не остаётся.
+pub fn braid(graph: &mut MazeGraph, factor: f32, rng: &mut dyn RngCore) {
+    if factor <= 0.0 {
+        return;
+    }
+
+    let mut dead_ends: Vec<Cell> = graph
+        .iter()
+        .filter(|(_, neighbors)| neighbors.len() == 1)
+        .map(|(&cell, _)| cell)
+        .collect();
+    // Порядок HashMap не детерминирован — сортируем ради воспроизводимости
+    dead_ends.sort_by_key(|c| (c.x, c.y));
+
+    for cell in dead_ends {
+        // Мог перестать быть тупиком после предыдущих соединений
+        if graph[&cell].len() != 1 || rng.gen_range(0.0..1.0) >= factor {
+            continue;
+        }
+
+        let candidates: Vec<Cell> = DIRS
+            .iter()
+            .map(|d| cell + *d)
+            .filter(|n| graph.contains_key(n) && !graph[&cell].contains(n))
+            .collect();
+
+        if let Some(&target) = candidates.choose(rng) {
+            connect(graph, cell, target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::collections::HashSet;
+
+    fn reachable(graph: &MazeGraph) -> usize {
+        let Some(&start) = graph.keys().next() else {
+            return 0;
+        };
+        let mut seen = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(cell) = stack.pop() {
+            for n in &graph[&cell] {
+                if seen.insert(*n) {
+                    stack.push(*n);
+                }
+            }
+        }
+        seen.len()
+    }
+
+    fn edge_count(graph: &MazeGraph) -> usize {
+        graph.values().map(Vec::len).sum::<usize>() / 2
+    }
+
+    #[test]
+    fn perfect_mazes_are_spanning_trees() {
+        for algorithm in [
+            MazeAlgorithm::Backtracker,
+            MazeAlgorithm::Prim,
+            MazeAlgorithm::Kruskal,
+        ] {
+            let mut rng = StdRng::seed_from_u64(7);
+            let graph = algorithm.generator().generate(6, 4, &mut rng);
+
+            assert_eq!(graph.len(), 24, "{:?}", algorithm);
+            assert_eq!(reachable(&graph), 24, "{:?}", algorithm);
+            assert_eq!(edge_count(&graph), 23, "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn rooms_and_corridors_is_connected() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let graph = MazeAlgorithm::RoomsAndCorridors
+            .generator()
+            .generate(10, 10, &mut rng);
+
+        assert!(graph.contains_key(&Cell::ZERO));
+        assert_eq!(reachable(&graph), graph.len());
+    }
+
+    #[test]
+    fn full_braid_removes_dead_ends() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut graph = Backtracker.generate(5, 5, &mut rng);
+        braid(&mut graph, 1.0, &mut rng);
+
+        assert!(graph.values().all(|n| n.len() >= 2));
+    }
+
+    #[test]
+    fn floors_are_linked_top_to_bottom() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let params = FloorParams {
+            width: 4,
+            height: 4,
+            floors: 3,
+            braid: 0.0,
+            links_per_floor: 2,
+        };
+        let layout = generate_floors(&Backtracker, &params, &mut rng);
+
+        assert_eq!(layout.floors.len(), 3);
+        for lower in [-1, -2] {
+            let links: Vec<_> = layout.links.iter().filter(|l| l.lower == lower).collect();
+            assert_eq!(links.len(), 2);
+            assert!(links[0].kind.is_climbable());
+        }
+        // Над точкой появления игрока нет проёма
+        assert!(
+            !layout
+                .links
+                .iter()
+                .any(|l| l.lower == -1 && l.cell == Cell::ZERO)
+        );
+    }
+
+    #[test]
+    fn same_seed_same_maze() {
+        let a = Kruskal.generate(5, 5, &mut StdRng::seed_from_u64(11));
+        let b = Kruskal.generate(5, 5, &mut StdRng::seed_from_u64(11));
+        assert_eq!(a, b);
+    }
+}
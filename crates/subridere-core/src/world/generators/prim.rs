@@ -0,0 +1,49 @@
+use rand::{Rng, RngCore};
+use std::collections::HashSet;
+
+use super::{DIRS, MazeGenerator, MazeGraph, connect};
+use crate::world::generators::Cell;
+
+/// Randomized Prim: растим дерево от стартовой клетки,
+/// каждый шаг подключая случайную клетку с границы.
+pub struct Prim;
+
+impl MazeGenerator for Prim {
+    fn generate(&self, width: i32, height: i32, rng: &mut dyn RngCore) -> MazeGraph {
+        let mut graph = MazeGraph::new();
+        if width <= 0 || height <= 0 {
+            return graph;
+        }
+
+        let in_bounds = |c: &Cell| c.x >= 0 && c.x < width && c.y >= 0 && c.y < height;
+
+        let start = Cell::new(0, 0);
+        let mut visited = HashSet::from([start]);
+        graph.entry(start).or_default();
+
+        // Граница: (клетка вне дерева, сосед в дереве)
+        let mut frontier: Vec<(Cell, Cell)> = DIRS
+            .iter()
+            .map(|d| (start + *d, start))
+            .filter(|(c, _)| in_bounds(c))
+            .collect();
+
+        while !frontier.is_empty() {
+            let (cell, from) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+            if !visited.insert(cell) {
+                continue;
+            }
+
+            connect(&mut graph, from, cell);
+
+            for d in DIRS {
+                let next = cell + d;
+                if in_bounds(&next) && !visited.contains(&next) {
+                    frontier.push((next, cell));
+                }
+            }
+        }
+
+        graph
+    }
+}
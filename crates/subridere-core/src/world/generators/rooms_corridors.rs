@@ -0,0 +1,127 @@
+use rand::{Rng, RngCore};
+
+use super::{Backtracker, MazeGenerator, MazeGraph, connect};
+use crate::world::generators::Cell;
+
+/// Прямоугольные залы из нескольких клеток, соединённые L-образными коридорами.
+/// Клетки вне залов и коридоров не строятся; клетка (0, 0) всегда связана с залами.
+pub struct RoomsAndCorridors {
+    /// Сколько раз пытаемся разместить зал
+    pub attempts: u32,
+    /// Минимальный/максимальный размер зала в клетках
+    pub min_size: i32,
+    pub max_size: i32,
+}
+
+impl Default for RoomsAndCorridors {
+    fn default() -> Self {
+        Self {
+            attempts: 20,
+            min_size: 2,
+            max_size: 3,
+        }
+    }
+}
+
+/// Прямоугольник клеток [min, max)
+#[derive(Clone, Copy)]
+struct Rect {
+    min: Cell,
+    max: Cell,
+}
+
+impl Rect {
+    /// Пересечение с зазором в одну клетку, чтобы залы не слипались
+    fn touches(&self, other: &Rect) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    fn center(&self) -> Cell {
+        (self.min + self.max - Cell::ONE) / 2
+    }
+}
+
+impl MazeGenerator for RoomsAndCorridors {
+    fn generate(&self, width: i32, height: i32, rng: &mut dyn RngCore) -> MazeGraph {
+        let mut rooms: Vec<Rect> = Vec::new();
+
+        for _ in 0..self.attempts {
+            let w = rng.gen_range(self.min_size..=self.max_size);
+            let h = rng.gen_range(self.min_size..=self.max_size);
+            if w > width || h > height {
+                continue;
+            }
+
+            let min = Cell::new(rng.gen_range(0..=width - w), rng.gen_range(0..=height - h));
+            let rect = Rect {
+                min,
+                max: min + Cell::new(w, h),
+            };
+
+            if rooms.iter().all(|r| !r.touches(&rect)) {
+                rooms.push(rect);
+            }
+        }
+
+        // Слишком тесная сетка — обычный лабиринт
+        if rooms.is_empty() {
+            return Backtracker.generate(width, height, rng);
+        }
+
+        let mut graph = MazeGraph::new();
+
+        // Внутри зала все соседние клетки соединены
+        for rect in &rooms {
+            for z in rect.min.y..rect.max.y {
+                for x in rect.min.x..rect.max.x {
+                    let cell = Cell::new(x, z);
+                    graph.entry(cell).or_default();
+                    if x + 1 < rect.max.x {
+                        connect(&mut graph, cell, Cell::new(x + 1, z));
+                    }
+                    if z + 1 < rect.max.y {
+                        connect(&mut graph, cell, Cell::new(x, z + 1));
+                    }
+                }
+            }
+        }
+
+        // Каждый зал соединяем с предыдущим
+        for pair in rooms.windows(2) {
+            let from = pair[0].center();
+            let to = pair[1].center();
+
+            let corner = if rng.gen_bool(0.5) {
+                Cell::new(to.x, from.y)
+            } else {
+                Cell::new(from.x, to.y)
+            };
+
+            dig(&mut graph, from, corner);
+            dig(&mut graph, corner, to);
+        }
+
+        // Игрок стартует в (0, 0) — ведём туда коридор от первого зала
+        let start = Cell::ZERO;
+        let first = rooms[0].center();
+        graph.entry(start).or_default();
+        dig(&mut graph, start, Cell::new(first.x, start.y));
+        dig(&mut graph, Cell::new(first.x, start.y), first);
+
+        graph
+    }
+}
+
+/// Прорыть прямой коридор между клетками на одной линии
+fn dig(graph: &mut MazeGraph, from: Cell, to: Cell) {
+    let step = (to - from).signum();
+    let mut cur = from;
+    while cur != to {
+        let next = cur + step;
+        connect(graph, cur, next);
+        cur = next;
+    }
+}
@@ -1,4 +1,6 @@
 pub mod builders;
+pub mod config;
 pub mod generators;
 pub mod plugin;
 pub mod room;
+pub mod seed;
@@ -1,10 +1,35 @@
+use crate::app::AppState;
+use crate::world::config::{DungeonConfig, DungeonConfigLoader};
+use crate::world::room::shrine::use_shrines;
+use crate::world::room::template::{RoomTemplate, RoomTemplateLoader, RoomTemplates};
 use crate::world::room::types::RoomMap;
+use crate::world::seed::{SeedOrigin, WorldSeed, reroll_world_seed};
 use bevy::prelude::*;
 
 pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(RoomMap::default());
+        let (seed, origin) = match WorldSeed::from_args() {
+            Some(seed) => (seed, SeedOrigin::CommandLine),
+            None => (WorldSeed::random(), SeedOrigin::Random),
+        };
+        info!("🌱 World seed: {}", seed.0);
+
+        app.insert_resource(RoomMap::default())
+            .insert_resource(seed)
+            .insert_resource(origin)
+            .init_asset::<DungeonConfig>()
+            .init_asset_loader::<DungeonConfigLoader>()
+            // Значения по умолчанию, пока ассет не загружен
+            .init_resource::<DungeonConfig>()
+            .init_asset::<RoomTemplate>()
+            .init_asset_loader::<RoomTemplateLoader>()
+            .init_resource::<RoomTemplates>()
+            // Сид следующего забега: экран смерти ещё показывает сыгранный
+            .add_systems(OnEnter(AppState::MainMenu), reroll_world_seed)
+            .add_systems(OnExit(AppState::Dead), reroll_world_seed)
+            .add_systems(OnExit(AppState::Victory), reroll_world_seed)
+            .add_systems(Update, use_shrines.run_if(in_state(AppState::InGame)));
     }
 }
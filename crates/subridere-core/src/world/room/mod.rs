@@ -1 +1,3 @@
+pub mod shrine;
+pub mod template;
 pub mod types;
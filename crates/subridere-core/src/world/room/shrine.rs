@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::player::component::Player;
+use crate::stats::{Health, Mana, Stamina};
+
+/// Радиус, в котором алтарь срабатывает
+const SHRINE_RADIUS: f32 = 2.0;
+
+/// Алтарь: один раз полностью восстанавливает здоровье, ману и выносливость
+#[derive(Component, Default)]
+pub struct Shrine {
+    pub used: bool,
+}
+
+pub fn use_shrines(
+    mut shrines: Query<(&mut Shrine, &GlobalTransform)>,
+    mut player_query: Query<(&Transform, &mut Health, &mut Mana, &mut Stamina), With<Player>>,
+) {
+    let Ok((player_transform, mut health, mut mana, mut stamina)) = player_query.single_mut()
+    else {
+        return;
+    };
+
+    for (mut shrine, shrine_transform) in &mut shrines {
+        if shrine.used {
+            continue;
+        }
+
+        let distance = player_transform
+            .translation
+            .distance(shrine_transform.translation());
+        if distance > SHRINE_RADIUS {
+            continue;
+        }
+
+        health.current = health.max;
+        mana.current = mana.max;
+        stamina.current = stamina.max;
+        shrine.used = true;
+
+        info!("⛩️ Shrine restored the player");
+    }
+}
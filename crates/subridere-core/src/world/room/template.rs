@@ -0,0 +1,186 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::block_body::{BlockBodyAsset, BlockClipAsset};
+use crate::items::{ItemId, VisualPart};
+use crate::world::room::types::RoomKind;
+
+/// Шаблон комнаты (assets/rooms/*.room.ron): свет, декор, лут и точки появления врагов
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RoomTemplate {
+    /// Цвет стен и пола (RGB)
+    pub tint: (f32, f32, f32),
+    /// Вероятность, что в комнате будет свет
+    pub light_chance: f32,
+    pub light: RoomLight,
+    /// Декор (с коллайдерами), смещения от центра пола
+    pub props: Vec<VisualPart>,
+    /// Блочные тела (модели из assets/models)
+    pub bodies: Vec<RoomBody>,
+    /// Дополнительный лут комнаты
+    pub loot: Vec<LootEntry>,
+    /// Точки появления врагов, смещения от центра пола
+    pub spawn_points: Vec<(f32, f32, f32)>,
+}
+
+impl Default for RoomTemplate {
+    fn default() -> Self {
+        Self {
+            tint: (0.75, 0.75, 0.8),
+            light_chance: 0.3,
+            light: RoomLight::default(),
+            props: Vec::new(),
+            bodies: Vec::new(),
+            loot: Vec::new(),
+            spawn_points: Vec::new(),
+        }
+    }
+}
+
+impl RoomTemplate {
+    pub fn color(&self) -> Color {
+        Color::srgb(self.tint.0, self.tint.1, self.tint.2)
+    }
+}
+
+/// Точечный источник света под потолком
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RoomLight {
+    pub color: (f32, f32, f32),
+    pub intensity: f32,
+    pub range: f32,
+    pub height: f32,
+    pub shadows: bool,
+}
+
+impl Default for RoomLight {
+    fn default() -> Self {
+        Self {
+            color: (0.0, 0.7, 0.0),
+            intensity: 1_000_000.0,
+            range: 200.0,
+            height: 5.0,
+            shadows: true,
+        }
+    }
+}
+
+impl RoomLight {
+    pub fn point_light(&self) -> PointLight {
+        PointLight {
+            color: Color::srgb(self.color.0, self.color.1, self.color.2),
+            intensity: self.intensity,
+            range: self.range,
+            shadows_enabled: self.shadows,
+            ..default()
+        }
+    }
+}
+
+/// Блочное тело в комнате. Хэндлы модели и клипа заполняет загрузчик шаблона.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoomBody {
+    pub model: String,
+    /// Смещение от центра пола
+    pub offset: (f32, f32, f32),
+    /// Поворот вокруг Y, градусы
+    #[serde(default)]
+    pub yaw: f32,
+    /// Клип, который тело проигрывает
+    #[serde(default)]
+    pub animation: Option<String>,
+    /// Статичное тело (статуя): один запечённый меш и составной коллайдер, без анимации
+    #[serde(default)]
+    pub merged: bool,
+    #[serde(skip)]
+    pub model_handle: Handle<BlockBodyAsset>,
+    #[serde(skip)]
+    pub animation_handle: Option<Handle<BlockClipAsset>>,
+}
+
+impl RoomBody {
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(Vec3::from(self.offset))
+            .with_rotation(Quat::from_rotation_y(self.yaw.to_radians()))
+    }
+}
+
+/// Строка таблицы лута: `count` штук с вероятностью `chance`
+#[derive(Debug, Clone, Deserialize)]
+pub struct LootEntry {
+    pub item: ItemId,
+    #[serde(default = "default_chance")]
+    pub chance: f32,
+    #[serde(default = "default_count")]
+    pub count: usize,
+}
+
+fn default_chance() -> f32 {
+    1.0
+}
+
+fn default_count() -> usize {
+    1
+}
+
+/// Хэндлы загружаемых шаблонов
+#[derive(Resource, Default)]
+pub struct RoomTemplateHandles(pub Vec<(RoomKind, Handle<RoomTemplate>)>);
+
+/// Загруженные шаблоны по типам комнат.
+/// Для отсутствующих типов используется RoomTemplate::default().
+#[derive(Resource, Default)]
+pub struct RoomTemplates {
+    templates: HashMap<RoomKind, RoomTemplate>,
+    fallback: RoomTemplate,
+}
+
+impl RoomTemplates {
+    pub fn insert(&mut self, kind: RoomKind, template: RoomTemplate) {
+        self.templates.insert(kind, template);
+    }
+
+    pub fn get(&self, kind: RoomKind) -> &RoomTemplate {
+        self.templates.get(&kind).unwrap_or(&self.fallback)
+    }
+}
+
+#[derive(Default)]
+pub struct RoomTemplateLoader;
+
+impl AssetLoader for RoomTemplateLoader {
+    type Asset = RoomTemplate;
+    type Settings = ();
+    type Error = ron::error::SpannedError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.unwrap();
+        let mut template: RoomTemplate = ron::de::from_bytes(&bytes)?;
+
+        // Модели и клипы — зависимости шаблона
+        for body in &mut template.bodies {
+            body.model_handle = load_context.load(body.model.clone());
+            body.animation_handle = body
+                .animation
+                .as_ref()
+                .map(|path| load_context.load(path.clone()));
+        }
+
+        Ok(template)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["room.ron"]
+    }
+}
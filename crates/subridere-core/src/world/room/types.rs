@@ -1,7 +1,8 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct WallFlags {
     pub front: bool,
     pub back: bool,
@@ -9,7 +10,7 @@ pub struct WallFlags {
     pub right: bool,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DoorFlags {
     pub front: bool,
     pub back: bool,
@@ -17,14 +18,121 @@ pub struct DoorFlags {
     pub right: bool,
 }
 
+/// Назначение комнаты — определяет шаблон (свет, декор, лут, враги)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RoomKind {
+    /// Обычная комната
+    #[default]
+    Normal,
+    /// Комната появления игрока
+    Start,
+    /// Тупик с сокровищами
+    Treasure,
+    /// Развилка с засадой
+    Arena,
+    /// Алтарь, восстанавливающий силы
+    Shrine,
+    /// Пустой тупик
+    DeadEnd,
+    /// Самая дальняя комната подземелья
+    Exit,
+}
+
+impl RoomKind {
+    pub const ALL: [RoomKind; 7] = [
+        RoomKind::Normal,
+        RoomKind::Start,
+        RoomKind::Treasure,
+        RoomKind::Arena,
+        RoomKind::Shrine,
+        RoomKind::DeadEnd,
+        RoomKind::Exit,
+    ];
+
+    /// Путь к шаблону комнаты в assets/
+    pub fn template_path(self) -> &'static str {
+        match self {
+            RoomKind::Normal => "rooms/normal.room.ron",
+            RoomKind::Start => "rooms/start.room.ron",
+            RoomKind::Treasure => "rooms/treasure.room.ron",
+            RoomKind::Arena => "rooms/arena.room.ron",
+            RoomKind::Shrine => "rooms/shrine.room.ron",
+            RoomKind::DeadEnd => "rooms/dead_end.room.ron",
+            RoomKind::Exit => "rooms/exit.room.ron",
+        }
+    }
+}
+
+/// Тип вертикального перехода между этажами (x, y, z) ↔ (x, y±1, z)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerticalKind {
+    /// Пандус — проходим в обе стороны
+    Stairwell,
+    /// Лестница — подъём при движении вперёд
+    Ladder,
+    /// Шахта — только спуск (падение)
+    Shaft,
+}
+
+impl VerticalKind {
+    /// Можно ли подняться обратно
+    pub fn is_climbable(self) -> bool {
+        !matches!(self, VerticalKind::Shaft)
+    }
+}
+
+/// Проёмы в полу/потолке комнаты
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VerticalFlags {
+    /// Переход в комнату выше (y + 1) — проём в потолке
+    pub up: Option<VerticalKind>,
+    /// Переход в комнату ниже (y - 1) — проём в полу
+    pub down: Option<VerticalKind>,
+}
+
+impl VerticalFlags {
+    pub fn is_connector(&self) -> bool {
+        self.up.is_some() || self.down.is_some()
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RoomMetadata {
+    pub kind: RoomKind,
     pub wall_flags: WallFlags,
     pub door_flags: DoorFlags,
+    pub vertical: VerticalFlags,
     pub has_light: bool,
     pub entity: Option<Entity>,
 }
 
+/// Объём лестницы: юнит внутри него может карабкаться вверх
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Ladder {
+    pub half_extents: Vec3,
+}
+
+impl RoomMetadata {
+    /// Соседи, в которые можно пройти: двери и вертикальные переходы
+    pub fn exits(&self, pos: IVec3) -> Vec<IVec3> {
+        let mut exits = Vec::new();
+        let doors = [
+            (self.door_flags.front, IVec3::Z),
+            (self.door_flags.back, IVec3::NEG_Z),
+            (self.door_flags.left, IVec3::NEG_X),
+            (self.door_flags.right, IVec3::X),
+            (self.vertical.up.is_some(), IVec3::Y),
+            (self.vertical.down.is_some(), IVec3::NEG_Y),
+        ];
+        for (open, offset) in doors {
+            if open {
+                exits.push(pos + offset);
+            }
+        }
+        exits
+    }
+}
+
 #[derive(Debug, Resource, Default)]
 pub struct RoomMap {
     pub rooms: HashMap<IVec3, RoomMetadata>,
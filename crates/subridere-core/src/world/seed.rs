@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Независимые потоки случайности генерации.
+/// Каждый этап получает свой RNG, поэтому изменение одного этапа
+/// (например, больше лута) не сдвигает раскладку лабиринта.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedStream {
+    Maze,
+    Lights,
+    Loot,
+    Enemies,
+    Rooms,
+}
+
+impl SeedStream {
+    fn salt(self) -> u64 {
+        match self {
+            SeedStream::Maze => 0x6d61_7a65,
+            SeedStream::Lights => 0x6c69_6768,
+            SeedStream::Loot => 0x6c6f_6f74,
+            SeedStream::Enemies => 0x656e_656d,
+            SeedStream::Rooms => 0x726f_6f6d,
+        }
+    }
+}
+
+/// Сид мира: определяет всю генерацию забега
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorldSeed(pub u64);
+
+impl Default for WorldSeed {
+    fn default() -> Self {
+        Self::random()
+    }
+}
+
+/// Откуда взялся сид: случайный перебрасывается на каждый новый забег,
+/// а заданный через `--seed` или игроком в меню — сохраняется
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeedOrigin {
+    #[default]
+    Random,
+    CommandLine,
+    Player,
+}
+
+impl WorldSeed {
+    pub fn random() -> Self {
+        // Короткие сиды проще продиктовать в баг-репорте
+        Self(rand::random::<u32>() as u64)
+    }
+
+    /// `--seed <N>` из командной строки
+    pub fn from_args() -> Option<Self> {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let value = match arg.strip_prefix("--seed=") {
+                Some(value) => value.to_string(),
+                None if arg == "--seed" => args.next()?,
+                None => continue,
+            };
+            return value.parse().ok().map(Self);
+        }
+        None
+    }
+
+    /// Детерминированный RNG для этапа генерации
+    pub fn rng(&self, stream: SeedStream) -> StdRng {
+        StdRng::seed_from_u64(splitmix64(self.0 ^ stream.salt()))
+    }
+}
+
+/// Новый забег: случайный сид меняется, чтобы не повторять прошлое подземелье
+pub fn reroll_world_seed(mut seed: ResMut<WorldSeed>, origin: Res<SeedOrigin>) {
+    if *origin != SeedOrigin::Random {
+        return;
+    }
+    *seed = WorldSeed::random();
+    info!("🌱 World seed: {}", seed.0);
+}
+
+/// Перемешивание сида, чтобы соседние сиды давали несвязанные потоки
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngCore;
+
+    #[test]
+    fn same_seed_same_stream() {
+        let seed = WorldSeed(42);
+        let mut a = seed.rng(SeedStream::Maze);
+        let mut b = seed.rng(SeedStream::Maze);
+        for _ in 0..8 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn streams_are_independent() {
+        let seed = WorldSeed(42);
+        let maze = seed.rng(SeedStream::Maze).next_u64();
+        let loot = seed.rng(SeedStream::Loot).next_u64();
+        assert_ne!(maze, loot);
+    }
+}